@@ -127,6 +127,7 @@ pub struct PayrollResponse {
 pub enum SalaryInputType {
     Annual,
     Monthly,
+    Hourly,
 }
 
 // ============================================================================
@@ -833,6 +834,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn salary_input_type_hourly_serde_round_trip() {
+        assert_eq!(
+            serde_json::to_string(&SalaryInputType::Hourly).unwrap(),
+            "\"HOURLY\""
+        );
+        assert_eq!(
+            serde_json::from_str::<SalaryInputType>("\"HOURLY\"").unwrap(),
+            SalaryInputType::Hourly
+        );
+    }
+
     #[test]
     fn workday_status_serde_round_trip() {
         for status in [
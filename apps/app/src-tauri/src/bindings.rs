@@ -2,8 +2,8 @@ use tauri_specta::{collect_commands, Builder};
 
 pub fn generate_bindings() -> Builder<tauri::Wry> {
     use crate::commands::{
-        app, auth, confetti, menubar, notifications, preferences, recovery, terms, user_settings,
-        workday,
+        app, auth, calendar_export, company_holidays, confetti, goals, leave, menubar,
+        notifications, preferences, recovery, terms, user_settings, workday,
     };
     use crate::{salary, tray};
 
@@ -36,6 +36,22 @@ pub fn generate_bindings() -> Builder<tauri::Wry> {
         preferences::greet,
         preferences::load_preferences,
         preferences::save_preferences,
+        // Goals
+        goals::load_goals,
+        goals::save_goal,
+        goals::delete_goal,
+        // Leave balance
+        leave::get_leave_balance,
+        leave::get_leave_accruals,
+        leave::get_year_end_leave_balance,
+        leave::adjust_leave_balance,
+        // Company holidays
+        company_holidays::import_company_holidays,
+        company_holidays::get_company_holidays,
+        company_holidays::remove_company_holiday_calendar,
+        // Calendar export
+        calendar_export::export_workday_calendar,
+        calendar_export::get_calendar_feed_path,
         // Notifications
         notifications::send_native_notification,
         // Recovery
@@ -51,11 +67,15 @@ pub fn generate_bindings() -> Builder<tauri::Wry> {
         tray::set_tray_icon_state,
         tray::set_tray_title,
         // Salary ticker
-        salary::notify_settings_changed,
+        salary::ticker::notify_settings_changed,
+        salary::ticker::watch_salary_tick,
+        salary::forecast::get_salary_forecast,
         // Workday sync
         workday::fetch_workday,
         workday::clear_workday_schedule_override,
         workday::mutate_workday,
+        workday::get_job_workday,
+        workday::save_job_workday,
     ])
 }
 
@@ -0,0 +1,198 @@
+//! 근무 일정 iCalendar 내보내기.
+//!
+//! 월급날(급여 기간 시작일과 서버 `PAYDAY` 이벤트), 휴가일, 출퇴근 시각을 바꾼 날을
+//! 일정으로 만든다. 파일 쓰기와 구독 파일 갱신은 `crate::commands::calendar_export`.
+
+use chrono::{NaiveDate, NaiveTime};
+
+use crate::ics::{EventTime, ExportEvent};
+use crate::salary;
+use crate::types::{UserSettings, WorkdayCache, WorkdayCacheEvent, WorkdayKind};
+
+/// 내보내는 달력 이름 (`X-WR-CALNAME`)
+pub const CALENDAR_NAME: &str = "모아 근무 일정";
+
+/// 휴가 종류 이름. 휴가가 아니면 None
+pub fn leave_label(kind: &WorkdayKind) -> Option<&'static str> {
+    match kind {
+        WorkdayKind::AnnualLeave => Some("연차"),
+        WorkdayKind::MorningHalfLeave => Some("오전 반차"),
+        WorkdayKind::AfternoonHalfLeave => Some("오후 반차"),
+        WorkdayKind::MorningQuarterLeave => Some("오전 반반차"),
+        WorkdayKind::AfternoonQuarterLeave => Some("오후 반반차"),
+        WorkdayKind::UnpaidLeave => Some("무급휴가"),
+        WorkdayKind::SickLeave => Some("병가"),
+        WorkdayKind::Work | WorkdayKind::DayOff | WorkdayKind::PublicHoliday => None,
+    }
+}
+
+/// 출퇴근 시각 override (근무일·반차·반반차, 둘 다 있을 때만). 야간 근무는 다음 날 퇴근
+///
+/// 반차·반반차는 급여 계산처럼 이 일정을 휴가와 나누므로, 휴가 일정과 함께 그대로 내보낸다.
+fn shift_event(
+    settings: &UserSettings,
+    date: NaiveDate,
+    cache: &WorkdayCache,
+) -> Option<ExportEvent> {
+    if !matches!(
+        cache.kind,
+        WorkdayKind::Work
+            | WorkdayKind::MorningHalfLeave
+            | WorkdayKind::AfternoonHalfLeave
+            | WorkdayKind::MorningQuarterLeave
+            | WorkdayKind::AfternoonQuarterLeave
+    ) {
+        return None;
+    }
+    let clock_in = cache.clock_in_time.as_deref()?;
+    let clock_out = cache.clock_out_time.as_deref()?;
+    let start_time = NaiveTime::parse_from_str(clock_in, "%H:%M").ok()?;
+    let end_time = NaiveTime::parse_from_str(clock_out, "%H:%M").ok()?;
+    let start = date.and_time(start_time);
+    let end = if end_time <= start_time {
+        (date + chrono::Days::new(1)).and_time(end_time)
+    } else {
+        date.and_time(end_time)
+    };
+    Some(ExportEvent {
+        uid: format!("{date}-shift@moa"),
+        summary: format!("근무 {clock_in}–{clock_out}"),
+        time: EventTime::Timed {
+            start: salary::work_time(settings, start),
+            end: salary::work_time(settings, end),
+        },
+    })
+}
+
+/// `[from, until]`의 월급날·휴가·근무 시각 일정 (날짜순)
+///
+/// `workday`는 날짜별 `WorkdayCache` 조회, `is_public_holiday`는 월급날 보정용.
+pub fn workday_events(
+    settings: &UserSettings,
+    from: NaiveDate,
+    until: NaiveDate,
+    workday: &dyn Fn(NaiveDate) -> Option<WorkdayCache>,
+    is_public_holiday: &dyn Fn(NaiveDate) -> bool,
+) -> Vec<ExportEvent> {
+    let paydays = salary::paydays_between(settings, from, until, is_public_holiday);
+    let mut events = vec![];
+    for date in from.iter_days().take_while(|day| *day <= until) {
+        let cache = workday(date);
+        let is_payday = paydays.contains(&date)
+            || cache
+                .as_ref()
+                .is_some_and(|cache| cache.events.contains(&WorkdayCacheEvent::Payday));
+        if is_payday {
+            events.push(ExportEvent {
+                uid: format!("{date}-payday@moa"),
+                summary: "월급날".to_string(),
+                time: EventTime::AllDay(date),
+            });
+        }
+        let Some(cache) = cache else {
+            continue;
+        };
+        if let Some(label) = leave_label(&cache.kind) {
+            events.push(ExportEvent {
+                uid: format!("{date}-leave@moa"),
+                summary: label.to_string(),
+                time: EventTime::AllDay(date),
+            });
+        }
+        events.extend(shift_event(settings, date, &cache));
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn cache(date: &str, kind: WorkdayKind, hours: Option<(&str, &str)>) -> WorkdayCache {
+        WorkdayCache {
+            date: date.into(),
+            kind,
+            clock_in_time: hours.map(|(s, _)| s.to_string()),
+            clock_out_time: hours.map(|(_, e)| e.to_string()),
+            completed: false,
+            events: vec![],
+            is_dirty: false,
+        }
+    }
+
+    #[test]
+    fn exports_paydays_leave_and_shift_overrides() {
+        let settings = UserSettings {
+            pay_day: 25,
+            work_days: vec![1, 2, 3, 4, 5],
+            work_timezone: Some("Asia/Seoul".to_string()),
+            ..Default::default()
+        };
+        let workday = |day: NaiveDate| match day.format("%Y-%m-%d").to_string().as_str() {
+            "2025-02-10" => Some(cache("2025-02-10", WorkdayKind::AnnualLeave, None)),
+            "2025-02-12" => Some(cache(
+                "2025-02-12",
+                WorkdayKind::Work,
+                Some(("10:00", "19:00")),
+            )),
+            "2025-02-14" => Some(cache(
+                "2025-02-14",
+                WorkdayKind::Work,
+                Some(("22:00", "06:00")),
+            )),
+            "2025-02-20" => Some(WorkdayCache {
+                events: vec![WorkdayCacheEvent::Payday],
+                ..cache("2025-02-20", WorkdayKind::Work, None)
+            }),
+            // 반차의 출퇴근 override도 급여 계산에 쓰므로 휴가와 근무 일정을 모두 내보낸다
+            "2025-03-05" => Some(cache(
+                "2025-03-05",
+                WorkdayKind::MorningHalfLeave,
+                Some(("14:00", "18:00")),
+            )),
+            // 종일 휴가는 출퇴근 override를 쓰지 않는다
+            "2025-03-06" => Some(cache(
+                "2025-03-06",
+                WorkdayKind::SickLeave,
+                Some(("09:00", "18:00")),
+            )),
+            _ => None,
+        };
+        let events = workday_events(
+            &settings,
+            date("2025-02-01"),
+            date("2025-03-31"),
+            &workday,
+            &|_| false,
+        );
+        let summaries: Vec<(String, &str)> = events
+            .iter()
+            .map(|e| (e.uid.clone(), e.summary.as_str()))
+            .collect();
+        assert_eq!(
+            summaries,
+            vec![
+                ("2025-02-10-leave@moa".to_string(), "연차"),
+                ("2025-02-12-shift@moa".to_string(), "근무 10:00–19:00"),
+                ("2025-02-14-shift@moa".to_string(), "근무 22:00–06:00"),
+                ("2025-02-20-payday@moa".to_string(), "월급날"),
+                ("2025-02-25-payday@moa".to_string(), "월급날"),
+                ("2025-03-05-leave@moa".to_string(), "오전 반차"),
+                ("2025-03-05-shift@moa".to_string(), "근무 14:00–18:00"),
+                ("2025-03-06-leave@moa".to_string(), "병가"),
+                ("2025-03-25-payday@moa".to_string(), "월급날"),
+            ]
+        );
+        assert_eq!(
+            events[2].time,
+            EventTime::Timed {
+                start: chrono::DateTime::parse_from_rfc3339("2025-02-14T22:00:00+09:00").unwrap(),
+                end: chrono::DateTime::parse_from_rfc3339("2025-02-15T06:00:00+09:00").unwrap(),
+            }
+        );
+    }
+}
@@ -706,10 +706,7 @@ pub async fn complete_onboarding(app: AppHandle, nickname: String) -> Result<(),
         .unwrap_or_else(|_| "https://www.moa-official.kr".to_string());
     let api = ApiClient::new(&base_url);
 
-    let payroll_req = PayrollPatchRequest {
-        salary_input_type: to_server_salary_type(&settings.salary_type),
-        salary_amount: settings.salary_amount as i64,
-    };
+    let payroll_req = payroll_request(&settings);
     let work_policy_req = WorkPolicyPatchRequest {
         workdays: settings
             .work_days
@@ -770,10 +767,7 @@ pub async fn sync_settings_to_server(app: AppHandle) -> Result<(), String> {
     let api = ApiClient::new(&base_url);
 
     // payroll
-    let payroll_req = PayrollPatchRequest {
-        salary_input_type: to_server_salary_type(&settings.salary_type),
-        salary_amount: settings.salary_amount as i64,
-    };
+    let payroll_req = payroll_request(&settings);
     if let Err(e) = api.patch_payroll(&token, &payroll_req).await {
         if handle_api_error(&app, &e, "payroll push") {
             return Ok(()); // 토큰 만료 — 이후 PATCH 중단
@@ -870,11 +864,12 @@ pub async fn sync_from_server(app: AppHandle) -> Result<(), String> {
 
     // 로컬 설정 로드
     let mut settings = load_local_settings(&app)?;
-    let changed = merge_server_to_local(&mut settings, &payroll, &work_policy, &profile);
+    let today = salary::work_now(&settings).format("%Y-%m-%d").to_string();
+    let changed = merge_server_to_local(&mut settings, &payroll, &work_policy, &profile, &today);
 
     if changed {
         save_user_settings_sync(&app, &settings)?;
-        salary::notify_settings_changed();
+        salary::ticker::notify_settings_changed();
         log::info!("서버 → 로컬 sync 완료 (변경 있음)");
     } else {
         log::debug!("서버 → 로컬 sync: 변경 없음");
@@ -886,7 +881,6 @@ pub async fn sync_from_server(app: AppHandle) -> Result<(), String> {
     }
 
     // workday 동기화 (실패해도 다른 sync 진행 — fetch_workday가 내부 fallback 처리)
-    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
     if let Err(e) = crate::commands::workday::fetch_workday(app.clone(), today).await {
         log::warn!("fetch_workday 실패 — 다른 sync는 정상 진행: {e}");
     }
@@ -915,11 +909,12 @@ async fn sync_after_login(app: &AppHandle, api: &ApiClient, token: &str) -> Resu
         let profile = status.profile.unwrap();
 
         let mut settings = load_local_settings(app).unwrap_or_default();
-        merge_server_to_local(&mut settings, &payroll, &work_policy, &profile);
+        let today = salary::work_now(&settings).format("%Y-%m-%d").to_string();
+        merge_server_to_local(&mut settings, &payroll, &work_policy, &profile, &today);
         settings.onboarding_completed = true;
         settings.terms_agreed = status.has_required_terms_agreed;
         save_user_settings_sync(app, &settings)?;
-        salary::notify_settings_changed();
+        salary::ticker::notify_settings_changed();
         log::info!("서버 데이터 → 로컬 sync 완료");
         Ok(false) // 온보딩 불필요
     } else {
@@ -937,14 +932,20 @@ async fn sync_after_login(app: &AppHandle, api: &ApiClient, token: &str) -> Resu
 }
 
 /// 서버 데이터를 로컬 설정에 머지. 변경 여부 반환.
+///
+/// 급여가 바뀌었으면 덮어쓰지 않고 `today`부터 적용되는 급여 이력을 추가해
+/// 지난 날짜 계산이 이전 급여를 유지하게 한다.
 fn merge_server_to_local(
     settings: &mut UserSettings,
     payroll: &crate::api_client::PayrollResponse,
     work_policy: &crate::api_client::WorkPolicyResponse,
     profile: &crate::api_client::ProfileResponse,
+    today: &str,
 ) -> bool {
     let new_salary_type = from_server_salary_type(&payroll.salary_input_type);
     let new_amount = u32::try_from(payroll.salary_amount.max(0)).unwrap_or(settings.salary_amount);
+    // 오늘부터 적용되는 이력 항목으로 추가 (지난 날짜는 이전 급여 유지)
+    let salary_changed = settings.record_salary_change(new_salary_type, new_amount, today);
     let new_pay_day = if (1..=31).contains(&profile.payday_day) {
         profile.payday_day as u8
     } else {
@@ -958,23 +959,45 @@ fn merge_server_to_local(
     let new_start = &work_policy.clock_in_time;
     let new_end = &work_policy.clock_out_time;
 
-    let changed = settings.salary_type != new_salary_type
-        || settings.salary_amount != new_amount
-        || settings.pay_day != new_pay_day
+    let changed = settings.pay_day != new_pay_day
         || settings.work_days != new_work_days
         || settings.work_start_time != *new_start
         || settings.work_end_time != *new_end;
 
     if changed {
-        settings.salary_type = new_salary_type;
-        settings.salary_amount = new_amount;
         settings.pay_day = new_pay_day;
         settings.work_days = new_work_days;
         settings.work_start_time = new_start.clone();
         settings.work_end_time = new_end.clone();
     }
 
-    changed
+    // 서버 work-policy는 전역 출퇴근 시간만 가진다. 로컬 요일별 시간은 유지하되
+    // 근무 요일에서 빠졌거나 새 전역 시간과 같아진 항목은 정리한다.
+    let weekday_hours_before = settings.weekday_hours.len();
+    let (work_days, start, end) = (
+        settings.work_days.clone(),
+        settings.work_start_time.clone(),
+        settings.work_end_time.clone(),
+    );
+    settings
+        .weekday_hours
+        .retain(|h| work_days.contains(&h.weekday) && (h.start_time != start || h.end_time != end));
+
+    salary_changed || changed || settings.weekday_hours.len() != weekday_hours_before
+}
+
+/// 오늘 적용 중인 급여(지급 비율 적용 전 계약 금액)로 payroll PATCH 요청 구성
+fn payroll_request(settings: &UserSettings) -> PayrollPatchRequest {
+    let today = salary::work_now(settings).format("%Y-%m-%d").to_string();
+    let (salary_type, salary_amount) = settings
+        .salary_version_on(&today)
+        .map_or((&settings.salary_type, settings.salary_amount), |version| {
+            (&version.salary_type, version.salary_amount)
+        });
+    PayrollPatchRequest {
+        salary_input_type: to_server_salary_type(salary_type),
+        salary_amount: salary_amount as i64,
+    }
 }
 
 /// 로컬 설정을 서버에 push (best-effort, 401 감지 시 조기 중단)
@@ -984,16 +1007,14 @@ async fn push_local_to_server(
     token: &str,
     settings: &UserSettings,
 ) {
-    let payroll_req = PayrollPatchRequest {
-        salary_input_type: to_server_salary_type(&settings.salary_type),
-        salary_amount: settings.salary_amount as i64,
-    };
+    let payroll_req = payroll_request(settings);
     let work_policy_req = WorkPolicyPatchRequest {
         workdays: settings
             .work_days
             .iter()
             .filter_map(|&d| Weekday::from_local_index(d))
             .collect(),
+        // 요일별 시간(`weekday_hours`)은 서버 모델에 없어 로컬 전용 — 전역 시간만 push
         clock_in_time: settings.work_start_time.clone(),
         clock_out_time: settings.work_end_time.clone(),
     };
@@ -1029,6 +1050,7 @@ fn to_server_salary_type(t: &SalaryType) -> SalaryInputType {
     match t {
         SalaryType::Yearly => SalaryInputType::Annual,
         SalaryType::Monthly => SalaryInputType::Monthly,
+        SalaryType::Hourly => SalaryInputType::Hourly,
     }
 }
 
@@ -1036,6 +1058,7 @@ fn from_server_salary_type(t: &SalaryInputType) -> SalaryType {
     match t {
         SalaryInputType::Annual => SalaryType::Yearly,
         SalaryInputType::Monthly => SalaryType::Monthly,
+        SalaryInputType::Hourly => SalaryType::Hourly,
     }
 }
 
@@ -1182,4 +1205,110 @@ mod tests {
         assert!(url.contains("state=state-value"));
         assert!(!url.contains("scope="));
     }
+
+    /// 월급 `salary_amount`, 평일 10–19시, 25일 급여일인 서버 응답
+    fn server_settings(
+        salary_amount: i64,
+    ) -> (
+        crate::api_client::PayrollResponse,
+        crate::api_client::WorkPolicyResponse,
+        crate::api_client::ProfileResponse,
+    ) {
+        use crate::api_client::{
+            PayrollResponse, ProfileResponse, SalaryInputType, Weekday, WorkPolicyResponse,
+        };
+        (
+            PayrollResponse {
+                salary_input_type: SalaryInputType::Monthly,
+                salary_amount,
+            },
+            WorkPolicyResponse {
+                workdays: vec![
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                    Weekday::Fri,
+                ],
+                clock_in_time: "10:00".to_string(),
+                clock_out_time: "19:00".to_string(),
+            },
+            ProfileResponse {
+                nickname: "moa".to_string(),
+                workplace: None,
+                payday_day: 25,
+            },
+        )
+    }
+
+    #[test]
+    fn merge_keeps_weekday_hours_for_remaining_work_days() {
+        use crate::types::WeekdayHours;
+
+        let hours = |weekday: u8, start: &str, end: &str| WeekdayHours {
+            weekday,
+            start_time: start.to_string(),
+            end_time: end.to_string(),
+        };
+        let mut settings = UserSettings {
+            salary_amount: 3_000_000,
+            weekday_hours: vec![
+                hours(5, "09:00", "16:00"),
+                hours(6, "10:00", "14:00"),
+                hours(1, "10:00", "19:00"),
+            ],
+            ..Default::default()
+        };
+        let (payroll, work_policy, profile) = server_settings(3_000_000);
+
+        assert!(merge_server_to_local(
+            &mut settings,
+            &payroll,
+            &work_policy,
+            &profile,
+            "2025-03-10"
+        ));
+        assert_eq!(settings.work_start_time, "10:00");
+        // 토요일(근무 요일 아님)·월요일(전역과 동일)은 정리, 짧은 금요일은 유지
+        assert_eq!(settings.weekday_hours, vec![hours(5, "09:00", "16:00")]);
+    }
+
+    #[test]
+    fn merge_appends_salary_version_dated_today() {
+        let mut settings = UserSettings {
+            salary_amount: 3_000_000,
+            hire_date: Some("2024-07-01".to_string()),
+            ..Default::default()
+        };
+        let (payroll, work_policy, profile) = server_settings(3_300_000);
+        assert!(merge_server_to_local(
+            &mut settings,
+            &payroll,
+            &work_policy,
+            &profile,
+            "2025-03-10"
+        ));
+
+        // 덮어쓰지 않고 오늘부터 적용되는 항목 추가
+        assert_eq!(settings.salary_amount, 3_300_000);
+        let amounts: Vec<(&str, u32)> = settings
+            .salary_history
+            .iter()
+            .map(|version| (version.effective_from.as_str(), version.salary_amount))
+            .collect();
+        assert_eq!(
+            amounts,
+            vec![("2024-07-01", 3_000_000), ("2025-03-10", 3_300_000)]
+        );
+
+        // 같은 응답을 다시 머지하면 변경 없음
+        assert!(!merge_server_to_local(
+            &mut settings,
+            &payroll,
+            &work_policy,
+            &profile,
+            "2025-03-11"
+        ));
+        assert_eq!(settings.salary_history.len(), 2);
+    }
 }
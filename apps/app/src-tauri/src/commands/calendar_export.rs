@@ -0,0 +1,203 @@
+//! 근무 일정 .ics 내보내기 커맨드
+//!
+//! 날짜 범위를 골라 파일로 내보내거나, `calendar_feed_enabled`이면 앱 데이터 디렉토리의
+//! 구독 파일(`moa-workdays.ics`)을 `workday-changed`마다 다시 쓴다. 다시 쓰기는 백그라운드
+//! 스레드에서 하고, 서버 sync처럼 몰려온 알림은 잠잠해진 뒤 한 번만 처리한다.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use chrono::{Months, NaiveDate};
+use tauri::{AppHandle, Listener, Manager};
+use tauri_plugin_dialog::DialogExt;
+
+use crate::calendar_export::{workday_events, CALENDAR_NAME};
+use crate::commands::auth::load_local_settings;
+use crate::commands::workday::load_workday_cache;
+use crate::holidays::HolidayCalendar;
+use crate::types::{UserSettings, WorkdayCache};
+use crate::{ics, salary};
+
+/// 구독 파일 이름
+const FEED_FILE: &str = "moa-workdays.ics";
+/// 구독 파일 범위: 오늘 기준 몇 달 전부터 몇 달 후까지
+const FEED_MONTHS_BEFORE: u32 = 3;
+const FEED_MONTHS_AFTER: u32 = 12;
+/// 한 번에 내보낼 수 있는 최대 일수
+const MAX_EXPORT_DAYS: i64 = 366 * 3;
+/// 마지막 갱신 요청 후 이만큼 조용하면 구독 파일을 다시 쓴다
+const FEED_DEBOUNCE: Duration = Duration::from_secs(2);
+
+type FeedChannel = (Sender<()>, Mutex<Option<Receiver<()>>>);
+
+/// 구독 파일 갱신 요청. 수신 측은 `start_calendar_feed`가 가져간다.
+static FEED_CHANNEL: LazyLock<FeedChannel> = LazyLock::new(|| {
+    let (sender, receiver) = mpsc::channel();
+    (sender, Mutex::new(Some(receiver)))
+});
+
+/// 구독 파일 경로
+fn get_feed_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("앱 데이터 디렉토리 접근 실패: {e}"))?;
+
+    std::fs::create_dir_all(&app_data_dir).map_err(|e| format!("디렉토리 생성 실패: {e}"))?;
+
+    Ok(app_data_dir.join(FEED_FILE))
+}
+
+/// `[from, until]` 근무 일정 .ics 내용. 공휴일은 ticker와 같이 내장 달력으로 채운다
+fn calendar_contents(
+    app: &AppHandle,
+    settings: &UserSettings,
+    from: NaiveDate,
+    until: NaiveDate,
+) -> String {
+    let holidays = HolidayCalendar::load(app);
+    // 월급날 보정이 같은 날을 다시 물어도 파일은 날짜마다 한 번만 읽는다
+    let loaded: RefCell<HashMap<NaiveDate, Option<WorkdayCache>>> = RefCell::new(HashMap::new());
+    let workday = |date: NaiveDate| {
+        if let Some(cache) = loaded.borrow().get(&date) {
+            return cache.clone();
+        }
+        let cache = load_workday_cache(app, &date.format("%Y-%m-%d").to_string())
+            .ok()
+            .flatten();
+        let cache = salary::with_builtin_holiday(&holidays, date, cache);
+        loaded.borrow_mut().insert(date, cache.clone());
+        cache
+    };
+    let is_public_holiday =
+        |date| workday(date).is_some_and(|cache| salary::is_public_holiday_cache(&cache));
+    let events = workday_events(settings, from, until, &workday, &is_public_holiday);
+    ics::write(
+        CALENDAR_NAME,
+        settings.work_timezone.as_deref(),
+        &events,
+        chrono::Utc::now(),
+    )
+}
+
+/// 구독 파일을 다시 쓴다 (구독 스레드 전용). `calendar_feed_enabled`가 꺼져 있으면 지운다
+fn refresh_calendar_feed(app: &AppHandle) -> Result<(), String> {
+    let settings = load_local_settings(app)?;
+    let path = get_feed_path(app)?;
+
+    if !settings.calendar_feed_enabled || !settings.onboarding_completed {
+        if path.exists() {
+            std::fs::remove_file(&path).map_err(|e| format!("구독 파일 삭제 실패: {e}"))?;
+        }
+        return Ok(());
+    }
+
+    let today = salary::work_now(&settings).date_naive();
+    let from = today
+        .checked_sub_months(Months::new(FEED_MONTHS_BEFORE))
+        .unwrap_or(today);
+    let until = today
+        .checked_add_months(Months::new(FEED_MONTHS_AFTER))
+        .unwrap_or(today);
+    let contents = calendar_contents(app, &settings, from, until);
+
+    // Atomic write
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, contents).map_err(|e| format!("임시 파일 쓰기 실패: {e}"))?;
+    std::fs::rename(&temp_path, &path).map_err(|e| format!("파일 저장 실패: {e}"))?;
+    Ok(())
+}
+
+/// 구독 파일 갱신 요청 (설정 저장 등). 바로 돌아오고, 구독 스레드가 잠시 뒤 다시 쓴다
+pub(crate) fn request_calendar_feed_refresh() {
+    // 수신 측은 앱이 끝날 때까지 살아 있으므로 실패는 무시
+    let _ = FEED_CHANNEL.0.send(());
+}
+
+/// `workday-changed`마다 구독 파일 갱신. 앱 시작 시 한 번 호출한다
+pub fn start_calendar_feed(app: &AppHandle) {
+    let Some(receiver) = FEED_CHANNEL
+        .1
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+    else {
+        log::warn!("근무 일정 구독 스레드가 이미 실행 중입니다");
+        return;
+    };
+    app.listen_any("workday-changed", |_| request_calendar_feed_refresh());
+
+    let app = app.clone();
+    std::thread::spawn(move || {
+        // 앱이 꺼져 있는 동안 바뀐 범위·캐시 반영
+        loop {
+            if let Err(e) = refresh_calendar_feed(&app) {
+                log::warn!("근무 일정 구독 파일 갱신 실패: {e}");
+            }
+            if receiver.recv().is_err() {
+                break;
+            }
+            loop {
+                match receiver.recv_timeout(FEED_DEBOUNCE) {
+                    Ok(()) => {}
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+            }
+        }
+    });
+}
+
+/// `from`부터 `to`까지(포함) 월급날·휴가·근무 시각 일정을 .ics로 저장. 취소하면 None, 저장하면 경로
+#[tauri::command]
+#[specta::specta]
+pub async fn export_workday_calendar(
+    app: AppHandle,
+    from: String,
+    to: String,
+) -> Result<Option<String>, String> {
+    let parse = |date: &str| NaiveDate::parse_from_str(date, "%Y-%m-%d");
+    let (Ok(from), Ok(until)) = (parse(&from), parse(&to)) else {
+        return Err("시작일과 종료일은 YYYY-MM-DD 형식이어야 합니다".to_string());
+    };
+    if until < from {
+        return Err("종료일이 시작일보다 빠릅니다".to_string());
+    }
+    if (until - from).num_days() >= MAX_EXPORT_DAYS {
+        return Err("내보내기 기간은 3년 이내여야 합니다".to_string());
+    }
+    let settings = load_local_settings(&app)?;
+    let contents = calendar_contents(&app, &settings, from, until);
+
+    // 창이 열려 있는 동안 async 런타임 스레드를 막지 않도록 콜백으로 받는다
+    let (sender, mut receiver) = tauri::async_runtime::channel(1);
+    app.dialog()
+        .file()
+        .set_title("근무 일정 내보내기")
+        .set_file_name(FEED_FILE)
+        .add_filter("iCalendar", &["ics"])
+        .save_file(move |file| {
+            let _ = sender.try_send(file);
+        });
+    let Some(file) = receiver.recv().await.flatten() else {
+        return Ok(None);
+    };
+    let path = file
+        .into_path()
+        .map_err(|e| format!("파일 경로 확인 실패: {e}"))?;
+    std::fs::write(&path, contents).map_err(|e| format!("달력 파일 저장 실패: {e}"))?;
+
+    log::info!("근무 일정 내보내기: {from} ~ {until}");
+    Ok(Some(path.to_string_lossy().into_owned()))
+}
+
+/// 근무 일정 구독 파일 경로 (`calendar_feed_enabled`일 때만 존재)
+#[tauri::command]
+#[specta::specta]
+pub async fn get_calendar_feed_path(app: AppHandle) -> Result<String, String> {
+    Ok(get_feed_path(&app)?.to_string_lossy().into_owned())
+}
@@ -0,0 +1,136 @@
+//! 회사 휴무일 커맨드 (.ics 가져오기)
+//!
+//! 가져온 일정은 달력별로 반복 규칙째 `company-holidays.json`에 저장하고, 급여 ticker가
+//! `HolidayCalendar::load`로 읽어 공휴일과 같게 계산한다.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Manager};
+use tauri_plugin_dialog::DialogExt;
+
+use crate::commands::auth::load_local_settings;
+use crate::holidays::{expand_company_calendar, expand_company_holidays};
+use crate::types::{CompanyHoliday, CompanyHolidayBook};
+use crate::{ics, salary};
+
+static COMPANY_HOLIDAYS_LOCK: Mutex<()> = Mutex::new(());
+
+/// 회사 휴무일 파일 경로
+fn get_company_holidays_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("앱 데이터 디렉토리 접근 실패: {e}"))?;
+
+    std::fs::create_dir_all(&app_data_dir).map_err(|e| format!("디렉토리 생성 실패: {e}"))?;
+
+    Ok(app_data_dir.join("company-holidays.json"))
+}
+
+fn read_book(app: &AppHandle) -> Result<CompanyHolidayBook, String> {
+    let path = get_company_holidays_path(app)?;
+    if !path.exists() {
+        return Ok(CompanyHolidayBook::new());
+    }
+    let contents =
+        std::fs::read_to_string(&path).map_err(|e| format!("회사 휴무일 파일 읽기 실패: {e}"))?;
+    serde_json::from_str(&contents).map_err(|e| format!("회사 휴무일 파싱 실패: {e}"))
+}
+
+fn write_book(app: &AppHandle, book: &CompanyHolidayBook) -> Result<(), String> {
+    let path = get_company_holidays_path(app)?;
+    let json = serde_json::to_string_pretty(book).map_err(|e| format!("직렬화 실패: {e}"))?;
+
+    // Atomic write
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, &json).map_err(|e| format!("임시 파일 쓰기 실패: {e}"))?;
+    std::fs::rename(&temp_path, &path).map_err(|e| format!("파일 저장 실패: {e}"))?;
+
+    // ticker가 다음 tick에 달력을 다시 읽도록
+    salary::ticker::notify_settings_changed();
+    Ok(())
+}
+
+/// 목록에 펼칠 끝 날짜 (근무 시간대 기준 내년 말)
+fn horizon(app: &AppHandle) -> chrono::NaiveDate {
+    let today = load_local_settings(app)
+        .map(|settings| salary::work_now(&settings).date_naive())
+        .unwrap_or_else(|_| chrono::Local::now().date_naive());
+    ics::expansion_horizon(today)
+}
+
+/// 파일 선택 창에서 고른 .ics를 회사 휴무일로 가져온다. 취소하면 None
+///
+/// 같은 달력을 다시 가져오면 그 달력의 기존 일정을 바꾼다. 돌려주는 목록은 반복 일정을 내년 말까지 펼친 것.
+#[tauri::command]
+#[specta::specta]
+pub async fn import_company_holidays(
+    app: AppHandle,
+) -> Result<Option<Vec<CompanyHoliday>>, String> {
+    // 창이 열려 있는 동안 async 런타임 스레드를 막지 않도록 콜백으로 받는다
+    let (sender, mut receiver) = tauri::async_runtime::channel(1);
+    app.dialog()
+        .file()
+        .set_title("회사 휴무일 달력 가져오기")
+        .add_filter("iCalendar", &["ics"])
+        .pick_file(move |file| {
+            let _ = sender.try_send(file);
+        });
+    let Some(file) = receiver.recv().await.flatten() else {
+        return Ok(None);
+    };
+    let path = file
+        .into_path()
+        .map_err(|e| format!("파일 경로 확인 실패: {e}"))?;
+    let contents =
+        std::fs::read_to_string(&path).map_err(|e| format!("달력 파일 읽기 실패: {e}"))?;
+    let settings = load_local_settings(&app).ok();
+    let calendar = ics::parse(&contents, settings.as_ref().and_then(|s| s.work_tz()))?;
+
+    let name = calendar
+        .name
+        .clone()
+        .or_else(|| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+        })
+        .unwrap_or_else(|| "회사 달력".to_string());
+    let holidays = expand_company_calendar(&name, &calendar.events, horizon(&app));
+
+    let _guard = COMPANY_HOLIDAYS_LOCK
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    let mut book = read_book(&app)?;
+    book.insert(name.clone(), calendar.events);
+    write_book(&app, &book)?;
+
+    log::info!("회사 휴무일 가져오기: {name} ({}일)", holidays.len());
+    Ok(Some(holidays))
+}
+
+/// 가져온 회사 휴무일 전체 (날짜순, 반복 일정은 내년 말까지)
+#[tauri::command]
+#[specta::specta]
+pub async fn get_company_holidays(app: AppHandle) -> Result<Vec<CompanyHoliday>, String> {
+    let until = horizon(&app);
+    let _guard = COMPANY_HOLIDAYS_LOCK
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    Ok(expand_company_holidays(&read_book(&app)?, until))
+}
+
+/// `calendar`에서 가져온 회사 휴무일 삭제
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_company_holiday_calendar(
+    app: AppHandle,
+    calendar: String,
+) -> Result<(), String> {
+    let _guard = COMPANY_HOLIDAYS_LOCK
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    let mut book = read_book(&app)?;
+    book.remove(&calendar);
+    write_book(&app, &book)
+}
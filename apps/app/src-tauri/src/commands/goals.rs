@@ -0,0 +1,137 @@
+//! 저축·구매 목표 관리 커맨드
+//!
+//! 목표 목록은 `goals.json` 하나에 저장한다. 달성 기록(`achieved_on`)은
+//! 급여 ticker가 남기므로, 파일 읽기-수정-쓰기는 모두 `GOALS_LOCK` 안에서 한다.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+use crate::types::{validate_goal, Goal};
+
+static GOALS_LOCK: Mutex<()> = Mutex::new(());
+
+/// 목표 파일 경로
+fn get_goals_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("앱 데이터 디렉토리 접근 실패: {e}"))?;
+
+    std::fs::create_dir_all(&app_data_dir).map_err(|e| format!("디렉토리 생성 실패: {e}"))?;
+
+    Ok(app_data_dir.join("goals.json"))
+}
+
+fn read_goals_file(app: &AppHandle) -> Result<Vec<Goal>, String> {
+    let path = get_goals_path(app)?;
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let contents =
+        std::fs::read_to_string(&path).map_err(|e| format!("목표 파일 읽기 실패: {e}"))?;
+    serde_json::from_str(&contents).map_err(|e| format!("목표 파싱 실패: {e}"))
+}
+
+fn write_goals(app: &AppHandle, goals: &[Goal]) -> Result<(), String> {
+    let path = get_goals_path(app)?;
+    let json = serde_json::to_string_pretty(goals).map_err(|e| format!("직렬화 실패: {e}"))?;
+
+    // Atomic write
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, &json).map_err(|e| format!("임시 파일 쓰기 실패: {e}"))?;
+    std::fs::rename(&temp_path, &path).map_err(|e| format!("파일 저장 실패: {e}"))?;
+
+    crate::salary::ticker::notify_goals_changed();
+    Ok(())
+}
+
+/// 목표 파일을 잠근 채 수정하고 저장
+fn update_goals<T>(
+    app: &AppHandle,
+    update: impl FnOnce(&mut Vec<Goal>) -> Result<T, String>,
+) -> Result<T, String> {
+    let _guard = GOALS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut goals = read_goals_file(app)?;
+    let result = update(&mut goals)?;
+    write_goals(app, &goals)?;
+    Ok(result)
+}
+
+/// 목표 파일을 잠근 채 읽기
+pub(crate) fn read_goals(app: &AppHandle) -> Result<Vec<Goal>, String> {
+    let _guard = GOALS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    read_goals_file(app)
+}
+
+/// 달성한 목표에 축하 날짜 기록 (ticker 전용). 지난 날짜 기록은 새로 덮어쓴다
+pub(crate) fn mark_goals_achieved(
+    app: &AppHandle,
+    ids: &[String],
+    date: &str,
+) -> Result<(), String> {
+    update_goals(app, |goals| {
+        for goal in goals.iter_mut().filter(|goal| ids.contains(&goal.id)) {
+            if goal
+                .achieved_on
+                .as_deref()
+                .is_none_or(|achieved| achieved < date)
+            {
+                goal.achieved_on = Some(date.to_string());
+            }
+        }
+        Ok(())
+    })
+}
+
+/// 목표 목록 불러오기
+#[tauri::command]
+#[specta::specta]
+pub async fn load_goals(app: AppHandle) -> Result<Vec<Goal>, String> {
+    read_goals(&app)
+}
+
+/// 목표 추가 또는 수정 (같은 id면 교체). 저장 후 전체 목록 반환
+///
+/// 금액·기준을 바꾸면 달성 기록을 지워 다시 축하할 수 있게 한다.
+#[tauri::command]
+#[specta::specta]
+pub async fn save_goal(app: AppHandle, goal: Goal) -> Result<Vec<Goal>, String> {
+    validate_goal(&goal)?;
+    update_goals(&app, |goals| {
+        match goals.iter_mut().find(|existing| existing.id == goal.id) {
+            Some(existing) => {
+                let retargeted =
+                    existing.target_amount != goal.target_amount || existing.basis != goal.basis;
+                let achieved_on = if retargeted {
+                    None
+                } else {
+                    existing.achieved_on.clone()
+                };
+                *existing = Goal {
+                    achieved_on,
+                    ..goal
+                };
+            }
+            None => goals.push(Goal {
+                achieved_on: None,
+                ..goal
+            }),
+        }
+        Ok(goals.clone())
+    })
+}
+
+/// 목표 삭제. 저장 후 전체 목록 반환
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_goal(app: AppHandle, id: String) -> Result<Vec<Goal>, String> {
+    update_goals(&app, |goals| {
+        let before = goals.len();
+        goals.retain(|goal| goal.id != id);
+        if goals.len() == before {
+            return Err("목표를 찾을 수 없습니다".to_string());
+        }
+        Ok(goals.clone())
+    })
+}
@@ -0,0 +1,138 @@
+//! 연차 잔여 관리 커맨드
+//!
+//! 날짜별 사용 기록과 수동 조정은 `leave-balance.json` 하나에 저장한다.
+//! 부여 일수·잔여 계산은 `crate::leave`가 입사일 기준으로 한다.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use chrono::NaiveDate;
+use tauri::{AppHandle, Manager};
+
+use crate::commands::auth::load_local_settings;
+use crate::holidays::HolidayCalendar;
+use crate::leave::{self, LeaveAccrual, LeaveBalance};
+use crate::salary;
+use crate::types::{validate_leave_adjustment, LeaveAdjustment, LeaveLedger, WorkdayKind};
+
+static LEAVE_LOCK: Mutex<()> = Mutex::new(());
+
+/// 연차 장부 파일 경로
+fn get_leave_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("앱 데이터 디렉토리 접근 실패: {e}"))?;
+
+    std::fs::create_dir_all(&app_data_dir).map_err(|e| format!("디렉토리 생성 실패: {e}"))?;
+
+    Ok(app_data_dir.join("leave-balance.json"))
+}
+
+fn read_ledger(app: &AppHandle) -> Result<LeaveLedger, String> {
+    let path = get_leave_path(app)?;
+    if !path.exists() {
+        return Ok(LeaveLedger::default());
+    }
+    let contents =
+        std::fs::read_to_string(&path).map_err(|e| format!("연차 파일 읽기 실패: {e}"))?;
+    serde_json::from_str(&contents).map_err(|e| format!("연차 파싱 실패: {e}"))
+}
+
+fn write_ledger(app: &AppHandle, ledger: &LeaveLedger) -> Result<(), String> {
+    let path = get_leave_path(app)?;
+    let json = serde_json::to_string_pretty(ledger).map_err(|e| format!("직렬화 실패: {e}"))?;
+
+    // Atomic write
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, &json).map_err(|e| format!("임시 파일 쓰기 실패: {e}"))?;
+    std::fs::rename(&temp_path, &path).map_err(|e| format!("파일 저장 실패: {e}"))?;
+    Ok(())
+}
+
+/// 입사일과 근무 시간대 기준 오늘
+fn hire_date_and_today(app: &AppHandle) -> Result<(NaiveDate, NaiveDate), String> {
+    let settings = load_local_settings(app)?;
+    let hire = settings
+        .hire_date
+        .as_deref()
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        .ok_or("입사일을 먼저 설정해주세요")?;
+    Ok((hire, salary::work_now(&settings).date_naive()))
+}
+
+/// 날짜의 연차 사용 기록 (`save_workday_cache` 전용)
+///
+/// 날짜마다 한 번만 차감되도록 덮어쓰고, 연차 종류가 아니게 바뀌거나 소정 근무일이 아니면
+/// 기록을 지운다.
+pub(crate) fn record_leave(app: &AppHandle, date: &str, kind: &WorkdayKind) -> Result<(), String> {
+    let days = if leave::leave_days(kind) > 0.0 {
+        let day = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|e| format!("날짜 형식 오류: {e}"))?;
+        let settings = load_local_settings(app)?;
+        leave::debit_days(kind, day, &settings.work_days, &HolidayCalendar::load(app))
+    } else {
+        0.0
+    };
+    let _guard = LEAVE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut ledger = read_ledger(app)?;
+    let changed = if days > 0.0 {
+        ledger.debits.insert(date.to_string(), days) != Some(days)
+    } else {
+        ledger.debits.remove(date).is_some()
+    };
+    if changed {
+        write_ledger(app, &ledger)?;
+    }
+    Ok(())
+}
+
+/// 오늘 기준 연차 잔여
+#[tauri::command]
+#[specta::specta]
+pub async fn get_leave_balance(app: AppHandle) -> Result<LeaveBalance, String> {
+    let (hire, today) = hire_date_and_today(&app)?;
+    let _guard = LEAVE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    Ok(leave::balance_on(hire, &read_ledger(&app)?, today))
+}
+
+/// 입사일부터 오늘까지 연차 부여·조정 내역
+#[tauri::command]
+#[specta::specta]
+pub async fn get_leave_accruals(app: AppHandle) -> Result<Vec<LeaveAccrual>, String> {
+    let (hire, today) = hire_date_and_today(&app)?;
+    let _guard = LEAVE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    Ok(leave::accrual_history(hire, &read_ledger(&app)?, today))
+}
+
+/// 올해 12월 31일 기준 예상 잔여 (예약한 연차·그때까지 부여될 연차 반영)
+#[tauri::command]
+#[specta::specta]
+pub async fn get_year_end_leave_balance(app: AppHandle) -> Result<LeaveBalance, String> {
+    let (hire, today) = hire_date_and_today(&app)?;
+    let _guard = LEAVE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    Ok(leave::year_end_balance(hire, &read_ledger(&app)?, today))
+}
+
+/// 연차 수동 조정 (오늘 날짜로 기록). 조정 후 잔여 반환
+#[tauri::command]
+#[specta::specta]
+pub async fn adjust_leave_balance(
+    app: AppHandle,
+    days: f64,
+    note: Option<String>,
+) -> Result<LeaveBalance, String> {
+    let (hire, today) = hire_date_and_today(&app)?;
+    let adjustment = LeaveAdjustment {
+        date: today.format("%Y-%m-%d").to_string(),
+        days,
+        note: note.filter(|note| !note.trim().is_empty()),
+    };
+    validate_leave_adjustment(&adjustment)?;
+
+    let _guard = LEAVE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut ledger = read_ledger(&app)?;
+    ledger.adjustments.push(adjustment);
+    write_ledger(&app, &ledger)?;
+    Ok(leave::balance_on(hire, &ledger, today))
+}
@@ -5,7 +5,11 @@
 
 pub mod app;
 pub mod auth;
+pub mod calendar_export;
+pub mod company_holidays;
 pub mod confetti;
+pub mod goals;
+pub mod leave;
 pub mod menubar;
 pub mod migration;
 pub mod notifications;
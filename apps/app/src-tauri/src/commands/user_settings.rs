@@ -3,7 +3,13 @@
 use std::path::PathBuf;
 use tauri::{AppHandle, Manager};
 
-use crate::types::{validate_pay_day, validate_salary_amount, UserSettings};
+use crate::types::{
+    validate_bonuses, validate_break_times, validate_dependents, validate_exchange_rates,
+    validate_hire_date, validate_job_profiles, validate_pay_cycle, validate_pay_day,
+    validate_premium_rules, validate_salary_amount, validate_salary_history,
+    validate_sick_leave_pay_percent, validate_weekday_hours, validate_work_timezone,
+    validate_yearly_splits, UserSettings,
+};
 
 /// 사용자 설정 파일 경로
 pub(crate) fn get_user_settings_path(app: &AppHandle) -> Result<PathBuf, String> {
@@ -43,7 +49,20 @@ pub(crate) fn save_user_settings_sync(
     settings: &UserSettings,
 ) -> Result<(), String> {
     validate_salary_amount(settings.salary_amount)?;
+    validate_salary_history(&settings.salary_history)?;
     validate_pay_day(settings.pay_day)?;
+    validate_pay_cycle(settings.pay_cycle, settings.pay_anchor_date.as_deref())?;
+    validate_hire_date(settings.hire_date.as_deref())?;
+    validate_work_timezone(settings.work_timezone.as_deref())?;
+    validate_weekday_hours(&settings.weekday_hours)?;
+    validate_break_times(&settings.break_times)?;
+    validate_premium_rules(&settings.premium_rules)?;
+    validate_exchange_rates(&settings.exchange_rates)?;
+    validate_yearly_splits(settings.yearly_splits)?;
+    validate_dependents(settings.dependents)?;
+    validate_bonuses(&settings.bonuses)?;
+    validate_sick_leave_pay_percent(settings.sick_leave_pay_percent)?;
+    validate_job_profiles(&settings.job_profiles)?;
 
     let path = get_user_settings_path(app)?;
     let json = serde_json::to_string_pretty(settings).map_err(|e| format!("직렬화 실패: {e}"))?;
@@ -60,8 +79,21 @@ pub(crate) fn save_user_settings_sync(
 /// 사용자 설정 저장
 #[tauri::command]
 #[specta::specta]
-pub async fn save_user_settings(app: AppHandle, settings: UserSettings) -> Result<(), String> {
-    save_user_settings_sync(&app, &settings)
+pub async fn save_user_settings(app: AppHandle, mut settings: UserSettings) -> Result<(), String> {
+    // 급여가 바뀌었으면 오늘부터 적용되는 이력으로 남긴다 (지난 날짜는 이전 급여로 계산)
+    let stored = crate::commands::auth::load_local_settings(&app).unwrap_or_default();
+    let (salary_type, salary_amount) = (settings.salary_type.clone(), settings.salary_amount);
+    settings.salary_type = stored.salary_type;
+    settings.salary_amount = stored.salary_amount;
+    let today = crate::salary::work_now(&settings)
+        .format("%Y-%m-%d")
+        .to_string();
+    settings.record_salary_change(salary_type, salary_amount, &today);
+
+    save_user_settings_sync(&app, &settings)?;
+    // 구독 파일 켜기/끄기·급여일 변경 반영
+    crate::commands::calendar_export::request_calendar_feed_refresh();
+    Ok(())
 }
 
 /// 온보딩 완료 여부 확인
@@ -81,7 +113,14 @@ pub async fn reset_all_data(app: AppHandle) -> Result<(), String> {
         .app_data_dir()
         .map_err(|e| format!("앱 데이터 디렉토리 접근 실패: {e}"))?;
 
-    let files_to_delete = ["user-settings.json", "preferences.json"];
+    let files_to_delete = [
+        "user-settings.json",
+        "preferences.json",
+        "goals.json",
+        "leave-balance.json",
+        "company-holidays.json",
+        "moa-workdays.ics",
+    ];
 
     for filename in files_to_delete {
         let file_path = app_data_dir.join(filename);
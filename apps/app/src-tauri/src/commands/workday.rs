@@ -6,10 +6,11 @@
 //! - 로컬 캐시(`recovery/workday/{date}.json`) read/write
 //! - 서버 GET 응답 ↔ `WorkdayCache` 양방향 매핑
 //! - `fetch_workday`: 서버 우선 hydrate (`is_dirty=true`면 무시)
+//! - 추가 근무 프로필의 로컬 전용 캐시(`recovery/jobs/{id}/workday/{date}.json`)
 //!
 //! Write sync(`mutate_workday`)와 retry queue는 후속 단계에서 추가된다.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use serde::{Deserialize, Serialize};
@@ -21,8 +22,9 @@ use crate::api_client::{
     WorkdayType, WorkdayUpsertRequest,
 };
 use crate::auth;
+use crate::commands::leave;
 use crate::salary;
-use crate::types::{WorkdayCache, WorkdayCacheEvent, WorkdayKind};
+use crate::types::{validate_job_id, WorkdayCache, WorkdayCacheEvent, WorkdayKind};
 
 const SYNC_QUEUE_FILENAME: &str = "sync-queue.json";
 const MAX_RETRIES: u32 = 5;
@@ -107,29 +109,66 @@ fn cache_path(app: &AppHandle, date: &str) -> Result<PathBuf, String> {
     Ok(workday_dir(app)?.join(format!("{date}.json")))
 }
 
+/// 추가 근무 프로필의 workday 디렉토리. 서버 동기화 대상이 아니다.
+fn job_workday_dir(app: &AppHandle, job_id: &str) -> Result<PathBuf, String> {
+    validate_job_id(job_id)?;
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("app data dir 조회 실패: {e}"))?;
+    let dir = app_data_dir
+        .join("recovery")
+        .join("jobs")
+        .join(job_id)
+        .join("workday");
+    std::fs::create_dir_all(&dir).map_err(|e| format!("workday 디렉토리 생성 실패: {e}"))?;
+    Ok(dir)
+}
+
+fn validate_date(date: &str) -> Result<(), String> {
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map(|_| ())
+        .map_err(|_| format!("날짜 형식이 올바르지 않습니다: {date}"))
+}
+
 /// 로컬 캐시 로드. 파일 없으면 `Ok(None)`.
 pub fn load_workday_cache(app: &AppHandle, date: &str) -> Result<Option<WorkdayCache>, String> {
-    let path = cache_path(app, date)?;
+    read_cache_file(&cache_path(app, date)?)
+}
+
+fn read_cache_file(path: &Path) -> Result<Option<WorkdayCache>, String> {
     if !path.exists() {
         return Ok(None);
     }
-    let contents = std::fs::read_to_string(&path).map_err(|e| format!("cache read 실패: {e}"))?;
+    let contents = std::fs::read_to_string(path).map_err(|e| format!("cache read 실패: {e}"))?;
     let cache: WorkdayCache =
         serde_json::from_str(&contents).map_err(|e| format!("cache parse 실패: {e}"))?;
     Ok(Some(cache))
 }
 
 /// 로컬 캐시 저장 (원자적 write — `.tmp` → rename).
+///
+/// 사용자 변경·서버 동기화 모두 여기를 거치므로 연차 사용 기록도 함께 맞춘다.
 pub fn save_workday_cache(app: &AppHandle, cache: &WorkdayCache) -> Result<(), String> {
-    let path = cache_path(app, &cache.date)?;
+    write_cache_file(&cache_path(app, &cache.date)?, cache)?;
+    // 연차·반차는 날짜별로 한 번만 차감 (다른 종류로 바꾸면 복구)
+    if let Err(e) = leave::record_leave(app, &cache.date, &cache.kind) {
+        log::warn!("연차 사용 기록 실패: {e}");
+    }
+    Ok(())
+}
+
+fn write_cache_file(path: &Path, cache: &WorkdayCache) -> Result<(), String> {
     let content =
         serde_json::to_string_pretty(cache).map_err(|e| format!("cache 직렬화 실패: {e}"))?;
     let temp = path.with_extension("tmp");
     std::fs::write(&temp, content).map_err(|e| format!("임시 파일 write 실패: {e}"))?;
-    std::fs::rename(&temp, &path).map_err(|e| {
+    std::fs::rename(&temp, path).map_err(|e| {
         let _ = std::fs::remove_file(&temp);
         format!("rename 실패: {e}")
     })?;
+    // 지난 날짜 기록이 바뀌면 누적액 재계산
+    salary::ticker::invalidate_workday_history(&cache.date);
     Ok(())
 }
 
@@ -167,22 +206,32 @@ pub fn response_to_cache(response: WorkdayResponse) -> WorkdayCache {
     }
 }
 
-/// 캐시 → 서버 PUT body.
+/// 서버 type enum(OpenAPI: `WORK`·`VACATION`·`NONE`)에 대응 값이 있는 종류의 type.
+/// 반차·반반차·무급휴가·병가는 로컬 전용이라 None — 서버에 올리지 않는다.
 ///
 /// `PublicHoliday`는 사용자가 직접 토글하는 종류가 아니므로 안전한 default(`WORK`)로 매핑.
 /// 서버가 events를 자동 관리한다는 가정.
-pub fn cache_to_upsert(cache: &WorkdayCache) -> WorkdayUpsertRequest {
-    let workday_type = match cache.kind {
-        WorkdayKind::Work => WorkdayType::Work,
-        WorkdayKind::AnnualLeave => WorkdayType::Vacation,
-        WorkdayKind::DayOff => WorkdayType::None,
-        WorkdayKind::PublicHoliday => WorkdayType::Work,
-    };
-    WorkdayUpsertRequest {
-        workday_type,
+fn server_type_of(kind: &WorkdayKind) -> Option<WorkdayType> {
+    match kind {
+        WorkdayKind::Work | WorkdayKind::PublicHoliday => Some(WorkdayType::Work),
+        WorkdayKind::AnnualLeave => Some(WorkdayType::Vacation),
+        WorkdayKind::DayOff => Some(WorkdayType::None),
+        WorkdayKind::MorningHalfLeave
+        | WorkdayKind::AfternoonHalfLeave
+        | WorkdayKind::MorningQuarterLeave
+        | WorkdayKind::AfternoonQuarterLeave
+        | WorkdayKind::UnpaidLeave
+        | WorkdayKind::SickLeave => None,
+    }
+}
+
+/// 캐시 → 서버 PUT body. 로컬 전용 종류면 None
+pub fn cache_to_upsert(cache: &WorkdayCache) -> Option<WorkdayUpsertRequest> {
+    Some(WorkdayUpsertRequest {
+        workday_type: server_type_of(&cache.kind)?,
         clock_in_time: cache.clock_in_time.clone(),
         clock_out_time: cache.clock_out_time.clone(),
-    }
+    })
 }
 
 // ============================================================================
@@ -233,11 +282,15 @@ fn generate_id() -> String {
 }
 
 /// 큐에 항목 추가. 같은 날짜 항목이 있으면 교체(attempts 누적).
+/// 로컬 전용 종류면 그 날짜 항목을 지우기만 한다.
 pub(crate) fn enqueue_sync_failure(
     app: &AppHandle,
     cache: &WorkdayCache,
     error: &str,
 ) -> Result<(), String> {
+    let Some(request) = cache_to_upsert(cache) else {
+        return dequeue_sync(app, &cache.date);
+    };
     let mut queue = load_sync_queue(app);
     let existing_attempts = queue
         .iter()
@@ -246,7 +299,7 @@ pub(crate) fn enqueue_sync_failure(
         .unwrap_or(0);
     queue.retain(|e| e.date != cache.date);
 
-    let payload = SerializedUpsert::from(&cache_to_upsert(cache));
+    let payload = SerializedUpsert::from(&request);
     queue.push(SyncQueueEntry {
         id: generate_id(),
         kind: SyncQueueKind::PutWorkday,
@@ -259,6 +312,17 @@ pub(crate) fn enqueue_sync_failure(
     save_sync_queue(app, &queue)
 }
 
+/// `date`의 큐 항목 제거. 로컬 전용 종류로 바뀐 날에 이전 PUT이 나중에 올라가지 않게 한다
+fn dequeue_sync(app: &AppHandle, date: &str) -> Result<(), String> {
+    let mut queue = load_sync_queue(app);
+    let before = queue.len();
+    queue.retain(|e| e.date != date);
+    if queue.len() == before {
+        return Ok(());
+    }
+    save_sync_queue(app, &queue)
+}
+
 fn is_retryable_server_error(status: u16) -> bool {
     status >= 500
 }
@@ -268,6 +332,11 @@ async fn sync_dirty_workday_cache(
     date: &str,
     cache: &mut WorkdayCache,
 ) -> Result<(), String> {
+    // 로컬 전용 종류: 올리지 않고 dirty로 남겨 서버 폴링이 덮어쓰지 않게 한다
+    let Some(req) = cache_to_upsert(cache) else {
+        log::debug!("sync_dirty_workday_cache: 로컬 전용 종류 ({date}) — 서버 PUT 생략");
+        return dequeue_sync(app, date);
+    };
     if auth::get_access_token(app).is_none() {
         log::info!("sync_dirty_workday_cache: 비로그인 ({date}) — 큐 적재");
         enqueue_sync_failure(app, cache, "no-token")?;
@@ -276,7 +345,6 @@ async fn sync_dirty_workday_cache(
 
     let base_url = std::env::var("MOA_API_BASE_URL")
         .unwrap_or_else(|_| "https://www.moa-official.kr".to_string());
-    let req = cache_to_upsert(cache);
 
     let put_result = auth::with_token_retry(app, {
         let base_url = base_url.clone();
@@ -319,7 +387,6 @@ async fn sync_dirty_workday_cache(
                 Ok(response) => {
                     *cache = response_to_cache(response);
                     save_workday_cache(app, cache)?;
-                    salary::notify_settings_changed();
                     let _ = app.emit("workday-changed", date);
                 }
                 Err(e) => {
@@ -387,9 +454,12 @@ pub async fn flush_sync_queue(app: &AppHandle) -> Result<(), String> {
         match put_result {
             Ok(()) => {
                 log::info!("flush_sync_queue: PUT {} 성공", entry.date);
+                // 그 사이 로컬 전용 종류로 바뀐 캐시는 dirty 유지
                 if let Ok(Some(mut cache)) = load_workday_cache(app, &entry.date) {
-                    cache.is_dirty = false;
-                    let _ = save_workday_cache(app, &cache);
+                    if cache_to_upsert(&cache).is_some() {
+                        cache.is_dirty = false;
+                        let _ = save_workday_cache(app, &cache);
+                    }
                 }
             }
             Err(ApiError::Unauthorized) => {
@@ -487,8 +557,8 @@ pub async fn fetch_workday(app: AppHandle, date: String) -> Result<WorkdayCache,
         }
     };
 
-    let server_cache = response_to_cache(response);
     let local_cache = load_workday_cache(&app, &date)?;
+    let server_cache = response_to_cache(response);
 
     if let Some(local) = &local_cache {
         if local.is_dirty {
@@ -501,7 +571,6 @@ pub async fn fetch_workday(app: AppHandle, date: String) -> Result<WorkdayCache,
     if changed {
         save_workday_cache(&app, &server_cache)?;
         let _ = app.emit("workday-changed", &date);
-        salary::notify_settings_changed();
     }
 
     Ok(server_cache)
@@ -528,7 +597,6 @@ pub async fn clear_workday_schedule_override(
     cache.clock_out_time = None;
     cache.is_dirty = true;
     save_workday_cache(&app, &cache)?;
-    salary::notify_settings_changed();
     let _ = app.emit("workday-changed", &date);
 
     sync_dirty_workday_cache(&app, &date, &mut cache).await?;
@@ -536,11 +604,35 @@ pub async fn clear_workday_schedule_override(
     Ok(Some(cache))
 }
 
+/// 추가 근무 프로필의 workday 조회 (로컬 전용). 파일 없으면 빈 캐시.
+#[tauri::command]
+#[specta::specta]
+pub fn get_job_workday(
+    app: AppHandle,
+    job_id: String,
+    date: String,
+) -> Result<WorkdayCache, String> {
+    validate_date(&date)?;
+    let path = job_workday_dir(&app, &job_id)?.join(format!("{date}.json"));
+    Ok(read_cache_file(&path)?.unwrap_or_else(|| empty_cache(&date)))
+}
+
+/// 추가 근무 프로필의 workday 저장 (로컬 전용) + ticker/UI 즉시 반영.
+#[tauri::command]
+#[specta::specta]
+pub fn save_job_workday(app: AppHandle, job_id: String, cache: WorkdayCache) -> Result<(), String> {
+    validate_date(&cache.date)?;
+    let path = job_workday_dir(&app, &job_id)?.join(format!("{}.json", cache.date));
+    write_cache_file(&path, &cache)?;
+    let _ = app.emit("workday-changed", &cache.date);
+    Ok(())
+}
+
 /// 사용자 액션 → 로컬 즉시 update + 서버 PUT.
 ///
 /// 흐름:
 /// 1. 로컬 cache 즉시 write + `is_dirty=true` (낙관적)
-/// 2. `notify_settings_changed` + `workday-changed` emit (ticker/UI 즉시 반영)
+/// 2. `workday-changed` emit (UI 즉시 반영, ticker는 캐시 저장의 `WorkdayChanged`로)
 /// 3. 서버 PUT
 ///    - 성공 → `is_dirty=false`
 ///    - 4xx → 로컬 dirty 유지 (다음 polling이 GET으로 복원)
@@ -574,7 +666,6 @@ pub async fn mutate_workday(
         is_dirty: true,
     };
     save_workday_cache(&app, &cache)?;
-    salary::notify_settings_changed();
     let _ = app.emit("workday-changed", &date);
 
     // 2. 서버 PUT
@@ -671,7 +762,7 @@ mod tests {
             events: vec![],
             is_dirty: true,
         };
-        let req = cache_to_upsert(&cache);
+        let req = cache_to_upsert(&cache).unwrap();
         assert_eq!(req.workday_type, WorkdayType::None);
         assert!(req.clock_in_time.is_none());
     }
@@ -687,7 +778,7 @@ mod tests {
             events: vec![],
             is_dirty: true,
         };
-        let req = cache_to_upsert(&cache);
+        let req = cache_to_upsert(&cache).unwrap();
         assert_eq!(req.workday_type, WorkdayType::Vacation);
     }
 
@@ -702,9 +793,50 @@ mod tests {
             events: vec![],
             is_dirty: true,
         };
-        let req = cache_to_upsert(&cache);
+        let req = cache_to_upsert(&cache).unwrap();
         assert_eq!(req.workday_type, WorkdayType::Work);
         assert_eq!(req.clock_in_time.as_deref(), Some("09:00"));
         assert_eq!(req.clock_out_time.as_deref(), Some("18:00"));
     }
+
+    #[test]
+    fn each_kind_round_trips_or_stays_local() {
+        let kinds = [
+            WorkdayKind::Work,
+            WorkdayKind::AnnualLeave,
+            WorkdayKind::DayOff,
+            WorkdayKind::MorningHalfLeave,
+            WorkdayKind::AfternoonHalfLeave,
+            WorkdayKind::MorningQuarterLeave,
+            WorkdayKind::AfternoonQuarterLeave,
+            WorkdayKind::UnpaidLeave,
+            WorkdayKind::SickLeave,
+        ];
+        // 서버에 이미 있던 기록은 WORK (다른 기기·이전 저장)
+        let server = |workday_type| response(workday_type, WorkdayStatus::None, vec![]);
+        for kind in kinds {
+            let local = WorkdayCache {
+                kind: kind.clone(),
+                is_dirty: true,
+                ..response_to_cache(server(WorkdayType::Work))
+            };
+            // push → 서버가 받은 그대로 돌려준다고 보고 새 캐시로 fetch
+            let stored = match cache_to_upsert(&local) {
+                Some(req) => {
+                    // retry queue 저장 후 복원해도 같은 body
+                    let queued = serde_json::to_string(&SerializedUpsert::from(&req)).unwrap();
+                    let restored: SerializedUpsert = serde_json::from_str(&queued).unwrap();
+                    server(restored.to_request().workday_type)
+                }
+                None => server(WorkdayType::Work),
+            };
+            let fetched = response_to_cache(stored);
+            if server_type_of(&kind).is_some() {
+                assert_eq!(fetched.kind, kind);
+            } else {
+                // 로컬 전용 종류는 올리지 않으므로 다른 기기는 이전 기록을 그대로 본다
+                assert_eq!(fetched.kind, WorkdayKind::Work, "{kind:?}");
+            }
+        }
+    }
 }
@@ -0,0 +1,296 @@
+//! 세전 → 세후 급여 추정 (4대보험 근로자 부담분 + 근로소득 간이세액).
+//!
+//! 요율·상하한은 연도별 `DeductionTable`로 관리한다. 매년 1월(국민연금은 7월)
+//! 고시가 바뀌면 `DEDUCTION_TABLES`에 새 연도 항목을 추가하면 된다.
+//! 급여 귀속 연도보다 새 테이블이 없으면 가장 최근 테이블을 사용한다.
+//!
+//! 소득세는 간이세액표 작성 기준(소득세법 시행령 별표2)의 산식을 따르며,
+//! 자녀세액공제 등 개인별 공제는 반영하지 않는 추정치다.
+
+/// 연도별 4대보험 요율 (근로자 부담분).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeductionTable {
+    /// 적용 연도
+    pub year: i32,
+    /// 국민연금 요율
+    pub pension_rate: f64,
+    /// 국민연금 기준소득월액 하한
+    pub pension_base_min: f64,
+    /// 국민연금 기준소득월액 상한
+    pub pension_base_max: f64,
+    /// 건강보험 요율
+    pub health_rate: f64,
+    /// 장기요양보험 요율 (건강보험료 대비)
+    pub long_term_care_rate: f64,
+    /// 고용보험 요율
+    pub employment_rate: f64,
+}
+
+pub const DEDUCTION_TABLES: &[DeductionTable] = &[
+    DeductionTable {
+        year: 2025,
+        pension_rate: 0.045,
+        pension_base_min: 400_000.0,
+        pension_base_max: 6_370_000.0,
+        health_rate: 0.03545,
+        long_term_care_rate: 0.1295,
+        employment_rate: 0.009,
+    },
+    DeductionTable {
+        year: 2026,
+        pension_rate: 0.0475,
+        pension_base_min: 400_000.0,
+        pension_base_max: 6_370_000.0,
+        health_rate: 0.03595,
+        long_term_care_rate: 0.1314,
+        employment_rate: 0.009,
+    },
+];
+
+/// 공제대상 가족 1인당 인적공제 (연)
+const PERSONAL_DEDUCTION: f64 = 1_500_000.0;
+
+/// 월 공제 내역 추정 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeductionBreakdown {
+    /// 사용한 테이블 연도
+    pub year: i32,
+    /// 세전 월급 (비과세 포함)
+    pub gross: f64,
+    /// 비과세 수당 (식대 등)
+    pub tax_free: f64,
+    pub national_pension: f64,
+    pub health_insurance: f64,
+    pub long_term_care: f64,
+    pub employment_insurance: f64,
+    pub income_tax: f64,
+    pub local_income_tax: f64,
+    /// 공제 합계
+    pub total: f64,
+    /// 세후 월 실수령액
+    pub net: f64,
+}
+
+/// 해당 연도에 적용할 테이블. 없으면 그 이전 중 가장 최근, 그것도 없으면 가장 오래된 테이블.
+pub fn table_for_year(year: i32) -> &'static DeductionTable {
+    DEDUCTION_TABLES
+        .iter()
+        .rfind(|t| t.year <= year)
+        .unwrap_or(&DEDUCTION_TABLES[0])
+}
+
+/// 세전 월급 → 월 공제 내역.
+///
+/// `tax_free`는 세전 월급에 포함된 비과세 금액으로, 보험료·소득세 산정 기준에서 제외된다.
+/// `dependents`는 본인 포함 공제대상 가족 수 (최소 1).
+pub fn estimate_monthly(
+    gross: f64,
+    tax_free: f64,
+    dependents: u8,
+    table: &DeductionTable,
+) -> DeductionBreakdown {
+    let tax_free = tax_free.clamp(0.0, gross.max(0.0));
+    let taxable = (gross - tax_free).max(0.0);
+
+    let pension_base = taxable.clamp(table.pension_base_min, table.pension_base_max);
+    let national_pension = if taxable > 0.0 {
+        floor_10(pension_base * table.pension_rate)
+    } else {
+        0.0
+    };
+    let health_insurance = floor_10(taxable * table.health_rate);
+    let long_term_care = floor_10(health_insurance * table.long_term_care_rate);
+    let employment_insurance = floor_10(taxable * table.employment_rate);
+
+    let income_tax = floor_10(monthly_income_tax(taxable, national_pension, dependents));
+    let local_income_tax = floor_10(income_tax * 0.1);
+
+    let total = national_pension
+        + health_insurance
+        + long_term_care
+        + employment_insurance
+        + income_tax
+        + local_income_tax;
+
+    DeductionBreakdown {
+        year: table.year,
+        gross,
+        tax_free,
+        national_pension,
+        health_insurance,
+        long_term_care,
+        employment_insurance,
+        income_tax,
+        local_income_tax,
+        total,
+        net: gross - total,
+    }
+}
+
+/// 세후 월 실수령액 → 세전 월급 역산 (이분 탐색, 1원 단위).
+pub fn gross_from_net(net: f64, tax_free: f64, dependents: u8, table: &DeductionTable) -> f64 {
+    if net <= 0.0 {
+        return 0.0;
+    }
+    // 공제율은 50%를 넘지 않으므로 [net, net × 2] 안에 해가 있다
+    let (mut low, mut high) = (net, net * 2.0);
+    while high - low > 1.0 {
+        let mid = (low + high) / 2.0;
+        if estimate_monthly(mid, tax_free, dependents, table).net < net {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    high.round()
+}
+
+/// 간이세액표 산식 기준 월 근로소득세.
+fn monthly_income_tax(monthly_taxable: f64, monthly_pension: f64, dependents: u8) -> f64 {
+    let annual_pay = monthly_taxable * 12.0;
+    let earned_income = annual_pay - earned_income_deduction(annual_pay);
+    let deductions = PERSONAL_DEDUCTION * dependents.max(1) as f64
+        + monthly_pension * 12.0
+        + special_deduction(annual_pay);
+    let tax_base = (earned_income - deductions).max(0.0);
+    let computed = progressive_tax(tax_base);
+    let annual_tax = (computed - earned_income_tax_credit(computed, annual_pay)).max(0.0);
+    annual_tax / 12.0
+}
+
+/// 근로소득공제 (한도 2,000만원)
+fn earned_income_deduction(annual_pay: f64) -> f64 {
+    let deduction = if annual_pay <= 5_000_000.0 {
+        annual_pay * 0.7
+    } else if annual_pay <= 15_000_000.0 {
+        3_500_000.0 + (annual_pay - 5_000_000.0) * 0.4
+    } else if annual_pay <= 45_000_000.0 {
+        7_500_000.0 + (annual_pay - 15_000_000.0) * 0.15
+    } else if annual_pay <= 100_000_000.0 {
+        12_000_000.0 + (annual_pay - 45_000_000.0) * 0.05
+    } else {
+        14_750_000.0 + (annual_pay - 100_000_000.0) * 0.02
+    };
+    deduction.min(20_000_000.0)
+}
+
+/// 간이세액표 작성 기준의 특별소득공제·특별세액공제 일부 (공제대상 가족 1인 기준)
+fn special_deduction(annual_pay: f64) -> f64 {
+    if annual_pay <= 30_000_000.0 {
+        3_100_000.0 + annual_pay * 0.04
+    } else if annual_pay <= 45_000_000.0 {
+        3_100_000.0 + annual_pay * 0.04 - (annual_pay - 30_000_000.0) * 0.05
+    } else if annual_pay <= 70_000_000.0 {
+        3_100_000.0 + annual_pay * 0.015
+    } else if annual_pay <= 120_000_000.0 {
+        3_100_000.0 + annual_pay * 0.005
+    } else {
+        0.0
+    }
+}
+
+/// 종합소득세 기본세율 (누진공제 방식)
+fn progressive_tax(tax_base: f64) -> f64 {
+    const BRACKETS: [(f64, f64, f64); 8] = [
+        (14_000_000.0, 0.06, 0.0),
+        (50_000_000.0, 0.15, 1_260_000.0),
+        (88_000_000.0, 0.24, 5_760_000.0),
+        (150_000_000.0, 0.35, 15_440_000.0),
+        (300_000_000.0, 0.38, 19_940_000.0),
+        (500_000_000.0, 0.40, 25_940_000.0),
+        (1_000_000_000.0, 0.42, 35_940_000.0),
+        (f64::INFINITY, 0.45, 65_940_000.0),
+    ];
+    BRACKETS
+        .iter()
+        .find(|(limit, _, _)| tax_base <= *limit)
+        .map(|(_, rate, progressive)| (tax_base * rate - progressive).max(0.0))
+        .unwrap_or(0.0)
+}
+
+/// 근로소득세액공제 (총급여 구간별 한도 적용)
+fn earned_income_tax_credit(computed_tax: f64, annual_pay: f64) -> f64 {
+    let credit = if computed_tax <= 1_300_000.0 {
+        computed_tax * 0.55
+    } else {
+        715_000.0 + (computed_tax - 1_300_000.0) * 0.3
+    };
+    let limit = if annual_pay <= 33_000_000.0 {
+        740_000.0
+    } else if annual_pay <= 70_000_000.0 {
+        (740_000.0 - (annual_pay - 33_000_000.0) * 0.008).max(660_000.0)
+    } else if annual_pay <= 120_000_000.0 {
+        (660_000.0 - (annual_pay - 70_000_000.0) * 0.5).max(500_000.0)
+    } else {
+        (500_000.0 - (annual_pay - 120_000_000.0) * 0.5).max(200_000.0)
+    };
+    credit.min(limit)
+}
+
+/// 10원 미만 절사 (부동소수 오차를 피하려 원 단위 반올림 후 절사)
+fn floor_10(amount: f64) -> f64 {
+    (amount.round() / 10.0).floor() * 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_for_year_picks_latest_not_after_year() {
+        assert_eq!(table_for_year(2025).year, 2025);
+        assert_eq!(table_for_year(2026).year, 2026);
+        assert_eq!(table_for_year(2030).year, 2026);
+        assert_eq!(table_for_year(2020).year, 2025);
+    }
+
+    #[test]
+    fn insurance_premiums_for_3m_in_2025() {
+        let b = estimate_monthly(3_000_000.0, 0.0, 1, table_for_year(2025));
+        assert_eq!(b.national_pension, 135_000.0);
+        assert_eq!(b.health_insurance, 106_350.0);
+        assert_eq!(b.long_term_care, 13_770.0);
+        assert_eq!(b.employment_insurance, 27_000.0);
+        assert_eq!(b.local_income_tax, floor_10(b.income_tax * 0.1));
+        assert_eq!(b.net, b.gross - b.total);
+    }
+
+    #[test]
+    fn tax_free_allowance_is_excluded_from_base() {
+        let table = table_for_year(2025);
+        let with_meal = estimate_monthly(3_200_000.0, 200_000.0, 1, table);
+        let without = estimate_monthly(3_000_000.0, 0.0, 1, table);
+        assert_eq!(with_meal.total, without.total);
+        assert_eq!(with_meal.net, without.net + 200_000.0);
+    }
+
+    #[test]
+    fn pension_base_is_capped() {
+        let b = estimate_monthly(10_000_000.0, 0.0, 1, table_for_year(2025));
+        assert_eq!(b.national_pension, floor_10(6_370_000.0 * 0.045));
+    }
+
+    #[test]
+    fn more_dependents_lower_income_tax() {
+        let table = table_for_year(2025);
+        let single = estimate_monthly(5_000_000.0, 0.0, 1, table);
+        let family = estimate_monthly(5_000_000.0, 0.0, 4, table);
+        assert!(family.income_tax < single.income_tax);
+    }
+
+    #[test]
+    fn low_income_pays_no_income_tax() {
+        let b = estimate_monthly(800_000.0, 0.0, 1, table_for_year(2025));
+        assert_eq!(b.income_tax, 0.0);
+    }
+
+    #[test]
+    fn gross_from_net_round_trips() {
+        let table = table_for_year(2026);
+        let net = estimate_monthly(4_000_000.0, 200_000.0, 1, table).net;
+        let gross = gross_from_net(net, 200_000.0, 1, table);
+        // 10원 절사 때문에 같은 실수령액을 내는 세전 금액이 여럿일 수 있다
+        assert_eq!(estimate_monthly(gross, 200_000.0, 1, table).net, net);
+        assert!((gross - 4_000_000.0).abs() <= 100.0);
+    }
+}
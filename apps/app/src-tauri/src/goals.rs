@@ -0,0 +1,270 @@
+//! 저축·구매 목표 진행률과 달성 축하.
+//!
+//! 급여 ticker가 매 tick 목표별 진행률을 계산해 `salary-tick` payload에 싣는다.
+//! 기준 금액(`GoalBasis`)이 목표 금액을 넘으면 confetti 창과 네이티브 알림으로
+//! 축하하고 `achieved_on`을 기록해, 목표당 한 번만 축하한다. 오늘 기준(`Today`)
+//! 목표는 날마다 새로 시작하므로 하루에 한 번 축하한다.
+
+use chrono::{Months, NaiveDate};
+use serde::Serialize;
+use specta::Type;
+use tauri::AppHandle;
+
+use crate::money::{self, Minor};
+use crate::types::{Currency, Goal, GoalBasis};
+
+/// 목표별 진행 상황
+#[derive(Debug, Clone, Serialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalProgress {
+    pub id: String,
+    pub name: String,
+    pub basis: GoalBasis,
+    /// 기준 금액 (통화 최소 단위)
+    pub current_amount: f64,
+    pub target_amount: u32,
+    /// 0.0–1.0
+    pub progress: f64,
+    pub achieved_on: Option<String>,
+    /// 목표 시점(`deadline`) 마지막 날까지 남은 일수. 지났으면 0
+    pub days_left: Option<u32>,
+    /// 달성하지 못한 채 목표 시점이 지남
+    pub overdue: bool,
+}
+
+/// 이미 축하한 목표인지. `Today` 목표는 오늘 축하했을 때만
+fn is_celebrated(goal: &Goal, today: NaiveDate) -> bool {
+    let today = today.format("%Y-%m-%d").to_string();
+    goal.achieved_on
+        .as_deref()
+        .is_some_and(|date| goal.basis == GoalBasis::Accumulated || date == today)
+}
+
+/// `deadline`(YYYY-MM) 달의 마지막 날
+fn deadline_date(goal: &Goal) -> Option<NaiveDate> {
+    let month = goal.deadline.as_deref()?;
+    NaiveDate::parse_from_str(&format!("{month}-01"), "%Y-%m-%d")
+        .ok()?
+        .checked_add_months(Months::new(1))?
+        .pred_opt()
+}
+
+/// `accumulated`·`today_earnings` 금액 기준 `today`의 목표별 진행률
+pub fn goal_progress(
+    goals: &[Goal],
+    accumulated: Minor,
+    today_earnings: f64,
+    today: NaiveDate,
+) -> Vec<GoalProgress> {
+    goals
+        .iter()
+        .map(|goal| {
+            let current_amount = match goal.basis {
+                GoalBasis::Accumulated => accumulated as f64,
+                GoalBasis::Today => today_earnings,
+            };
+            let progress = (current_amount / goal.target_amount.max(1) as f64).clamp(0.0, 1.0);
+            let celebrated = is_celebrated(goal, today);
+            let deadline = deadline_date(goal);
+            GoalProgress {
+                id: goal.id.clone(),
+                name: goal.name.clone(),
+                basis: goal.basis,
+                current_amount,
+                target_amount: goal.target_amount,
+                progress,
+                achieved_on: goal.achieved_on.clone().filter(|_| celebrated),
+                days_left: deadline.map(|deadline| (deadline - today).num_days().max(0) as u32),
+                overdue: deadline.is_some_and(|deadline| deadline < today)
+                    && !celebrated
+                    && progress < 1.0,
+            }
+        })
+        .collect()
+}
+
+/// 이번에 처음 목표 금액을 넘은 목표 (아직 축하하지 않은 것만)
+fn newly_achieved<'a>(
+    goals: &'a [Goal],
+    progress: &[GoalProgress],
+    today: NaiveDate,
+) -> Vec<&'a Goal> {
+    goals
+        .iter()
+        .zip(progress)
+        .filter(|(goal, p)| !is_celebrated(goal, today) && p.progress >= 1.0)
+        .map(|(goal, _)| goal)
+        .collect()
+}
+
+/// ticker 스레드용 목표 상태
+pub struct GoalTracker {
+    goals: Vec<Goal>,
+}
+
+impl GoalTracker {
+    pub fn load(app: &AppHandle) -> Self {
+        Self {
+            goals: load_goals(app),
+        }
+    }
+
+    /// 목표 파일을 다시 읽는다 (ticker의 `GoalsChanged`)
+    pub fn reload(&mut self, app: &AppHandle) {
+        self.goals = load_goals(app);
+    }
+
+    /// 진행률 계산 후 새로 달성한 목표를 축하하고 `today`로 기록
+    pub fn tick(
+        &mut self,
+        app: &AppHandle,
+        accumulated: Minor,
+        today_earnings: f64,
+        today: NaiveDate,
+        currency: Currency,
+    ) -> Vec<GoalProgress> {
+        let mut progress = goal_progress(&self.goals, accumulated, today_earnings, today);
+        let achieved: Vec<String> = newly_achieved(&self.goals, &progress, today)
+            .into_iter()
+            .map(|goal| {
+                celebrate(app, goal, currency);
+                goal.id.clone()
+            })
+            .collect();
+        if achieved.is_empty() {
+            return progress;
+        }
+
+        // 파일 저장이 실패해도 이번 실행 중에는 다시 축하하지 않도록 메모리에 먼저 기록
+        let today = today.format("%Y-%m-%d").to_string();
+        for goal in self.goals.iter_mut().filter(|g| achieved.contains(&g.id)) {
+            goal.achieved_on = Some(today.to_string());
+        }
+        for p in progress.iter_mut().filter(|p| achieved.contains(&p.id)) {
+            p.achieved_on = Some(today.to_string());
+        }
+        if let Err(e) = crate::commands::goals::mark_goals_achieved(app, &achieved, &today) {
+            log::warn!("목표 달성 기록 실패: {e}");
+        }
+        progress
+    }
+}
+
+fn celebrate(app: &AppHandle, goal: &Goal, currency: Currency) {
+    log::info!("목표 달성: {}", goal.name);
+    if let Err(e) = crate::commands::confetti::show_confetti_window(app.clone()) {
+        log::warn!("confetti 표시 실패: {e}");
+    }
+
+    let app = app.clone();
+    let title = "목표 달성 🎉".to_string();
+    let body = format!(
+        "{} {}을(를) 모았어요",
+        goal.name,
+        money::format_amount(goal.target_amount as Minor, currency)
+    );
+    tauri::async_runtime::spawn(async move {
+        let _ =
+            crate::commands::notifications::send_native_notification(app, title, Some(body)).await;
+    });
+}
+
+fn load_goals(app: &AppHandle) -> Vec<Goal> {
+    crate::commands::goals::read_goals(app).unwrap_or_else(|e| {
+        log::warn!("목표 불러오기 실패: {e}");
+        vec![]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn goal(id: &str, target_amount: u32, basis: GoalBasis) -> Goal {
+        Goal {
+            id: id.to_string(),
+            name: id.to_string(),
+            target_amount,
+            basis,
+            deadline: None,
+            achieved_on: None,
+        }
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_goal_progress_uses_basis_amount() {
+        let goals = [
+            goal("laptop", 1_200_000, GoalBasis::Accumulated),
+            goal("lunch", 12_000, GoalBasis::Today),
+        ];
+        let progress = goal_progress(&goals, 300_000, 15_000.5, date("2026-10-01"));
+
+        assert_eq!(progress[0].current_amount, 300_000.0);
+        assert_eq!(progress[0].progress, 0.25);
+        assert_eq!(progress[1].current_amount, 15_000.5);
+        // 초과분은 1.0으로 고정
+        assert_eq!(progress[1].progress, 1.0);
+    }
+
+    #[test]
+    fn test_newly_achieved_skips_celebrated_goals() {
+        let mut goals = vec![
+            goal("laptop", 1_200_000, GoalBasis::Accumulated),
+            goal("lunch", 12_000, GoalBasis::Today),
+            goal("coffee", 5_000, GoalBasis::Today),
+        ];
+        goals[2].achieved_on = Some("2026-10-01".to_string());
+        let today = date("2026-10-01");
+        let progress = goal_progress(&goals, 300_000, 12_000.0, today);
+
+        let achieved: Vec<&str> = newly_achieved(&goals, &progress, today)
+            .iter()
+            .map(|g| g.id.as_str())
+            .collect();
+        assert_eq!(achieved, ["lunch"]);
+    }
+
+    #[test]
+    fn test_today_goal_rearms_next_day() {
+        let mut goals = vec![
+            goal("lunch", 12_000, GoalBasis::Today),
+            goal("laptop", 1_200_000, GoalBasis::Accumulated),
+        ];
+        goals[0].achieved_on = Some("2026-10-01".to_string());
+        goals[1].achieved_on = Some("2026-10-01".to_string());
+        let today = date("2026-10-02");
+
+        let progress = goal_progress(&goals, 1_200_000, 12_000.0, today);
+        assert_eq!(progress[0].achieved_on, None);
+        assert_eq!(progress[1].achieved_on.as_deref(), Some("2026-10-01"));
+        let achieved: Vec<&str> = newly_achieved(&goals, &progress, today)
+            .iter()
+            .map(|g| g.id.as_str())
+            .collect();
+        assert_eq!(achieved, ["lunch"]);
+    }
+
+    #[test]
+    fn test_goal_deadline_days_left_and_overdue() {
+        let mut goals = vec![
+            goal("laptop", 1_200_000, GoalBasis::Accumulated),
+            goal("camera", 900_000, GoalBasis::Accumulated),
+            goal("desk", 300_000, GoalBasis::Accumulated),
+        ];
+        goals[0].deadline = Some("2026-12".to_string());
+        goals[1].deadline = Some("2026-09".to_string());
+        let progress = goal_progress(&goals, 300_000, 0.0, date("2026-10-18"));
+
+        // 12월 31일까지
+        assert_eq!(progress[0].days_left, Some(74));
+        assert!(!progress[0].overdue);
+        assert_eq!(progress[1].days_left, Some(0));
+        assert!(progress[1].overdue);
+        assert_eq!(progress[2].days_left, None);
+        assert!(!progress[2].overdue);
+    }
+}
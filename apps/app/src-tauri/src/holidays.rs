@@ -0,0 +1,559 @@
+//! 내장 한국 공휴일 달력 (오프라인).
+//!
+//! 서버 workday 캐시에 `PUBLIC_HOLIDAY` 이벤트가 없어도(비로그인·오프라인) 공휴일을 안다.
+//! - 양력 공휴일: 신정, 삼일절, 어린이날, 현충일, 광복절, 개천절, 한글날, 성탄절
+//! - 음력 공휴일: 설날·추석(전날·다음날 포함), 부처님오신날. 음력 환산 대신 연도별
+//!   양력 날짜를 데이터 파일에서 읽는다
+//! - 대체공휴일: 「관공서의 공휴일에 관한 규정」 제3조 (2014년 도입, 2021·2023년 확대 반영)
+//! - 선거일·임시공휴일: 데이터 파일의 `extra`
+//!
+//! `data/holidays-kr.json`을 내장하고, 앱 데이터 디렉토리에 `holidays.json`이 있으면
+//! 그것을 쓴다 (새 연도·선거일 업데이트용).
+//!
+//! .ics에서 가져온 회사 휴무일(`company-holidays.json`)도 같은 달력에 얹는다. 여러 달력이
+//! 같은 날을 쉬면 달력마다 따로 남고, 매년 반복 일정은 펼칠 끝 날짜 없이 계산한다.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::Deserialize;
+use tauri::{AppHandle, Manager};
+
+use crate::ics::IcsEvent;
+use crate::types::{CompanyHoliday, CompanyHolidayBook};
+
+static BUNDLED_DATA: &str = include_str!("../data/holidays-kr.json");
+
+/// 음력 공휴일의 양력 날짜 (한 해)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LunarDates {
+    /// 설날 (음력 1월 1일)
+    seollal: NaiveDate,
+    /// 부처님오신날 (음력 4월 8일)
+    buddhas_birthday: NaiveDate,
+    /// 추석 (음력 8월 15일)
+    chuseok: NaiveDate,
+}
+
+/// 선거일·임시공휴일
+#[derive(Debug, Clone, Deserialize)]
+struct ExtraHoliday {
+    date: NaiveDate,
+    name: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+struct HolidayData {
+    #[serde(default)]
+    lunar: HashMap<i32, LunarDates>,
+    #[serde(default)]
+    extra: Vec<ExtraHoliday>,
+}
+
+/// 대체공휴일 규칙 묶음
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Substitute {
+    /// 대체 없음
+    None,
+    /// 설날·추석 연휴: 일요일·다른 공휴일과 겹치면 연휴 다음 날로
+    Lunar,
+    /// 어린이날 등: 토·일요일·다른 공휴일과 겹치면 다음 날로
+    Weekend,
+}
+
+/// 공휴일 하나 (대체 규칙 판단용)
+struct Holiday {
+    date: NaiveDate,
+    name: &'static str,
+    substitute: Substitute,
+}
+
+/// 연도별로 계산한 공휴일을 메모하는 달력 (ticker 스레드·커맨드 호출 단위로 만든다)
+pub struct HolidayCalendar {
+    data: HolidayData,
+    years: RefCell<HashMap<i32, BTreeMap<NaiveDate, String>>>,
+    company: CompanyHolidayBook,
+}
+
+impl HolidayCalendar {
+    /// 내장 데이터 달력
+    pub fn bundled() -> HolidayCalendar {
+        let data = serde_json::from_str(BUNDLED_DATA).unwrap_or_else(|e| {
+            log::warn!("내장 공휴일 데이터 파싱 실패: {e}");
+            HolidayData::default()
+        });
+        HolidayCalendar::new(data)
+    }
+
+    /// 내장(또는 `holidays.json`) 공휴일 + 가져온 회사 휴무일
+    pub fn load(app: &AppHandle) -> HolidayCalendar {
+        let company = app
+            .path()
+            .app_data_dir()
+            .ok()
+            .map(|dir| dir.join("company-holidays.json"))
+            .filter(|path| path.exists())
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+        HolidayCalendar::load_public(app).with_company_holidays(&company)
+    }
+
+    /// `{app_data_dir}/holidays.json`이 있으면 그 데이터, 없거나 읽을 수 없으면 내장 데이터
+    fn load_public(app: &AppHandle) -> HolidayCalendar {
+        let Some(path) = app
+            .path()
+            .app_data_dir()
+            .ok()
+            .map(|dir| dir.join("holidays.json"))
+            .filter(|path| path.exists())
+        else {
+            return HolidayCalendar::bundled();
+        };
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| serde_json::from_str(&contents).map_err(|e| e.to_string()));
+        match parsed {
+            Ok(data) => HolidayCalendar::new(data),
+            Err(e) => {
+                log::warn!("holidays.json 읽기 실패, 내장 데이터 사용: {e}");
+                HolidayCalendar::bundled()
+            }
+        }
+    }
+
+    fn new(data: HolidayData) -> HolidayCalendar {
+        HolidayCalendar {
+            data,
+            years: RefCell::new(HashMap::new()),
+            company: CompanyHolidayBook::new(),
+        }
+    }
+
+    pub fn with_company_holidays(mut self, book: &CompanyHolidayBook) -> HolidayCalendar {
+        self.company = book.clone();
+        self
+    }
+
+    /// 공휴일(또는 회사 휴무일) 이름. 휴일이 아니면 None
+    pub fn holiday_name(&self, date: NaiveDate) -> Option<String> {
+        self.years
+            .borrow_mut()
+            .entry(date.year())
+            .or_insert_with(|| holidays_in_year(&self.data, date.year()))
+            .get(&date)
+            .cloned()
+            .or_else(|| self.company_holiday(date).map(|h| h.name))
+    }
+
+    /// 가져온 회사 휴무일 (여러 달력이 겹치면 달력 이름순 첫 번째)
+    pub fn company_holiday(&self, date: NaiveDate) -> Option<CompanyHoliday> {
+        self.company_holidays(date).into_iter().next()
+    }
+
+    /// `date`를 쉬는 달력마다 회사 휴무일 하나 (달력 이름순)
+    pub fn company_holidays(&self, date: NaiveDate) -> Vec<CompanyHoliday> {
+        self.company
+            .iter()
+            .filter_map(|(calendar, events)| {
+                let event = events.iter().find(|event| event.covers(date))?;
+                Some(company_holiday(event, calendar, date))
+            })
+            .collect()
+    }
+
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holiday_name(date).is_some()
+    }
+}
+
+fn company_holiday(event: &IcsEvent, calendar: &str, date: NaiveDate) -> CompanyHoliday {
+    let name = if event.summary.is_empty() {
+        "회사 휴무일"
+    } else {
+        event.summary.as_str()
+    };
+    CompanyHoliday {
+        date: date.format("%Y-%m-%d").to_string(),
+        name: name.to_string(),
+        calendar: calendar.to_string(),
+    }
+}
+
+/// 달력 `calendar`의 일정을 `until`까지 날짜별 회사 휴무일로 펼친다 (같은 날 일정이 겹치면 먼저 나온 일정)
+pub fn expand_company_calendar(
+    calendar: &str,
+    events: &[IcsEvent],
+    until: NaiveDate,
+) -> Vec<CompanyHoliday> {
+    let mut by_date: BTreeMap<NaiveDate, CompanyHoliday> = BTreeMap::new();
+    for event in events {
+        for date in event.dates_until(until) {
+            by_date
+                .entry(date)
+                .or_insert_with(|| company_holiday(event, calendar, date));
+        }
+    }
+    by_date.into_values().collect()
+}
+
+/// 가져온 모든 달력을 `until`까지 펼친 목록 (날짜순, 같은 날은 달력 이름순)
+pub fn expand_company_holidays(book: &CompanyHolidayBook, until: NaiveDate) -> Vec<CompanyHoliday> {
+    let mut holidays: Vec<CompanyHoliday> = book
+        .iter()
+        .flat_map(|(calendar, events)| expand_company_calendar(calendar, events, until))
+        .collect();
+    holidays.sort_by(|a, b| (&a.date, &a.calendar).cmp(&(&b.date, &b.calendar)));
+    holidays
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("valid fixed holiday date")
+}
+
+/// `year`의 공휴일 (날짜 → 이름). 겹치는 날은 이름을 `·`로 잇는다
+fn holidays_in_year(data: &HolidayData, year: i32) -> BTreeMap<NaiveDate, String> {
+    // 2021년부터 국경일, 2023년부터 부처님오신날·성탄절도 토·일요일 대체
+    let national = if year >= 2021 {
+        Substitute::Weekend
+    } else {
+        Substitute::None
+    };
+    let religious = if year >= 2023 {
+        Substitute::Weekend
+    } else {
+        Substitute::None
+    };
+    let children = if year >= 2014 {
+        Substitute::Weekend
+    } else {
+        Substitute::None
+    };
+    let lunar_rule = if year >= 2014 {
+        Substitute::Lunar
+    } else {
+        Substitute::None
+    };
+
+    let mut holidays = vec![
+        Holiday {
+            date: ymd(year, 1, 1),
+            name: "신정",
+            substitute: Substitute::None,
+        },
+        Holiday {
+            date: ymd(year, 3, 1),
+            name: "삼일절",
+            substitute: national,
+        },
+        Holiday {
+            date: ymd(year, 5, 5),
+            name: "어린이날",
+            substitute: children,
+        },
+        Holiday {
+            date: ymd(year, 6, 6),
+            name: "현충일",
+            substitute: Substitute::None,
+        },
+        Holiday {
+            date: ymd(year, 8, 15),
+            name: "광복절",
+            substitute: national,
+        },
+        Holiday {
+            date: ymd(year, 10, 3),
+            name: "개천절",
+            substitute: national,
+        },
+        Holiday {
+            date: ymd(year, 10, 9),
+            name: "한글날",
+            substitute: national,
+        },
+        Holiday {
+            date: ymd(year, 12, 25),
+            name: "성탄절",
+            substitute: religious,
+        },
+    ];
+    if let Some(lunar) = data.lunar.get(&year) {
+        for (day, name) in [
+            (lunar.seollal.pred_opt(), "설날 연휴"),
+            (Some(lunar.seollal), "설날"),
+            (lunar.seollal.succ_opt(), "설날 연휴"),
+            (lunar.chuseok.pred_opt(), "추석 연휴"),
+            (Some(lunar.chuseok), "추석"),
+            (lunar.chuseok.succ_opt(), "추석 연휴"),
+        ] {
+            if let Some(date) = day {
+                holidays.push(Holiday {
+                    date,
+                    name,
+                    substitute: lunar_rule,
+                });
+            }
+        }
+        holidays.push(Holiday {
+            date: lunar.buddhas_birthday,
+            name: "부처님오신날",
+            substitute: religious,
+        });
+    }
+
+    let mut by_date: BTreeMap<NaiveDate, String> = BTreeMap::new();
+    for holiday in &holidays {
+        by_date
+            .entry(holiday.date)
+            .and_modify(|name| {
+                name.push('·');
+                name.push_str(holiday.name);
+            })
+            .or_insert_with(|| holiday.name.to_string());
+    }
+    for extra in data.extra.iter().filter(|e| e.date.year() == year) {
+        by_date
+            .entry(extra.date)
+            .or_insert_with(|| extra.name.clone());
+    }
+
+    // 대체공휴일: 겹친 날짜마다 하나, 날짜순으로 다음 첫 평일 비공휴일에 배정
+    let overlaps = |date: NaiveDate| holidays.iter().filter(|h| h.date == date).count() > 1;
+    let mut substitutes: Vec<NaiveDate> = vec![];
+    for date in by_date.keys().copied().collect::<Vec<_>>() {
+        let rules: Vec<Substitute> = holidays
+            .iter()
+            .filter(|h| h.date == date)
+            .map(|h| h.substitute)
+            .collect();
+        let weekday = date.weekday();
+        let needs = rules.iter().any(|rule| match rule {
+            Substitute::None => false,
+            Substitute::Lunar => weekday == Weekday::Sun || overlaps(date),
+            Substitute::Weekend => matches!(weekday, Weekday::Sat | Weekday::Sun) || overlaps(date),
+        });
+        if !needs {
+            continue;
+        }
+        // 설날·추석은 연휴가 끝난 다음 날부터 찾는다
+        let mut candidate = date.succ_opt();
+        if rules.contains(&Substitute::Lunar) {
+            while let Some(day) = candidate {
+                let in_lunar_break = holidays
+                    .iter()
+                    .any(|h| h.date == day && h.substitute == Substitute::Lunar);
+                if !in_lunar_break {
+                    break;
+                }
+                candidate = day.succ_opt();
+            }
+        }
+        while let Some(day) = candidate {
+            let is_free = !matches!(day.weekday(), Weekday::Sat | Weekday::Sun)
+                && !by_date.contains_key(&day)
+                && !substitutes.contains(&day);
+            if is_free {
+                substitutes.push(day);
+                break;
+            }
+            candidate = day.succ_opt();
+        }
+    }
+    for date in substitutes.into_iter().filter(|d| d.year() == year) {
+        by_date.insert(date, "대체공휴일".to_string());
+    }
+    by_date
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn names(year: i32) -> Vec<(String, String)> {
+        holidays_in_year(&HolidayCalendar::bundled().data, year)
+            .into_iter()
+            .map(|(d, name)| (d.format("%Y-%m-%d").to_string(), name))
+            .collect()
+    }
+
+    #[test]
+    fn bundled_data_parses() {
+        let calendar = HolidayCalendar::bundled();
+        assert!(calendar.data.lunar.contains_key(&2026));
+        assert!(!calendar.data.extra.is_empty());
+    }
+
+    #[test]
+    fn year_2025_with_lunar_and_substitute_holidays() {
+        let calendar = HolidayCalendar::bundled();
+        for (day, name) in [
+            ("2025-01-27", "임시공휴일"),
+            ("2025-01-28", "설날 연휴"),
+            ("2025-01-29", "설날"),
+            ("2025-01-30", "설날 연휴"),
+            ("2025-05-05", "어린이날·부처님오신날"),
+            // 어린이날·부처님오신날이 겹쳐 다음 날 대체
+            ("2025-05-06", "대체공휴일"),
+            ("2025-06-03", "제21대 대통령선거"),
+            // 추석 연휴(10/5–7) 첫날이 일요일 → 개천절·한글날을 피해 10/8
+            ("2025-10-06", "추석"),
+            ("2025-10-08", "대체공휴일"),
+            ("2025-10-09", "한글날"),
+        ] {
+            assert_eq!(
+                calendar.holiday_name(date(day)).as_deref(),
+                Some(name),
+                "{day}"
+            );
+        }
+        assert!(!calendar.is_holiday(date("2025-10-10")));
+        assert!(!calendar.is_holiday(date("2025-02-03")));
+    }
+
+    #[test]
+    fn year_2026_weekend_substitutes() {
+        let holidays = names(2026);
+        let substitutes: Vec<&str> = holidays
+            .iter()
+            .filter(|(_, name)| name == "대체공휴일")
+            .map(|(d, _)| d.as_str())
+            .collect();
+        // 삼일절(일)·부처님오신날(일)·광복절(토)·개천절(토). 추석 연휴 토요일은 대체 없음
+        assert_eq!(
+            substitutes,
+            vec!["2026-03-02", "2026-05-25", "2026-08-17", "2026-10-05"]
+        );
+        assert!(holidays.iter().any(|(d, _)| d == "2026-06-03"));
+    }
+
+    #[test]
+    fn substitute_rules_follow_their_introduction_year() {
+        // 2020 광복절(토): 확대 전이라 대체 없음 (8/17은 임시공휴일)
+        let holidays_2020 = names(2020);
+        assert_eq!(
+            holidays_2020
+                .iter()
+                .find(|(d, _)| d == "2020-08-17")
+                .map(|(_, n)| n.as_str()),
+            Some("임시공휴일")
+        );
+        // 2022 성탄절(일): 2023년 확대 전이라 대체 없음
+        assert!(!names(2022).iter().any(|(d, _)| d == "2022-12-26"));
+        // 2023 부처님오신날(토) → 5/29(월)
+        assert!(names(2023)
+            .iter()
+            .any(|(d, n)| d == "2023-05-29" && n == "대체공휴일"));
+    }
+
+    #[test]
+    fn lunar_break_substitute_comes_after_the_break() {
+        let calendar = HolidayCalendar::bundled();
+        // 2015 추석 일요일, 2027 설날 일요일, 2028 추석·개천절 겹침
+        for day in ["2015-09-29", "2027-02-09", "2028-10-05"] {
+            assert_eq!(
+                calendar.holiday_name(date(day)).as_deref(),
+                Some("대체공휴일"),
+                "{day}"
+            );
+        }
+    }
+
+    fn company_events(ics: &str) -> Vec<IcsEvent> {
+        crate::ics::parse(ics, Some(chrono_tz::Asia::Seoul))
+            .unwrap()
+            .events
+    }
+
+    #[test]
+    fn company_calendar_keeps_yearly_rules_past_the_horizon() {
+        let events = company_events(
+            "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:20190415\nRRULE:FREQ=YEARLY\n\
+SUMMARY:창립기념일\nEND:VEVENT\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:20251230\n\
+DTEND;VALUE=DATE:20260101\nSUMMARY:연말 휴무\nEND:VEVENT\nEND:VCALENDAR\n",
+        );
+        let holidays = expand_company_calendar("회사", &events, date("2026-12-31"));
+        assert_eq!(holidays.len(), 8 + 2);
+
+        let mut book = CompanyHolidayBook::new();
+        book.insert("회사".into(), events);
+        let calendar = HolidayCalendar::bundled().with_company_holidays(&book);
+        assert_eq!(
+            calendar
+                .company_holiday(date("2025-04-15"))
+                .map(|h| h.calendar),
+            Some("회사".to_string())
+        );
+        // 가져온 뒤 몇 년이 지나도 반복 일정은 계속 휴무일
+        assert_eq!(
+            calendar.holiday_name(date("2040-04-15")).as_deref(),
+            Some("창립기념일")
+        );
+        assert!(calendar.is_holiday(date("2025-12-30")));
+        // 국가 공휴일 이름이 우선
+        assert_eq!(
+            calendar.holiday_name(date("2025-12-25")).as_deref(),
+            Some("성탄절")
+        );
+    }
+
+    #[test]
+    fn overlapping_company_calendars_keep_their_own_holidays() {
+        let mut book = CompanyHolidayBook::new();
+        book.insert(
+            "본사".into(),
+            company_events(
+                "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:20250801\n\
+DTEND;VALUE=DATE:20250804\nSUMMARY:하계 휴무\nEND:VEVENT\nEND:VCALENDAR\n",
+            ),
+        );
+        book.insert(
+            "연구소".into(),
+            company_events(
+                "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:20250801\n\
+SUMMARY:연구소 창립일\nEND:VEVENT\nEND:VCALENDAR\n",
+            ),
+        );
+
+        let calendar = HolidayCalendar::bundled().with_company_holidays(&book);
+        let names: Vec<(String, String)> = calendar
+            .company_holidays(date("2025-08-01"))
+            .into_iter()
+            .map(|h| (h.calendar, h.name))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("본사".to_string(), "하계 휴무".to_string()),
+                ("연구소".to_string(), "연구소 창립일".to_string()),
+            ]
+        );
+        assert_eq!(
+            expand_company_holidays(&book, date("2025-12-31")).len(),
+            3 + 1
+        );
+
+        // 한 달력을 지워도 다른 달력의 같은 날 휴무일은 남는다
+        book.remove("본사");
+        let calendar = HolidayCalendar::bundled().with_company_holidays(&book);
+        assert_eq!(
+            calendar.company_holiday(date("2025-08-01")).map(|h| h.name),
+            Some("연구소 창립일".to_string())
+        );
+        assert!(!calendar.is_holiday(date("2025-08-02")));
+    }
+
+    #[test]
+    fn years_without_lunar_data_still_have_solar_holidays() {
+        let calendar = HolidayCalendar::bundled();
+        assert_eq!(
+            calendar.holiday_name(date("2040-08-15")).as_deref(),
+            Some("광복절")
+        );
+    }
+}
@@ -0,0 +1,584 @@
+//! iCalendar(.ics) 휴무일 파서 (RFC 5545 중 휴무일 가져오기에 필요한 부분만).
+//!
+//! `VEVENT`의 `DTSTART`/`DTEND`/`SUMMARY`/`STATUS`/`EXDATE`와 `RRULE:FREQ=YEARLY`
+//! (창립기념일 등)만 해석한다. 시각이 있는 일정은 근무 시간대로 바꾼 날짜만 쓴다
+//! (UTC `Z`·`TZID` 값은 변환, floating 값은 그대로). 그 밖의 반복 규칙은 첫 번째 일정만 가져온다.
+//!
+//! 내보내기(`write`)는 종일 일정과 시각 일정만 쓴다. 시각은 `VTIMEZONE` 없이 UTC(`Z`)로 쓴다.
+
+use chrono::{DateTime, Datelike, FixedOffset, Local, Months, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// 파싱한 달력
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcsCalendar {
+    /// `X-WR-CALNAME`
+    pub name: Option<String>,
+    pub events: Vec<IcsEvent>,
+}
+
+/// 종일(또는 여러 날) 일정 하나. 반복 규칙째로 `company-holidays.json`에 저장한다
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IcsEvent {
+    pub summary: String,
+    pub start: NaiveDate,
+    /// 마지막 날 다음 날 (exclusive)
+    pub end: NaiveDate,
+    pub recurrence: Option<YearlyRule>,
+    /// 반복에서 뺄 시작일 (`EXDATE`)
+    pub exceptions: Vec<NaiveDate>,
+}
+
+/// `RRULE:FREQ=YEARLY` (INTERVAL·COUNT·UNTIL)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct YearlyRule {
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<NaiveDate>,
+}
+
+impl IcsEvent {
+    /// 일정이 며칠짜리인지 (최소 1일)
+    fn length(&self) -> usize {
+        (self.end - self.start).num_days().max(1) as usize
+    }
+
+    /// 반복을 펼친 시작일들 (날짜순, `EXDATE` 제외). 반복이 끝나지 않으면 무한
+    fn starts(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        let rule = self.recurrence.as_ref();
+        let step = rule.map_or(0, |rule| 12 * rule.interval);
+        let count = rule.map_or(1, |rule| {
+            rule.count.map_or(usize::MAX, |count| count as usize)
+        });
+        (0..)
+            .map_while(move |n| self.start.checked_add_months(Months::new(step * n)))
+            .take(count)
+            .take_while(move |start| {
+                rule.and_then(|rule| rule.until)
+                    .is_none_or(|last| *start <= last)
+            })
+            .filter(|start| !self.exceptions.contains(start))
+    }
+
+    /// `until`(포함)까지 이 일정이 차지하는 날짜들 (반복·여러 날 일정 펼침)
+    pub fn dates_until(&self, until: NaiveDate) -> Vec<NaiveDate> {
+        let length = self.length();
+        self.starts()
+            .take_while(|start| *start <= until)
+            .flat_map(|start| start.iter_days().take(length))
+            .filter(|day| *day <= until)
+            .collect()
+    }
+
+    /// `date`가 이 일정(반복 포함)에 들어가는지. 펼칠 끝 날짜 없이 몇 년 뒤든 계산한다
+    pub fn covers(&self, date: NaiveDate) -> bool {
+        let length = self.length() as i64;
+        self.starts()
+            .take_while(|start| *start <= date)
+            .any(|start| (date - start).num_days() < length)
+    }
+}
+
+/// 접힌 줄(CRLF + 공백/탭)을 펴서 논리 줄로
+fn unfold(contents: &str) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+    for raw in contents.lines() {
+        match raw.strip_prefix([' ', '\t']) {
+            Some(rest) if !lines.is_empty() => {
+                if let Some(last) = lines.last_mut() {
+                    last.push_str(rest);
+                }
+            }
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+/// `NAME;TZID=..:VALUE` → (NAME 대문자, TZID, VALUE)
+fn split_property(line: &str) -> Option<(String, Option<&str>, &str)> {
+    let (head, value) = line.split_once(':')?;
+    let mut params = head.split(';');
+    let name = params.next()?.to_ascii_uppercase();
+    let tzid = params.find_map(|param| {
+        let (key, val) = param.split_once('=')?;
+        key.eq_ignore_ascii_case("TZID")
+            .then(|| val.trim_matches('"'))
+    });
+    Some((name, tzid, value))
+}
+
+/// TEXT 값 escape 해제 (`\,` `\;` `\n` `\\`)
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push(' '),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out.trim().to_string()
+}
+
+/// `20250101` 또는 `20250101T090000(Z)` → 근무 시간대(`zone`, 없으면 시스템) 기준
+/// (날짜, 자정이 아닌 시각이 있는지)
+///
+/// UTC(`Z`)와 알 수 있는 `TZID` 시각은 근무 시간대로 바꾸고, floating 시각은 그대로 쓴다.
+fn parse_date(value: &str, tzid: Option<&str>, zone: Option<Tz>) -> Option<(NaiveDate, bool)> {
+    let value = value.trim();
+    let date = NaiveDate::parse_from_str(value.get(..8)?, "%Y%m%d").ok()?;
+    let Some(time) = value.get(8..).and_then(|rest| rest.strip_prefix('T')) else {
+        return Some((date, false));
+    };
+    let naive = date.and_time(NaiveTime::parse_from_str(time.get(..6)?, "%H%M%S").ok()?);
+    let instant = if time[6..].eq_ignore_ascii_case("Z") {
+        Some(naive.and_utc())
+    } else {
+        tzid.and_then(|id| id.parse::<Tz>().ok())
+            .and_then(|source| source.from_local_datetime(&naive).earliest())
+            .map(|time| time.with_timezone(&Utc))
+    };
+    let local = match (instant, zone) {
+        (None, _) => naive,
+        (Some(instant), Some(zone)) => instant.with_timezone(&zone).naive_local(),
+        (Some(instant), None) => instant.with_timezone(&Local).naive_local(),
+    };
+    Some((local.date(), local.time() != NaiveTime::MIN))
+}
+
+/// 파싱 중인 `VEVENT`
+#[derive(Default)]
+struct PendingEvent {
+    summary: String,
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    recurrence: Option<YearlyRule>,
+    exceptions: Vec<NaiveDate>,
+    cancelled: bool,
+}
+
+fn parse_yearly_rule(value: &str, zone: Option<Tz>) -> Result<Option<YearlyRule>, String> {
+    let mut freq = None;
+    let mut rule = YearlyRule {
+        interval: 1,
+        count: None,
+        until: None,
+    };
+    for part in value.split(';') {
+        let Some((key, val)) = part.split_once('=') else {
+            continue;
+        };
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => freq = Some(val.to_ascii_uppercase()),
+            "INTERVAL" => {
+                rule.interval = val
+                    .parse()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or("RRULE INTERVAL이 올바르지 않습니다")?
+            }
+            "COUNT" => {
+                rule.count = Some(val.parse().map_err(|_| "RRULE COUNT가 올바르지 않습니다")?)
+            }
+            "UNTIL" => {
+                rule.until = Some(
+                    parse_date(val, None, zone)
+                        .ok_or("RRULE UNTIL이 올바르지 않습니다")?
+                        .0,
+                )
+            }
+            _ => {}
+        }
+    }
+    if freq.as_deref() == Some("YEARLY") {
+        Ok(Some(rule))
+    } else {
+        log::warn!("지원하지 않는 반복 규칙, 첫 일정만 가져옴: {value}");
+        Ok(None)
+    }
+}
+
+/// .ics 내용 파싱 (시각은 근무 시간대 `zone` 기준). `VEVENT`가 하나도 없으면 에러
+pub fn parse(contents: &str, zone: Option<Tz>) -> Result<IcsCalendar, String> {
+    let lines = unfold(contents);
+    if !lines
+        .iter()
+        .any(|line| line.trim().eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return Err("iCalendar 파일이 아닙니다".to_string());
+    }
+
+    let mut name = None;
+    let mut events = vec![];
+    let mut current: Option<PendingEvent> = None;
+
+    for line in &lines {
+        let Some((property, tzid, value)) = split_property(line) else {
+            continue;
+        };
+        match (property.as_str(), current.as_mut()) {
+            ("BEGIN", None) if value.eq_ignore_ascii_case("VEVENT") => {
+                current = Some(PendingEvent::default());
+            }
+            ("END", Some(_)) if value.eq_ignore_ascii_case("VEVENT") => {
+                let Some(event) = current.take() else {
+                    continue;
+                };
+                let start = event.start.ok_or("DTSTART가 없는 일정이 있습니다")?;
+                if event.cancelled {
+                    continue;
+                }
+                let end = event
+                    .end
+                    .filter(|end| *end > start)
+                    .unwrap_or(start + chrono::Days::new(1));
+                events.push(IcsEvent {
+                    summary: event.summary,
+                    start,
+                    end,
+                    recurrence: event.recurrence,
+                    exceptions: event.exceptions,
+                });
+            }
+            ("X-WR-CALNAME", None) => {
+                name = Some(unescape(value)).filter(|name| !name.is_empty());
+            }
+            ("SUMMARY", Some(event)) => event.summary = unescape(value),
+            ("DTSTART", Some(event)) => {
+                event.start = Some(
+                    parse_date(value, tzid, zone)
+                        .ok_or("DTSTART 형식이 올바르지 않습니다")?
+                        .0,
+                );
+            }
+            ("DTEND", Some(event)) => {
+                let (date, has_time) =
+                    parse_date(value, tzid, zone).ok_or("DTEND 형식이 올바르지 않습니다")?;
+                // 시각이 있으면 그날도 포함
+                event.end = Some(if has_time {
+                    date + chrono::Days::new(1)
+                } else {
+                    date
+                });
+            }
+            ("RRULE", Some(event)) => event.recurrence = parse_yearly_rule(value, zone)?,
+            ("EXDATE", Some(event)) => {
+                event.exceptions.extend(
+                    value
+                        .split(',')
+                        .filter_map(|v| parse_date(v, tzid, zone).map(|d| d.0)),
+                );
+            }
+            ("STATUS", Some(event)) => {
+                event.cancelled = value.trim().eq_ignore_ascii_case("CANCELLED")
+            }
+            _ => {}
+        }
+    }
+
+    if events.is_empty() {
+        return Err("가져올 일정이 없습니다".to_string());
+    }
+    Ok(IcsCalendar { name, events })
+}
+
+/// 반복 일정을 펼칠 끝 날짜 (`today` 다음 해 12월 31일)
+pub fn expansion_horizon(today: NaiveDate) -> NaiveDate {
+    NaiveDate::from_ymd_opt(today.year() + 1, 12, 31).unwrap_or(today)
+}
+
+// ============================================================================
+// Writer
+// ============================================================================
+
+/// 내보낼 일정 시각
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventTime {
+    AllDay(NaiveDate),
+    /// 근무 시간대 오프셋이 붙은 시각 (`write`가 UTC로 바꿔 쓴다)
+    Timed {
+        start: DateTime<FixedOffset>,
+        end: DateTime<FixedOffset>,
+    },
+}
+
+/// 내보낼 일정 하나
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportEvent {
+    /// 다시 내보내도 같은 값이어야 캘린더 앱이 같은 일정으로 갱신한다
+    pub uid: String,
+    pub summary: String,
+    pub time: EventTime,
+}
+
+/// TEXT 값 escape
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// 75 octet을 넘는 줄을 접어 CRLF로 끝낸다
+fn fold(line: &str, out: &mut String) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+/// 달력 `name`의 .ics 내용. `stamp`는 `DTSTAMP` (UTC), `timezone`은 `X-WR-TIMEZONE` 표시용
+pub fn write(
+    name: &str,
+    timezone: Option<&str>,
+    events: &[ExportEvent],
+    stamp: DateTime<Utc>,
+) -> String {
+    let utc = |time: DateTime<Utc>| time.format("%Y%m%dT%H%M%SZ").to_string();
+    let stamp = utc(stamp);
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//moa//Workday Calendar//KO".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", escape(name)),
+    ];
+    if let Some(tz) = timezone {
+        lines.push(format!("X-WR-TIMEZONE:{tz}"));
+    }
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", event.uid));
+        lines.push(format!("DTSTAMP:{stamp}"));
+        match event.time {
+            EventTime::AllDay(date) => {
+                lines.push(format!("DTSTART;VALUE=DATE:{}", date.format("%Y%m%d")));
+                lines.push(format!(
+                    "DTEND;VALUE=DATE:{}",
+                    (date + chrono::Days::new(1)).format("%Y%m%d")
+                ));
+                lines.push("TRANSP:TRANSPARENT".to_string());
+            }
+            EventTime::Timed { start, end } => {
+                lines.push(format!("DTSTART:{}", utc(start.to_utc())));
+                lines.push(format!("DTEND:{}", utc(end.to_utc())));
+            }
+        }
+        lines.push(format!("SUMMARY:{}", escape(&event.summary)));
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut out = String::new();
+    for line in &lines {
+        fold(line, &mut out);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEOUL: Option<Tz> = Some(chrono_tz::Asia::Seoul);
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    const COMPANY_ICS: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//Example//Company Calendar//KO\r\n\
+X-WR-CALNAME:모아 회사 휴무\r\n\
+BEGIN:VEVENT\r\n\
+UID:founding@example.com\r\n\
+DTSTART;VALUE=DATE:20190415\r\n\
+DTEND;VALUE=DATE:20190416\r\n\
+RRULE:FREQ=YEARLY\r\n\
+EXDATE;VALUE=DATE:20260415\r\n\
+SUMMARY:창립기념일\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:shutdown-2025@example.com\r\n\
+DTSTART;VALUE=DATE:20251229\r\n\
+DTEND;VALUE=DATE:20260101\r\n\
+SUMMARY:연말 휴무\\, 전사\r\n  (사무실 폐쇄)\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:cancelled@example.com\r\n\
+DTSTART;VALUE=DATE:20251110\r\n\
+STATUS:CANCELLED\r\n\
+SUMMARY:취소된 워크숍\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    #[test]
+    fn parses_company_calendar() {
+        let calendar = parse(COMPANY_ICS, SEOUL).unwrap();
+        assert_eq!(calendar.name.as_deref(), Some("모아 회사 휴무"));
+        assert_eq!(calendar.events.len(), 2);
+
+        let shutdown = &calendar.events[1];
+        assert_eq!(shutdown.summary, "연말 휴무, 전사 (사무실 폐쇄)");
+        assert_eq!(
+            shutdown.dates_until(date("2026-12-31")),
+            vec![date("2025-12-29"), date("2025-12-30"), date("2025-12-31")]
+        );
+    }
+
+    #[test]
+    fn expands_yearly_recurrence_with_exceptions() {
+        let calendar = parse(COMPANY_ICS, SEOUL).unwrap();
+        let founding = &calendar.events[0];
+        assert_eq!(
+            founding.dates_until(date("2027-12-31")),
+            vec![
+                date("2019-04-15"),
+                date("2020-04-15"),
+                date("2021-04-15"),
+                date("2022-04-15"),
+                date("2023-04-15"),
+                date("2024-04-15"),
+                date("2025-04-15"),
+                date("2027-04-15"),
+            ]
+        );
+
+        let limited = IcsEvent {
+            recurrence: Some(YearlyRule {
+                interval: 2,
+                count: Some(3),
+                until: None,
+            }),
+            exceptions: vec![],
+            ..founding.clone()
+        };
+        assert_eq!(
+            limited.dates_until(date("2030-12-31")),
+            vec![date("2019-04-15"), date("2021-04-15"), date("2023-04-15")]
+        );
+    }
+
+    #[test]
+    fn timed_events_use_their_dates() {
+        let ics = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:20251231T090000Z\n\
+DTEND:20260102T120000Z\nSUMMARY:종무식\nEND:VEVENT\nBEGIN:VEVENT\n\
+DTSTART:20260105T000000\nSUMMARY:시무식 휴무\nEND:VEVENT\nEND:VCALENDAR\n";
+        let calendar = parse(ics, SEOUL).unwrap();
+        assert_eq!(calendar.name, None);
+        assert_eq!(
+            calendar.events[0].dates_until(date("2026-12-31")),
+            vec![date("2025-12-31"), date("2026-01-01"), date("2026-01-02")]
+        );
+        assert_eq!(
+            calendar.events[1].dates_until(date("2026-12-31")),
+            vec![date("2026-01-05")]
+        );
+    }
+
+    #[test]
+    fn utc_and_tzid_times_use_work_timezone_dates() {
+        let ics = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:20251231T150000Z\n\
+DTEND:20251231T170000Z\nSUMMARY:신년 휴무\nEND:VEVENT\nBEGIN:VEVENT\n\
+DTSTART;TZID=America/New_York:20260414T230000\nSUMMARY:창립기념일\nEND:VEVENT\n\
+END:VCALENDAR\n";
+        let calendar = parse(ics, SEOUL).unwrap();
+        // 12/31 15:00Z = 1/1 00:00 KST (자정 시작, 02:00 끝)
+        assert_eq!(
+            calendar.events[0].dates_until(date("2026-12-31")),
+            vec![date("2026-01-01")]
+        );
+        // 4/14 23:00 뉴욕 = 4/15 12:00 KST
+        assert_eq!(
+            calendar.events[1].dates_until(date("2026-12-31")),
+            vec![date("2026-04-15")]
+        );
+
+        let in_new_york = parse(ics, Some(chrono_tz::America::New_York)).unwrap();
+        assert_eq!(
+            in_new_york.events[0].dates_until(date("2026-12-31")),
+            vec![date("2025-12-31")]
+        );
+    }
+
+    #[test]
+    fn covers_yearly_dates_without_a_horizon() {
+        let founding = &parse(COMPANY_ICS, SEOUL).unwrap().events[0];
+        assert!(founding.covers(date("2019-04-15")));
+        assert!(founding.covers(date("2040-04-15")));
+        assert!(!founding.covers(date("2026-04-15")));
+        assert!(!founding.covers(date("2040-04-16")));
+        assert!(!founding.covers(date("2018-04-15")));
+
+        let shutdown = &parse(COMPANY_ICS, SEOUL).unwrap().events[1];
+        assert!(shutdown.covers(date("2025-12-31")));
+        assert!(!shutdown.covers(date("2026-01-01")));
+    }
+
+    #[test]
+    fn written_calendar_parses_back() {
+        let events = vec![
+            ExportEvent {
+                uid: "2025-02-25-payday@moa".to_string(),
+                summary: "월급날".to_string(),
+                time: EventTime::AllDay(date("2025-02-25")),
+            },
+            ExportEvent {
+                uid: "2025-02-26-shift@moa".to_string(),
+                summary:
+                    "근무 22:00–06:00; 야간, 교대 근무가 길어서 한 줄이 75바이트를 넘는 일정 제목"
+                        .to_string(),
+                time: EventTime::Timed {
+                    start: DateTime::parse_from_rfc3339("2025-02-26T22:00:00+09:00").unwrap(),
+                    end: DateTime::parse_from_rfc3339("2025-02-27T06:00:00+09:00").unwrap(),
+                },
+            },
+        ];
+        let stamp = DateTime::parse_from_rfc3339("2025-02-20T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let contents = write("모아 근무", Some("Asia/Seoul"), &events, stamp);
+        assert!(contents.lines().all(|line| line.len() <= 76));
+        assert!(contents.contains("DTSTART:20250226T130000Z\r\n"));
+        assert!(contents.contains("DTEND:20250226T210000Z\r\n"));
+
+        let parsed = parse(&contents, SEOUL).unwrap();
+        assert_eq!(parsed.name.as_deref(), Some("모아 근무"));
+        assert_eq!(parsed.events[0].summary, "월급날");
+        assert_eq!(
+            parsed.events[0].dates_until(date("2025-12-31")),
+            vec![date("2025-02-25")]
+        );
+        assert_eq!(parsed.events[1].summary, events[1].summary);
+        assert_eq!(
+            parsed.events[1].dates_until(date("2025-12-31")),
+            vec![date("2025-02-26"), date("2025-02-27")]
+        );
+    }
+
+    #[test]
+    fn rejects_files_without_events() {
+        assert!(parse("hello", SEOUL).is_err());
+        assert!(parse("BEGIN:VCALENDAR\nEND:VCALENDAR\n", SEOUL).is_err());
+        assert!(parse(
+            "BEGIN:VCALENDAR\nBEGIN:VEVENT\nSUMMARY:x\nEND:VEVENT\nEND:VCALENDAR",
+            SEOUL
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,337 @@
+//! 연차 잔여 계산 (근로기준법 제60조).
+//!
+//! 입사일(`UserSettings.hire_date`) 기준으로 부여한다.
+//! - 입사 1년 미만: 한 달 근무할 때마다 1일 (최대 11일)
+//! - 1년 이상: 15일, 3년차부터 2년마다 1일 가산 (최대 25일)
+//!
+//! 출근율 80% 요건은 확인하지 않는다. 잔여는 입사 기념일부터 다음 기념일 전날까지의
+//! 연차 연도 안에서 부여·사용·조정을 합산하고, 1년 미만 월차도 1주년에 소멸한다.
+//! 사용분은 `save_workday_cache`가 캐시를 저장할 때마다(서버 sync 포함) 날짜별로 기록한다
+//! (`crate::commands::leave`). 급여처럼 소정 근무일이 아닌 날의 휴가는 차감하지 않는다.
+
+use chrono::{Datelike, Months, NaiveDate};
+use serde::Serialize;
+use specta::Type;
+
+use crate::holidays::HolidayCalendar;
+use crate::types::{LeaveLedger, WorkdayKind};
+
+/// 입사 1년 미만 월차 상한
+const FIRST_YEAR_MAX_DAYS: u32 = 11;
+/// 1년 이상 기본 연차
+const BASE_ANNUAL_DAYS: u32 = 15;
+/// 가산 포함 연차 상한
+const MAX_ANNUAL_DAYS: u32 = 25;
+
+/// 해당 종류가 쓰는 연차 일수 (연차 1, 반차 0.5, 반반차 0.25). 병가·무급휴가는 0
+pub fn leave_days(kind: &WorkdayKind) -> f64 {
+    match kind {
+        WorkdayKind::AnnualLeave => 1.0,
+        WorkdayKind::MorningHalfLeave | WorkdayKind::AfternoonHalfLeave => 0.5,
+        WorkdayKind::MorningQuarterLeave | WorkdayKind::AfternoonQuarterLeave => 0.25,
+        WorkdayKind::Work
+        | WorkdayKind::DayOff
+        | WorkdayKind::PublicHoliday
+        | WorkdayKind::UnpaidLeave
+        | WorkdayKind::SickLeave => 0.0,
+    }
+}
+
+/// `date`에 차감할 연차 일수. 근무 요일이 아니거나 공휴일·회사 휴무일이면 지급·공제가 없으므로 0
+pub fn debit_days(
+    kind: &WorkdayKind,
+    date: NaiveDate,
+    work_days: &[u8],
+    holidays: &HolidayCalendar,
+) -> f64 {
+    let weekday = date.weekday().num_days_from_sunday() as u8;
+    if !work_days.contains(&weekday) || holidays.is_holiday(date) {
+        return 0.0;
+    }
+    leave_days(kind)
+}
+
+/// 연차 부여·조정 내역 한 줄
+#[derive(Debug, Clone, Serialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveAccrual {
+    /// 부여일 (YYYY-MM-DD)
+    pub date: String,
+    /// 부여 일수 (조정이면 음수일 수 있음)
+    pub days: f64,
+    /// 예: "입사 3개월", "입사 2년", 조정 메모
+    pub reason: String,
+    /// 소멸일 (다음 연차 연도 시작일, YYYY-MM-DD)
+    pub expires_on: String,
+}
+
+/// 연차 연도 기준 잔여
+#[derive(Debug, Clone, Serialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveBalance {
+    /// 기준일 (YYYY-MM-DD)
+    pub as_of: String,
+    /// 연차 연도 시작일 (입사일 또는 입사 기념일)
+    pub period_start: String,
+    /// 다음 연차 연도 시작일. 이날 남은 연차가 소멸한다
+    pub period_end: String,
+    /// 기준일까지 부여된 일수
+    pub accrued: f64,
+    /// 연차 연도 안에 사용·예약한 일수 (기준일 이후 예약 포함)
+    pub used: f64,
+    /// 수동 조정 합계
+    pub adjusted: f64,
+    pub remaining: f64,
+}
+
+fn add_months(date: NaiveDate, months: u32) -> NaiveDate {
+    date.checked_add_months(Months::new(months))
+        .unwrap_or(NaiveDate::MAX)
+}
+
+fn ymd(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// `day`가 속한 연차 연도 `[시작, 다음 시작)`. 입사 전이면 첫 연도
+fn leave_year(hire: NaiveDate, day: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let mut years = 0;
+    while add_months(hire, 12 * (years + 1)) <= day {
+        years += 1;
+    }
+    (
+        add_months(hire, 12 * years),
+        add_months(hire, 12 * (years + 1)),
+    )
+}
+
+/// `until`까지의 법정 부여 내역 (날짜순)
+pub fn statutory_accruals(hire: NaiveDate, until: NaiveDate) -> Vec<LeaveAccrual> {
+    let first_year_end = add_months(hire, 12);
+    let monthly = (1..=FIRST_YEAR_MAX_DAYS).map(|month| {
+        (
+            add_months(hire, month),
+            1,
+            format!("입사 {month}개월"),
+            first_year_end,
+        )
+    });
+    let yearly = (1..).map(|year| {
+        (
+            add_months(hire, 12 * year),
+            (BASE_ANNUAL_DAYS + (year - 1) / 2).min(MAX_ANNUAL_DAYS),
+            format!("입사 {year}년"),
+            add_months(hire, 12 * (year + 1)),
+        )
+    });
+    monthly
+        .chain(yearly)
+        .take_while(|(date, ..)| *date <= until)
+        .map(|(date, days, reason, expires_on)| LeaveAccrual {
+            date: ymd(date),
+            days: days as f64,
+            reason,
+            expires_on: ymd(expires_on),
+        })
+        .collect()
+}
+
+/// 법정 부여와 수동 조정을 합친 `until`까지의 내역 (날짜순)
+pub fn accrual_history(
+    hire: NaiveDate,
+    ledger: &LeaveLedger,
+    until: NaiveDate,
+) -> Vec<LeaveAccrual> {
+    let mut history = statutory_accruals(hire, until);
+    history.extend(ledger.adjustments.iter().filter_map(|adjustment| {
+        let date = NaiveDate::parse_from_str(&adjustment.date, "%Y-%m-%d").ok()?;
+        let (_, expires_on) = leave_year(hire, date);
+        (date <= until).then(|| LeaveAccrual {
+            date: adjustment.date.clone(),
+            days: adjustment.days,
+            reason: adjustment
+                .note
+                .clone()
+                .unwrap_or_else(|| "수동 조정".to_string()),
+            expires_on: ymd(expires_on),
+        })
+    }));
+    history.sort_by(|a, b| a.date.cmp(&b.date));
+    history
+}
+
+/// `day` 기준 잔여. 부여는 `day`까지, 사용·조정은 연차 연도 전체를 센다
+pub fn balance_on(hire: NaiveDate, ledger: &LeaveLedger, day: NaiveDate) -> LeaveBalance {
+    let (start, end) = leave_year(hire, day);
+    let (start, end) = (ymd(start), ymd(end));
+    let in_year = |date: &str| start.as_str() <= date && date < end.as_str();
+
+    let accrued: f64 = statutory_accruals(hire, day)
+        .iter()
+        .filter(|accrual| in_year(&accrual.date))
+        .map(|accrual| accrual.days)
+        .sum();
+    let used: f64 = ledger
+        .debits
+        .iter()
+        .filter(|(date, _)| in_year(date))
+        .map(|(_, days)| days)
+        .sum();
+    let adjusted: f64 = ledger
+        .adjustments
+        .iter()
+        .filter(|adjustment| in_year(&adjustment.date))
+        .map(|adjustment| adjustment.days)
+        .sum();
+
+    LeaveBalance {
+        as_of: ymd(day),
+        period_start: start,
+        period_end: end,
+        accrued,
+        used,
+        adjusted,
+        remaining: accrued + adjusted - used,
+    }
+}
+
+/// `today`가 속한 해의 12월 31일 기준 예상 잔여 (예약한 휴가 반영)
+pub fn year_end_balance(hire: NaiveDate, ledger: &LeaveLedger, today: NaiveDate) -> LeaveBalance {
+    let year_end = NaiveDate::from_ymd_opt(today.year(), 12, 31).unwrap_or(today);
+    balance_on(hire, ledger, year_end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::LeaveAdjustment;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn ledger(debits: &[(&str, f64)], adjustments: &[(&str, f64)]) -> LeaveLedger {
+        LeaveLedger {
+            adjustments: adjustments
+                .iter()
+                .map(|&(d, days)| LeaveAdjustment {
+                    date: d.to_string(),
+                    days,
+                    note: None,
+                })
+                .collect(),
+            debits: debits
+                .iter()
+                .map(|&(d, days)| (d.to_string(), days))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn statutory_accruals_follow_labor_standards_act() {
+        let hire = date("2020-01-31");
+        let accruals = statutory_accruals(hire, date("2041-01-31"));
+
+        let first_year: Vec<_> = accruals.iter().take(11).collect();
+        assert!(first_year.iter().all(|a| a.days == 1.0));
+        // 말일 입사는 짧은 달 말일에 부여
+        assert_eq!(first_year[0].date, "2020-02-29");
+        assert_eq!(first_year[10].date, "2020-12-31");
+        assert!(first_year.iter().all(|a| a.expires_on == "2021-01-31"));
+
+        let yearly: Vec<f64> = accruals.iter().skip(11).map(|a| a.days).collect();
+        // 1·2년 15일, 3년 16일, 5년 17일 … 21년 25일
+        assert_eq!(&yearly[..5], &[15.0, 15.0, 16.0, 16.0, 17.0]);
+        assert_eq!(yearly.len(), 21);
+        assert_eq!(yearly[20], 25.0);
+        assert_eq!(accruals[11].reason, "입사 1년");
+    }
+
+    #[test]
+    fn leave_days_by_kind() {
+        assert_eq!(leave_days(&WorkdayKind::AnnualLeave), 1.0);
+        assert_eq!(leave_days(&WorkdayKind::AfternoonHalfLeave), 0.5);
+        assert_eq!(leave_days(&WorkdayKind::MorningQuarterLeave), 0.25);
+        assert_eq!(leave_days(&WorkdayKind::SickLeave), 0.0);
+        assert_eq!(leave_days(&WorkdayKind::Work), 0.0);
+    }
+
+    #[test]
+    fn leave_on_non_work_days_is_not_debited() {
+        let holidays = HolidayCalendar::bundled();
+        let work_days = [1, 2, 3, 4, 5];
+        let debit =
+            |kind: &WorkdayKind, day: &str| debit_days(kind, date(day), &work_days, &holidays);
+        // 평일
+        assert_eq!(debit(&WorkdayKind::AnnualLeave, "2025-05-07"), 1.0);
+        assert_eq!(debit(&WorkdayKind::MorningHalfLeave, "2025-05-07"), 0.5);
+        // 토요일, 어린이날(월)
+        assert_eq!(debit(&WorkdayKind::AnnualLeave, "2025-05-10"), 0.0);
+        assert_eq!(
+            debit(&WorkdayKind::AfternoonQuarterLeave, "2025-05-05"),
+            0.0
+        );
+        // 주말 근무자의 토요일은 차감
+        let weekend = [0, 6];
+        assert_eq!(
+            debit_days(
+                &WorkdayKind::AnnualLeave,
+                date("2025-05-10"),
+                &weekend,
+                &holidays
+            ),
+            1.0
+        );
+    }
+
+    #[test]
+    fn first_year_balance_counts_months_worked() {
+        let hire = date("2025-03-10");
+        let ledger = ledger(&[("2025-06-02", 1.0), ("2025-07-04", 0.5)], &[]);
+        let balance = balance_on(hire, &ledger, date("2025-07-15"));
+        assert_eq!(balance.period_start, "2025-03-10");
+        assert_eq!(balance.period_end, "2026-03-10");
+        // 4월·5월·6월·7월 10일 부여
+        assert_eq!(balance.accrued, 4.0);
+        assert_eq!(balance.used, 1.5);
+        assert_eq!(balance.remaining, 2.5);
+    }
+
+    #[test]
+    fn balance_resets_on_anniversary_with_adjustments() {
+        let hire = date("2022-03-10");
+        let ledger = ledger(
+            &[
+                ("2024-03-08", 1.0),
+                ("2024-04-01", 1.0),
+                ("2024-12-24", 0.25),
+            ],
+            &[("2024-03-20", 2.0), ("2023-05-01", 3.0)],
+        );
+        let balance = balance_on(hire, &ledger, date("2024-05-01"));
+        // 입사 2년 15일 + 조정 2일, 전 연도 사용·조정은 제외, 12월 예약분은 포함
+        assert_eq!(balance.period_start, "2024-03-10");
+        assert_eq!(balance.accrued, 15.0);
+        assert_eq!(balance.adjusted, 2.0);
+        assert_eq!(balance.used, 1.25);
+        assert_eq!(balance.remaining, 15.75);
+
+        let history = accrual_history(hire, &ledger, date("2024-05-01"));
+        let adjustment = history.iter().find(|a| a.date == "2024-03-20").unwrap();
+        assert_eq!(adjustment.reason, "수동 조정");
+        assert_eq!(adjustment.expires_on, "2025-03-10");
+        assert!(history.windows(2).all(|w| w[0].date <= w[1].date));
+    }
+
+    #[test]
+    fn year_end_projection_uses_leave_year_of_december() {
+        // 9월 입사: 12월 31일은 아직 첫 연도라 월차만 부여
+        let hire = date("2025-09-01");
+        let ledger = ledger(&[("2025-12-26", 1.0)], &[]);
+        let projected = year_end_balance(hire, &ledger, date("2025-10-15"));
+        assert_eq!(projected.as_of, "2025-12-31");
+        // 10·11·12월 1일 부여
+        assert_eq!(projected.accrued, 3.0);
+        assert_eq!(projected.remaining, 2.0);
+    }
+}
@@ -7,7 +7,14 @@
 mod api_client;
 mod auth;
 mod bindings;
+mod calendar_export;
 mod commands;
+mod deduction;
+mod goals;
+mod holidays;
+mod ics;
+mod leave;
+mod money;
 mod salary;
 mod tray;
 mod types;
@@ -152,7 +159,10 @@ pub fn run() {
             tray::create(app.handle())?;
 
             // Start background salary ticker (updates tray title every second)
-            salary::start_salary_ticker(app.handle().clone());
+            salary::ticker::start_salary_ticker(app.handle().clone());
+
+            // 근무 일정 .ics 구독 파일 (workday-changed마다 갱신)
+            commands::calendar_export::start_calendar_feed(app.handle());
 
             Ok(())
         })
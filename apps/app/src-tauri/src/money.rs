@@ -0,0 +1,287 @@
+//! 정수 금액 연산.
+//!
+//! 확정 금액(월급·일급·누적액)은 통화 최소 단위(KRW는 원)의 `i64`로 다루고,
+//! 정수화할 때마다 회사별 `RoundingPolicy`를 적용한다. 초당 금액처럼 화면 표시용
+//! 단가만 `f64`로 남긴다.
+//!
+//! 기간 배분은 누적 비율로 정수화하므로 나눠진 금액의 합이 원금과 정확히 일치한다.
+//!
+//! 통화별 표기(자릿수 구분, 소수 구분, 기호 위치)는 각 통화의 대표 로캘을 따른다.
+
+use crate::types::{Currency, ExchangeRate, RoundingPolicy};
+
+/// 금액 (통화 최소 단위)
+pub type Minor = i64;
+
+/// `numerator / denominator`를 정책대로 정수화. `denominator`는 양수.
+pub fn div_round(numerator: i128, denominator: i128, policy: RoundingPolicy) -> Minor {
+    let quotient = numerator.div_euclid(denominator);
+    let twice_remainder = numerator.rem_euclid(denominator) * 2;
+    let round_up = match policy {
+        RoundingPolicy::Floor => false,
+        RoundingPolicy::HalfUp => twice_remainder >= denominator,
+        RoundingPolicy::HalfEven => {
+            twice_remainder > denominator
+                || (twice_remainder == denominator && quotient.rem_euclid(2) == 1)
+        }
+    };
+    (quotient + round_up as i128) as Minor
+}
+
+/// `total × part / whole` 비례 금액. `whole`이 0 이하면 0.
+pub fn prorate(total: Minor, part: i64, whole: i64, policy: RoundingPolicy) -> Minor {
+    if whole <= 0 {
+        return 0;
+    }
+    div_round(total as i128 * part as i128, whole as i128, policy)
+}
+
+/// `total`을 `weights` 비율로 나눈 금액들. 합계는 항상 `total`.
+///
+/// 각 몫은 누적 비율의 정수화 차이라, 반올림 오차가 다음 몫으로 넘어간다.
+pub fn allocate(total: Minor, weights: &[i64], policy: RoundingPolicy) -> Vec<Minor> {
+    let whole: i64 = weights.iter().sum();
+    let mut cumulative = 0;
+    let mut allocated = 0;
+    weights
+        .iter()
+        .map(|&weight| {
+            cumulative += weight;
+            let through = prorate(total, cumulative, whole, policy);
+            let share = through - allocated;
+            allocated = through;
+            share
+        })
+        .collect()
+}
+
+/// 부동소수 금액(공제 추정·시급 환산 결과 등)을 정책대로 정수화.
+pub fn from_f64(amount: f64, policy: RoundingPolicy) -> Minor {
+    // 0.1 + 0.2 같은 표현 오차가 절사·동률 판단을 뒤집지 않도록 먼저 정리
+    let amount = (amount * 1e6).round() / 1e6;
+    let rounded = match policy {
+        RoundingPolicy::Floor => amount.floor(),
+        RoundingPolicy::HalfUp => amount.round(),
+        RoundingPolicy::HalfEven => amount.round_ties_even(),
+    };
+    rounded as Minor
+}
+
+/// `amount`(`from` 최소 단위)를 환율표로 `to` 최소 단위로 환산. 환율이 없으면 None.
+///
+/// 역방향 환율만 있으면 역수를 쓴다.
+pub fn convert(
+    amount: Minor,
+    from: Currency,
+    to: Currency,
+    rates: &[ExchangeRate],
+    policy: RoundingPolicy,
+) -> Option<Minor> {
+    if from == to {
+        return Some(amount);
+    }
+    let rate = rates
+        .iter()
+        .find_map(|r| match (r.from, r.to) {
+            (f, t) if f == from && t == to => Some(r.rate),
+            (f, t) if f == to && t == from => Some(1.0 / r.rate),
+            _ => None,
+        })
+        .filter(|rate| rate.is_finite() && *rate > 0.0)?;
+    let scale = 10f64.powi(to.minor_digits() as i32 - from.minor_digits() as i32);
+    Some(from_f64(amount as f64 * rate * scale, policy))
+}
+
+/// 통화별 표기 규칙
+struct CurrencyFormat {
+    prefix: &'static str,
+    suffix: &'static str,
+    group_separator: char,
+    decimal_separator: char,
+}
+
+const fn currency_format(currency: Currency) -> CurrencyFormat {
+    match currency {
+        // ko-KR: 1,234원
+        Currency::Krw => CurrencyFormat {
+            prefix: "",
+            suffix: "원",
+            group_separator: ',',
+            decimal_separator: '.',
+        },
+        // en-US: $1,234.56
+        Currency::Usd => CurrencyFormat {
+            prefix: "$",
+            suffix: "",
+            group_separator: ',',
+            decimal_separator: '.',
+        },
+        // ja-JP: ¥1,234
+        Currency::Jpy => CurrencyFormat {
+            prefix: "¥",
+            suffix: "",
+            group_separator: ',',
+            decimal_separator: '.',
+        },
+        // de-DE: 1.234,56 €
+        Currency::Eur => CurrencyFormat {
+            prefix: "",
+            suffix: "\u{a0}€",
+            group_separator: '.',
+            decimal_separator: ',',
+        },
+    }
+}
+
+/// 최소 단위 금액을 통화 표기로. 예: `1,234원`, `$1,234.56`, `1.234,56 €`
+pub fn format_amount(amount: Minor, currency: Currency) -> String {
+    let format = currency_format(currency);
+    let divisor = 10u64.pow(currency.minor_digits());
+    let abs = amount.unsigned_abs();
+    let mut number = group_digits(abs / divisor, format.group_separator);
+    if currency.minor_digits() > 0 {
+        number.push(format.decimal_separator);
+        number.push_str(&format!(
+            "{:0width$}",
+            abs % divisor,
+            width = currency.minor_digits() as usize
+        ));
+    }
+    let sign = if amount < 0 { "-" } else { "" };
+    format!("{sign}{}{number}{}", format.prefix, format.suffix)
+}
+
+/// 세 자리마다 `separator`로 구분. 예: 1234567 → "1,234,567"
+pub fn group_digits(n: u64, separator: char) -> String {
+    let s = n.to_string();
+    let len = s.len();
+    if len <= 3 {
+        return s;
+    }
+    let mut result = String::with_capacity(len + len / 3);
+    for (i, ch) in s.chars().enumerate() {
+        if i > 0 && (len - i).is_multiple_of(3) {
+            result.push(separator);
+        }
+        result.push(ch);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn div_round_applies_policy() {
+        assert_eq!(div_round(5, 2, RoundingPolicy::Floor), 2);
+        assert_eq!(div_round(5, 2, RoundingPolicy::HalfUp), 3);
+        assert_eq!(div_round(5, 2, RoundingPolicy::HalfEven), 2);
+        assert_eq!(div_round(7, 2, RoundingPolicy::HalfEven), 4);
+        assert_eq!(div_round(8, 3, RoundingPolicy::Floor), 2);
+        assert_eq!(div_round(8, 3, RoundingPolicy::HalfEven), 3);
+    }
+
+    #[test]
+    fn allocate_reconciles_with_total() {
+        // 3,000,000원을 9h 17일 + 7h 4일로 배분
+        let weights: Vec<i64> = std::iter::repeat_n(540, 17)
+            .chain(std::iter::repeat_n(420, 4))
+            .collect();
+        for policy in [
+            RoundingPolicy::Floor,
+            RoundingPolicy::HalfUp,
+            RoundingPolicy::HalfEven,
+        ] {
+            let shares = allocate(3_000_000, &weights, policy);
+            assert_eq!(shares.iter().sum::<Minor>(), 3_000_000);
+            // 같은 시간의 날끼리는 1원 이내 차이
+            let (min, max) = (shares[..17].iter().min(), shares[..17].iter().max());
+            assert!(max.unwrap() - min.unwrap() <= 1);
+        }
+    }
+
+    #[test]
+    fn allocate_equal_weights_differ_by_at_most_one() {
+        let shares = allocate(100, &[1, 1, 1], RoundingPolicy::HalfUp);
+        assert_eq!(shares, vec![33, 34, 33]);
+    }
+
+    #[test]
+    fn from_f64_ignores_representation_error() {
+        assert_eq!(from_f64(26_999.999_999_99, RoundingPolicy::Floor), 27_000);
+        assert_eq!(from_f64(2.5, RoundingPolicy::HalfUp), 3);
+        assert_eq!(from_f64(2.5, RoundingPolicy::HalfEven), 2);
+        assert_eq!(from_f64(2.49, RoundingPolicy::HalfUp), 2);
+    }
+
+    #[test]
+    fn group_digits_inserts_separators() {
+        assert_eq!(group_digits(0, ','), "0");
+        assert_eq!(group_digits(999, ','), "999");
+        assert_eq!(group_digits(1000, ','), "1,000");
+        assert_eq!(group_digits(1234567, ','), "1,234,567");
+        assert_eq!(group_digits(100000000, '.'), "100.000.000");
+    }
+
+    #[test]
+    fn format_amount_follows_currency_locale() {
+        assert_eq!(format_amount(1_234_567, Currency::Krw), "1,234,567원");
+        assert_eq!(format_amount(123_456, Currency::Usd), "$1,234.56");
+        assert_eq!(format_amount(5, Currency::Usd), "$0.05");
+        assert_eq!(format_amount(1_234, Currency::Jpy), "¥1,234");
+        assert_eq!(format_amount(123_456, Currency::Eur), "1.234,56\u{a0}€");
+        assert_eq!(format_amount(-1_000, Currency::Krw), "-1,000원");
+    }
+
+    #[test]
+    fn convert_uses_direct_or_inverse_rate() {
+        let rates = [ExchangeRate {
+            from: Currency::Usd,
+            to: Currency::Krw,
+            rate: 1_400.0,
+        }];
+        // $12.34 → 17,276원
+        assert_eq!(
+            convert(
+                1_234,
+                Currency::Usd,
+                Currency::Krw,
+                &rates,
+                RoundingPolicy::HalfUp
+            ),
+            Some(17_276)
+        );
+        // 14,000원 → $10.00
+        assert_eq!(
+            convert(
+                14_000,
+                Currency::Krw,
+                Currency::Usd,
+                &rates,
+                RoundingPolicy::HalfUp
+            ),
+            Some(1_000)
+        );
+        assert_eq!(
+            convert(
+                100,
+                Currency::Krw,
+                Currency::Jpy,
+                &rates,
+                RoundingPolicy::HalfUp
+            ),
+            None
+        );
+        assert_eq!(
+            convert(
+                100,
+                Currency::Jpy,
+                Currency::Jpy,
+                &rates,
+                RoundingPolicy::HalfUp
+            ),
+            Some(100)
+        );
+    }
+}
@@ -0,0 +1,493 @@
+//! 급여 예상과 올해·입사 이후 누적.
+//!
+//! 지난 급여 기간 확정액은 프로필별로 메모해 ticker와 급여 예상이 같이 쓴다.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate};
+use serde::Serialize;
+use specta::Type;
+use tauri::AppHandle;
+
+use crate::holidays::HolidayCalendar;
+use crate::money::{self, Minor};
+use crate::types::{UserSettings, WorkStatus, PRIMARY_JOB_ID};
+
+use super::rates::{pay_period, pay_rates};
+use super::{
+    calculate_with_overrides, current_shift, day_earnings, get_recovery_dir,
+    is_public_holiday_cache, load_settings, overrides_from_cache, with_builtin_holiday, work_now,
+    DayEarnings, SalaryContext, WorkdayHistory,
+};
+
+// ============================================================================
+// Forecast
+// ============================================================================
+
+/// 급여 예상. 금액은 통화 최소 단위 정수 값 (specta가 i64를 내보내지 않아 f64로 둔다)
+#[derive(Debug, Clone, Serialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SalaryForecast {
+    /// 이번 급여 기간 현재 누적
+    pub accumulated_earnings: f64,
+    /// 오늘 퇴근 시점 예상 누적
+    pub end_of_today: f64,
+    /// 이번 주(월–일) 마지막 날 예상 누적. 월급날이 먼저 오면 기간 마지막 날 기준
+    pub end_of_week: f64,
+    /// 월급날 전날까지 예상 누적 (이번 월급)
+    pub end_of_period: f64,
+    /// 월급날까지 더 벌 금액
+    pub remaining_until_payday: f64,
+    /// 이번 급여 기간 무급휴가·병가 공제 예상액 (계획된 휴가 포함)
+    pub leave_deduction: f64,
+    /// 다음 월급날 (보정 반영, YYYY-MM-DD)
+    pub next_payday: String,
+    /// 월급날까지 남은 일수
+    pub days_until_payday: u32,
+    /// 월급날까지 남은 근무일 수 (오늘 근무가 남아 있으면 포함)
+    pub work_days_until_payday: u32,
+    /// 월급날까지 남은 유급 근무 시간
+    pub hours_until_payday: f64,
+    /// 올해 1월 1일(올해 입사했으면 입사일)부터 현재까지 누적
+    pub year_to_date: f64,
+}
+
+/// 오늘·이번 주·이번 급여 기간 예상 누적, 월급날까지 남은 일수·시간, 올해 누적.
+///
+/// `job_id`가 없거나 `PRIMARY_JOB_ID`면 본업 기준. 계획된 연차 등 미래 날짜의 workday 캐시도 반영한다.
+#[tauri::command]
+#[specta::specta]
+pub fn get_salary_forecast(
+    app: AppHandle,
+    job_id: Option<String>,
+) -> Result<SalaryForecast, String> {
+    let settings = load_settings(&app).ok_or("설정을 찾을 수 없습니다")?;
+    let recovery_dir = get_recovery_dir(&app);
+    let (settings, workday_dir) = match job_id.as_deref() {
+        None | Some(PRIMARY_JOB_ID) => (settings.clone(), recovery_dir.clone()),
+        Some(id) => {
+            let job = settings
+                .job_profiles
+                .iter()
+                .find(|job| job.id == id)
+                .ok_or("근무 프로필을 찾을 수 없습니다")?;
+            (settings.for_job(job), recovery_dir.join("jobs").join(id))
+        }
+    };
+
+    let history = WorkdayHistory::default();
+    let holidays = HolidayCalendar::load(&app);
+    let is_public_holiday = |date| {
+        with_builtin_holiday(&holidays, date, history.get(&recovery_dir, date))
+            .is_some_and(|cache| is_public_holiday_cache(&cache))
+    };
+    let workday = |date| with_builtin_holiday(&holidays, date, history.get(&workday_dir, date));
+    let ctx = SalaryContext {
+        is_public_holiday: &is_public_holiday,
+        workday: &workday,
+    };
+    let mut memo = SETTLED_MEMO.lock().unwrap_or_else(|e| e.into_inner());
+    let memo = memo
+        .entry(job_id.unwrap_or_else(|| PRIMARY_JOB_ID.to_string()))
+        .or_default();
+    forecast_salary(&settings, work_now(&settings), &ctx, memo)
+        .ok_or_else(|| "급여 예상을 계산할 수 없습니다".to_string())
+}
+
+/// `ctx.workday`는 지난 날짜·오늘·미래(계획된 휴가 등) 모두 조회한다.
+/// 올해 누적의 지난 급여 기간분은 `memo`에 보관해 다음 호출에서 다시 쓴다.
+pub(super) fn forecast_salary(
+    settings: &UserSettings,
+    now: DateTime<FixedOffset>,
+    ctx: &SalaryContext,
+    memo: &mut SettledMemo,
+) -> Option<SalaryForecast> {
+    let today = now.date_naive();
+    let workday = ctx.workday;
+    let overrides = workday(today).map(overrides_from_cache);
+    let today_override = overrides.as_ref().and_then(|o| o.schedule.clone());
+    let (_, _, effective_day) = current_shift(
+        settings,
+        today_override
+            .as_ref()
+            .map(|(s, e)| (s.as_str(), e.as_str())),
+        now.naive_local(),
+    );
+    let current = calculate_with_overrides(settings, overrides, now, ctx)?;
+    let (period_start, period_end) = pay_period(settings, today, ctx.is_public_holiday)?;
+    let rates = pay_rates(settings, period_start, period_end, ctx)?;
+    let rounding = settings.rounding_policy;
+
+    // 근무 귀속일의 오늘분은 근무가 남아 있으면 하루 전체, 아니면 현재 확정액
+    let today_settled = money::from_f64(current.today_earnings, rounding);
+    let work_remaining = matches!(
+        current.work_status,
+        WorkStatus::BeforeWork | WorkStatus::Working | WorkStatus::OnBreak
+    );
+    let upcoming: Vec<(NaiveDate, DayEarnings)> = effective_day
+        .iter_days()
+        .take_while(|&day| day < period_end)
+        .map(|day| {
+            let earnings = if day != effective_day {
+                day_earnings(settings, &rates, day, workday)
+            } else if work_remaining {
+                // 반차·반반차의 휴가 지급분은 금액에만 있으므로 시간은 실근로 초로 뺀다
+                let full = day_earnings(settings, &rates, day, workday);
+                DayEarnings {
+                    earnings: full.earnings,
+                    hours: (full.hours - current.worked_seconds as f64 / 3600.0).max(0.0),
+                    deduction: 0,
+                }
+            } else {
+                // 오늘 공제분은 `current.leave_deduction`에 이미 포함
+                DayEarnings {
+                    earnings: today_settled,
+                    hours: 0.0,
+                    deduction: 0,
+                }
+            };
+            (day, earnings)
+        })
+        .collect();
+
+    let settled_before = current.accumulated_earnings - today_settled;
+    let projected_through = |last: NaiveDate| -> Minor {
+        settled_before
+            + upcoming
+                .iter()
+                .filter(|(day, _)| *day <= last)
+                .map(|(_, d)| d.earnings)
+                .sum::<Minor>()
+    };
+    // 야간 근무 자정 이후면 근무 귀속일(어제)의 주 기준
+    let sunday = effective_day
+        + chrono::Days::new(6 - effective_day.weekday().num_days_from_monday() as u64);
+    let end_of_period = projected_through(period_end);
+
+    // 올해 누적: 지난 급여 기간은 날짜별 기간 단가로 계산
+    let year_to_date = earnings_since(
+        settings,
+        year_start(settings, today)?,
+        current.accumulated_earnings,
+        today,
+        ctx,
+        memo,
+    );
+
+    Some(SalaryForecast {
+        accumulated_earnings: current.accumulated_earnings as f64,
+        end_of_today: projected_through(effective_day) as f64,
+        end_of_week: projected_through(sunday) as f64,
+        end_of_period: end_of_period as f64,
+        remaining_until_payday: (end_of_period - current.accumulated_earnings) as f64,
+        leave_deduction: (current.leave_deduction
+            + upcoming.iter().map(|(_, d)| d.deduction).sum::<Minor>())
+            as f64,
+        next_payday: period_end.format("%Y-%m-%d").to_string(),
+        days_until_payday: (period_end - today).num_days() as u32,
+        work_days_until_payday: upcoming.iter().filter(|(_, d)| d.worked()).count() as u32,
+        hours_until_payday: upcoming.iter().map(|(_, d)| d.hours).sum(),
+        year_to_date: year_to_date as f64,
+    })
+}
+
+// ============================================================================
+// Settled totals
+// ============================================================================
+
+pub(super) fn hire_date(settings: &UserSettings) -> Option<NaiveDate> {
+    settings
+        .hire_date
+        .as_deref()
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+}
+
+/// 올해 누적 시작일: 1월 1일, 올해 입사했으면 입사일
+pub(super) fn year_start(settings: &UserSettings, today: NaiveDate) -> Option<NaiveDate> {
+    let jan_1 = NaiveDate::from_ymd_opt(today.year(), 1, 1)?;
+    Some(hire_date(settings).map_or(jan_1, |hired| hired.max(jan_1)))
+}
+
+/// 한 급여 기간 안 `[from, until)` 확정액의 메모 키
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) struct SettledRange {
+    period: (NaiveDate, NaiveDate),
+    from: NaiveDate,
+    until: NaiveDate,
+}
+
+/// 급여 기간별 확정액 메모. 설정이 바뀌면 비우고, workday 캐시가 바뀌면 그 날짜가 든 기간만 지운다
+pub(super) type SettledMemo = HashMap<SettledRange, Minor>;
+
+/// 프로필 id별 확정액 메모. ticker의 올해·입사 이후 누적과 급여 예상의 올해 누적이 같이 쓴다
+pub(super) static SETTLED_MEMO: LazyLock<Mutex<HashMap<String, SettledMemo>>> =
+    LazyLock::new(Default::default);
+
+/// 변경 알림을 보내는 쪽에서 바로 비워, ticker가 알림을 처리하기 전의 급여 예상도 새 값으로 계산한다
+pub(super) fn clear_settled_memo() {
+    SETTLED_MEMO
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clear();
+}
+
+/// `date`가 든 급여 기간의 확정액을 지운다. 휴무 하나가 기간 전체의 일급 배분을 바꾸므로 기간 단위로 지운다
+pub(super) fn forget_settled(memo: &mut SettledMemo, date: NaiveDate) {
+    memo.retain(|range, _| !(range.period.0 <= date && date < range.period.1));
+}
+
+/// `[from, until)` 날짜들의 확정액 합계. 급여 기간별로 나눠 그 기간 단가와 workday 캐시로 계산하고,
+/// 기간별 합계는 `memo`에 보관한다.
+fn settled_earnings(
+    settings: &UserSettings,
+    from: NaiveDate,
+    until: NaiveDate,
+    ctx: &SalaryContext,
+    memo: &mut SettledMemo,
+) -> Minor {
+    let mut total = 0;
+    let mut day = from;
+    while day < until {
+        let Some(period) = pay_period(settings, day, ctx.is_public_holiday) else {
+            break;
+        };
+        let range = SettledRange {
+            period,
+            from: day,
+            until: period.1.min(until),
+        };
+        total += *memo.entry(range).or_insert_with(|| {
+            pay_rates(settings, period.0, period.1, ctx).map_or(0, |rates| {
+                range
+                    .from
+                    .iter_days()
+                    .take_while(|&day| day < range.until)
+                    .map(|day| day_earnings(settings, &rates, day, ctx.workday).earnings)
+                    .sum()
+            })
+        });
+        if period.1 <= day {
+            break;
+        }
+        day = period.1;
+    }
+    total
+}
+
+/// `from`(올해 1월 1일·입사일)부터 지금까지 누적 = 지난 급여 기간 확정액 + 이번 기간 누적(`accumulated`).
+///
+/// `from`이 이번 기간 안이면 기간 시작일부터 `from` 전날까지를 뺀다.
+pub(super) fn earnings_since(
+    settings: &UserSettings,
+    from: NaiveDate,
+    accumulated: Minor,
+    today: NaiveDate,
+    ctx: &SalaryContext,
+    memo: &mut SettledMemo,
+) -> Minor {
+    let Some((period_start, _)) = pay_period(settings, today, ctx.is_public_holiday) else {
+        return accumulated;
+    };
+    if from <= period_start {
+        accumulated + settled_earnings(settings, from, period_start, ctx, memo)
+    } else {
+        accumulated - settled_earnings(settings, period_start, from, ctx, memo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::ticker::totals_since;
+    use super::*;
+    use crate::salary::tests::{
+        calculate_job_salary, calculate_salary, forecast_salary, history_cache,
+        make_hourly_settings, make_overnight_settings, make_settings, with_workday, PLAIN,
+    };
+    use crate::types::MenubarDisplayMode;
+
+    #[test]
+    fn test_forecast_projects_period_and_remaining_work() {
+        let settings = make_settings(3_000_000, 25);
+        // 02-10(월) 10:00, 기간 01-25 ~ 02-25
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let current = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        let forecast = forecast_salary(&settings, now, &PLAIN, &mut SettledMemo::new()).unwrap();
+
+        assert_eq!(
+            forecast.accumulated_earnings,
+            current.accumulated_earnings as f64
+        );
+        assert_eq!(forecast.end_of_period, 3_000_000.0);
+        assert_eq!(
+            forecast.remaining_until_payday,
+            3_000_000.0 - forecast.accumulated_earnings
+        );
+        let today_settled = current.today_earnings.round();
+        assert_eq!(
+            forecast.end_of_today,
+            forecast.accumulated_earnings - today_settled + current.daily_rate as f64
+        );
+        assert!(forecast.end_of_week > forecast.end_of_today);
+        assert_eq!(forecast.next_payday, "2025-02-25");
+        assert_eq!(forecast.days_until_payday, 15);
+        // 02-10 ~ 02-24 평일 11일, 오늘 남은 8h + 10일 × 9h (휴게 없음)
+        assert_eq!(forecast.work_days_until_payday, 11);
+        assert!((forecast.hours_until_payday - 98.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_forecast_accounts_for_planned_leave() {
+        use crate::types::WorkdayKind;
+        let settings = make_settings(3_000_000, 25);
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let planned = |date: NaiveDate| {
+            (date == NaiveDate::from_ymd_opt(2025, 2, 12).unwrap())
+                .then(|| history_cache("2025-02-12", WorkdayKind::DayOff, None))
+        };
+        let forecast = forecast_salary(
+            &settings,
+            now,
+            &with_workday(&planned),
+            &mut SettledMemo::new(),
+        )
+        .unwrap();
+        // 휴무일은 기간 근무일에서 빠지므로 월급은 그대로, 남은 근무일·시간만 준다
+        assert_eq!(forecast.end_of_period, 3_000_000.0);
+        assert_eq!(forecast.work_days_until_payday, 10);
+        assert!((forecast.hours_until_payday - 89.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_forecast_half_leave_today_counts_only_worked_hours() {
+        use crate::types::WorkdayKind;
+        let settings = make_settings(3_000_000, 25);
+        // 02-10(월) 오전 반차, 오후 근무 중
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(16, 0, 0)
+            .unwrap();
+        let leave = |date: NaiveDate| {
+            (date == NaiveDate::from_ymd_opt(2025, 2, 10).unwrap())
+                .then(|| history_cache("2025-02-10", WorkdayKind::MorningHalfLeave, None))
+        };
+        let ctx = with_workday(&leave);
+        let current = calculate_job_salary(&settings, now, &ctx).unwrap();
+        assert_eq!(current.work_status, WorkStatus::Working);
+        let forecast = forecast_salary(&settings, now, &ctx, &mut SettledMemo::new()).unwrap();
+
+        // 휴가 지급분은 근무시간이 아니므로 오늘 남은 시간은 퇴근까지 그대로 남는다
+        let remaining_today = current.seconds_until_clock_out.unwrap() as f64 / 3600.0;
+        assert!(remaining_today > 0.0);
+        assert!((forecast.hours_until_payday - (remaining_today + 10.0 * 9.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_forecast_year_to_date() {
+        // 시급 12,036 (주휴 포함) × 8h, 1/1 ~ 2/7 평일 28일 + 오늘 1h
+        let settings = make_hourly_settings(10_030);
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let forecast = forecast_salary(&settings, now, &PLAIN, &mut SettledMemo::new()).unwrap();
+        assert_eq!(forecast.year_to_date, 12_036.0 * (28.0 * 8.0 + 1.0));
+
+        // 월급날이 1일이면 올해 누적 = 이번 기간 누적
+        let monthly = make_settings(3_000_000, 1);
+        let jan = NaiveDate::from_ymd_opt(2025, 1, 20)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let forecast = forecast_salary(&monthly, jan, &PLAIN, &mut SettledMemo::new()).unwrap();
+        assert_eq!(forecast.year_to_date, forecast.accumulated_earnings);
+
+        // 지난 기간분은 memo에 남아 다음 호출에서 다시 계산하지 않는다
+        let mut memo = SettledMemo::new();
+        let first = forecast_salary(&settings, now, &PLAIN, &mut memo).unwrap();
+        assert_eq!(memo.len(), 1);
+        let later = now + chrono::Duration::hours(1);
+        let second = forecast_salary(&settings, later, &PLAIN, &mut memo).unwrap();
+        assert_eq!(memo.len(), 1);
+        assert_eq!(second.year_to_date, first.year_to_date + 12_036.0);
+    }
+
+    #[test]
+    fn test_year_to_date_starts_at_hire_date_this_year() {
+        // 02-03(월) 입사, 02-10 10:00 → 02-03 ~ 02-07 5일 × 8h + 오늘 1h
+        let settings = UserSettings {
+            hire_date: Some("2025-02-03".to_string()),
+            ..make_hourly_settings(10_030)
+        };
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let forecast = forecast_salary(&settings, now, &PLAIN, &mut SettledMemo::new()).unwrap();
+        assert_eq!(forecast.year_to_date, 12_036.0 * (5.0 * 8.0 + 1.0));
+
+        // 작년 입사면 1월 1일부터
+        let veteran = UserSettings {
+            hire_date: Some("2020-03-02".to_string()),
+            ..settings.clone()
+        };
+        assert_eq!(
+            year_start(&veteran, now.date()),
+            NaiveDate::from_ymd_opt(2025, 1, 1)
+        );
+        let year_to_date = UserSettings {
+            menubar_display_mode: MenubarDisplayMode::YearToDate,
+            ..settings
+        };
+        assert_eq!(
+            totals_since(&year_to_date, now.date()),
+            NaiveDate::from_ymd_opt(2025, 2, 3)
+        );
+    }
+
+    #[test]
+    fn test_workday_change_forgets_only_its_pay_period() {
+        // 월급날 25일: 2024-12-25 ~ 01-25, 01-25 ~ 02-25 두 기간이 메모에 남는다
+        let settings = make_settings(3_000_000, 25);
+        let mut memo = SettledMemo::new();
+        let from = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let until = NaiveDate::from_ymd_opt(2025, 2, 25).unwrap();
+        let total = settled_earnings(&settings, from, until, &PLAIN, &mut memo);
+        assert_eq!(memo.len(), 2);
+
+        forget_settled(&mut memo, NaiveDate::from_ymd_opt(2025, 2, 3).unwrap());
+        assert_eq!(memo.len(), 1);
+        assert!(memo
+            .keys()
+            .all(|range| range.period.1 == NaiveDate::from_ymd_opt(2025, 1, 25).unwrap()));
+        assert_eq!(
+            settled_earnings(&settings, from, until, &PLAIN, &mut memo),
+            total
+        );
+    }
+
+    #[test]
+    fn test_forecast_week_follows_overnight_shift_day() {
+        // 일요일 22:00 ~ 월요일 06:00 근무, 월요일 02:00은 일요일 근무 → 이번 주는 이미 마지막 날
+        let settings = UserSettings {
+            work_days: vec![0, 1, 2, 3, 4],
+            work_start_time: "22:00".to_string(),
+            work_end_time: "06:00".to_string(),
+            ..make_overnight_settings()
+        };
+        let monday_2am = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(2, 0, 0)
+            .unwrap();
+        let forecast =
+            forecast_salary(&settings, monday_2am, &PLAIN, &mut SettledMemo::new()).unwrap();
+        assert_eq!(forecast.end_of_week, forecast.end_of_today);
+        assert!(forecast.end_of_period > forecast.end_of_week);
+    }
+}
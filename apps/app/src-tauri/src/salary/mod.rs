@@ -0,0 +1,2379 @@
+//! Salary calculation and menubar ticker.
+//!
+//! Earnings are calculated per pay period from `UserSettings` and the per-date
+//! `WorkdayCache` (leave, day off, clock in/out), in the work timezone, and
+//! settled in integer minor units (`crate::money`).
+//!
+//! - `ticker`: background thread that updates the tray title and emits `salary-tick`
+//! - `forecast`: payday forecast and year-to-date totals
+//! - `rates`: pay periods, per-period rates, weekly allowance and premiums
+
+pub mod forecast;
+mod rates;
+pub mod ticker;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveDate, Offset, TimeZone, Timelike};
+use serde::Serialize;
+use specta::Type;
+use tauri::{AppHandle, Manager};
+
+use crate::goals::GoalProgress;
+use crate::holidays::HolidayCalendar;
+use crate::money::{self, Minor};
+use crate::types::{BreakWindow, CompanyHoliday, RoundingPolicy, UserSettings, WorkStatus};
+
+pub(crate) use rates::paydays_between;
+use rates::{
+    overtime_threshold_seconds, pay_period, pay_rates, premium_earnings, worked_segments, PayRates,
+};
+
+#[derive(Debug, Clone, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SalaryTickPayload {
+    /// 확정 금액 (통화 최소 단위)
+    pub daily_rate: Minor,
+    pub hourly_rate: f64,
+    pub per_second: f64,
+    /// 지난 날짜 확정액 + 오늘 금액을 `rounding_policy`로 정수화한 값
+    pub accumulated_earnings: Minor,
+    /// 오늘 번 금액 (기본급 + 가산수당 + 상여금)
+    pub today_earnings: f64,
+    pub base_earnings: f64,
+    /// 연장·야간·휴일 가산수당
+    pub premium_earnings: f64,
+    /// 오늘 반영된 상여금 (월급날 일시 반영분 + 적립분)
+    pub bonus_earnings: f64,
+    /// 이번 급여 기간 무급휴가·병가 공제액 (일급 − 지급액 합계, 오늘 포함).
+    /// 쉬는 날(`DayOff`)은 기간 근무시간에서 빠져 월급이 줄지 않으므로 넣지 않는다
+    pub leave_deduction: Minor,
+    pub work_status: WorkStatus,
+    pub is_work_day: bool,
+    pub worked_days: u32,
+    /// 오늘 휴게를 뺀 실제 근무 시간 (초). 반차·반반차의 휴가 부분은 넣지 않는다
+    pub worked_seconds: u32,
+    /// 오늘이 (보정 반영) 월급날인지
+    pub is_payday: bool,
+    /// 퇴근까지 남은 초 (근무·휴게 중일 때만)
+    pub seconds_until_clock_out: Option<u32>,
+    /// 출근까지 남은 초 (출근 전일 때만)
+    pub seconds_until_clock_in: Option<u32>,
+    /// 다음 월급날까지 남은 일수
+    pub days_until_payday: u32,
+    /// 프로필별 내역 (본업 포함). 상위 금액 필드는 이 합계
+    pub jobs: Vec<JobTick>,
+    /// 저축·구매 목표 진행률 (합계 금액 기준)
+    pub goals: Vec<GoalProgress>,
+    /// 오늘이 가져온 회사 휴무일이면 그 항목 (어느 달력에서 왔는지 표시용)
+    pub company_holiday: Option<CompanyHoliday>,
+}
+
+/// 프로필별 급여 요약
+#[derive(Debug, Clone, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct JobTick {
+    pub id: String,
+    pub name: String,
+    pub daily_rate: Minor,
+    pub hourly_rate: f64,
+    pub today_earnings: f64,
+    pub accumulated_earnings: Minor,
+    pub work_status: WorkStatus,
+    pub is_payday: bool,
+    pub seconds_until_clock_out: Option<u32>,
+    pub days_until_payday: u32,
+}
+
+impl JobTick {
+    fn from_payload(id: &str, name: &str, p: &SalaryTickPayload) -> JobTick {
+        JobTick {
+            id: id.to_string(),
+            name: name.to_string(),
+            daily_rate: p.daily_rate,
+            hourly_rate: p.hourly_rate,
+            today_earnings: p.today_earnings,
+            accumulated_earnings: p.accumulated_earnings,
+            work_status: p.work_status.clone(),
+            is_payday: p.is_payday,
+            seconds_until_clock_out: p.seconds_until_clock_out,
+            days_until_payday: p.days_until_payday,
+        }
+    }
+}
+
+/// 내부 ticker용 status enum.
+///
+/// `WorkdayCache.kind`에서 도출되며, `kind=Work`는 None으로 매핑되어
+/// 일반 근무일 계산 경로를 탄다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TodayWorkStatus {
+    AnnualLeave,
+    UnpaidLeave,
+    SickLeave,
+    DayOff,
+    PublicHoliday,
+    /// 공휴일이지만 근무 (`kind=Work` + `PUBLIC_HOLIDAY` 이벤트)
+    HolidayWork,
+    /// 반차·반반차
+    PartialLeave(PartialLeave),
+}
+
+impl TodayWorkStatus {
+    /// 하루 종일 휴가면 (지급 비율 %, 표시 상태). 연차 100%, 무급휴가 0%
+    fn full_day_leave(self, settings: &UserSettings) -> Option<(u8, WorkStatus)> {
+        match self {
+            TodayWorkStatus::AnnualLeave => Some((100, WorkStatus::AnnualLeave)),
+            TodayWorkStatus::UnpaidLeave => Some((0, WorkStatus::UnpaidLeave)),
+            TodayWorkStatus::SickLeave => Some((
+                settings.sick_leave_pay_percent.min(100),
+                WorkStatus::SickLeave,
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// 반차·반반차. 휴가 부분은 일급 비율만큼 한 번에 지급하고 나머지 구간만 근무한다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PartialLeave {
+    /// 휴가 비율 (4분의 n): 반차 2, 반반차 1
+    quarters: u32,
+    /// 근무 앞부분(오전) 휴가인지
+    morning: bool,
+}
+
+impl PartialLeave {
+    fn leave_minutes(&self, paid_minutes: u32) -> u32 {
+        paid_minutes * self.quarters / 4
+    }
+
+    /// 휴가 부분의 일급
+    fn leave_pay(&self, daily_rate: Minor, rounding: RoundingPolicy) -> Minor {
+        money::prorate(daily_rate, self.quarters as i64, 4, rounding)
+    }
+
+    /// 휴가를 뺀 근무 구간 (분). 휴게시간은 유급 시간에 넣지 않고 센다.
+    ///
+    /// 09–18시(점심 12–13시) 기준 오전 반차는 14–18시, 오후 반차는 09–14시 근무.
+    fn working_window(
+        &self,
+        start: u32,
+        end: u32,
+        breaks: &[(u32, u32)],
+        paid_minutes: u32,
+    ) -> (u32, u32) {
+        let leave_minutes = self.leave_minutes(paid_minutes);
+        if self.morning {
+            (paid_point(start, breaks, leave_minutes), end)
+        } else {
+            (
+                start,
+                paid_point(start, breaks, paid_minutes - leave_minutes),
+            )
+        }
+    }
+}
+
+/// `start`부터 휴게 구간(정규화됨)을 빼고 `paid_minutes`만큼 지난 시각 (분)
+fn paid_point(start: u32, breaks: &[(u32, u32)], paid_minutes: u32) -> u32 {
+    let mut cursor = start;
+    let mut remaining = paid_minutes;
+    for &(break_start, break_end) in breaks {
+        let available = break_start.saturating_sub(cursor);
+        if remaining <= available {
+            break;
+        }
+        remaining -= available;
+        cursor = cursor.max(break_end);
+    }
+    cursor + remaining
+}
+
+/// `WorkdayCache`에서 추출한 ticker용 override 정보.
+struct TickerOverrides {
+    status: Option<TodayWorkStatus>,
+    schedule: Option<(String, String)>,
+    completed: bool,
+}
+
+// ============================================================================
+// Salary calculation (1:1 port of use-salary-calculator.ts)
+// ============================================================================
+
+/// 급여 계산의 날짜별 조회.
+///
+/// `is_public_holiday`는 월급날 보정·기간 근무일 산정용 공휴일(본업 달력 기준), `workday`는 프로필의
+/// 날짜별 `WorkdayCache` (내장 달력 반영, None이면 요일 기본 일정).
+#[derive(Clone, Copy)]
+struct SalaryContext<'a> {
+    is_public_holiday: &'a dyn Fn(NaiveDate) -> bool,
+    workday: &'a dyn Fn(NaiveDate) -> Option<crate::types::WorkdayCache>,
+}
+
+/// 지금 시각이 속한 근무의 (출근, 퇴근, 근무 귀속일).
+///
+/// Overnight shift: when in the post-midnight working window (before shift ends),
+/// attribute the shift to the previous calendar day for work-day determination.
+/// 요일별 시간이 다를 수 있어 어제 요일의 일정으로 먼저 판단한다.
+fn current_shift<'a>(
+    settings: &'a UserSettings,
+    today_override: Option<(&'a str, &'a str)>,
+    now: chrono::NaiveDateTime,
+) -> (&'a str, &'a str, NaiveDate) {
+    let today = now.date();
+    let yesterday = today.pred_opt().unwrap_or(today);
+    let raw_current_minutes = now.time().hour() * 60 + now.time().minute();
+    let in_overnight_tail = |(start, end): (&str, &str)| {
+        time_to_minutes(end) < time_to_minutes(start) && raw_current_minutes < time_to_minutes(end)
+    };
+    match today_override {
+        Some(hours) if in_overnight_tail(hours) => (hours.0, hours.1, yesterday),
+        Some(hours) => (hours.0, hours.1, today),
+        None => {
+            let yesterday_hours = settings.hours_for_weekday(weekday_index(yesterday));
+            if in_overnight_tail(yesterday_hours) {
+                (yesterday_hours.0, yesterday_hours.1, yesterday)
+            } else {
+                let (start, end) = settings.hours_for_weekday(weekday_index(today));
+                (start, end, today)
+            }
+        }
+    }
+}
+
+/// 오늘 override를 받아 계산한다. 지난 날짜는 `ctx.workday` 캐시 기준.
+fn calculate_salary(
+    settings: &UserSettings,
+    today_status_override: Option<TodayWorkStatus>,
+    today_override: Option<(&str, &str)>,
+    now: DateTime<FixedOffset>,
+    ctx: &SalaryContext,
+) -> Option<SalaryTickPayload> {
+    let work_days = &settings.work_days;
+    let wall = now.naive_local();
+    let today = wall.date();
+    let raw_current_minutes = wall.hour() * 60 + wall.minute();
+    let (work_start_time, work_end_time, effective_day) =
+        current_shift(settings, today_override, wall);
+
+    let work_start_minutes = time_to_minutes(work_start_time);
+    let raw_end_minutes = time_to_minutes(work_end_time);
+
+    // DST 전환일(23·25시간)은 벽시계 대신 근무일 자정부터의 실제 경과 시간으로 계산
+    let (shift_start, shift_end, breaks) =
+        shift_minutes(settings, effective_day, work_start_time, work_end_time);
+    let break_minutes: u32 = breaks.iter().map(|(s, e)| e - s).sum();
+    let work_minutes_per_day = shift_end.checked_sub(shift_start + break_minutes)?;
+    let work_hours_per_day = work_minutes_per_day as f64 / 60.0;
+
+    let (period_start, period_end) = pay_period(settings, today, ctx.is_public_holiday)?;
+    // JS Date.getDay(): 0=Sun, 1=Mon, ..., 6=Sat
+    let day_of_week = weekday_index(effective_day);
+    let rates = pay_rates(settings, period_start, period_end, ctx)?;
+    let daily_rate = rates.daily_rate(settings, effective_day, work_minutes_per_day);
+    let (hourly_rate, per_second) = if work_hours_per_day > 0.0 {
+        let hr = daily_rate as f64 / work_hours_per_day;
+        (hr, hr / 3600.0)
+    } else {
+        (0.0, 0.0)
+    };
+
+    let is_overnight = raw_end_minutes < work_start_minutes;
+    let is_work_day = work_days.contains(&day_of_week) || today_override.is_some();
+
+    // Overnight shift: normalise current time past midnight.
+    // 어제 일정의 자정 이후 구간이 아닌데 오늘 야간 근무 종료 시각 이전이면 아직 출근 전.
+    // 나머지는 근무 귀속일 자정부터 UTC로 잰 경과 시간이라 서머타임 종료로 반복되는 한 시간도 센다.
+    let current_seconds = if effective_day == today
+        && is_overnight
+        && (raw_end_minutes..work_start_minutes).contains(&raw_current_minutes)
+    {
+        real_minutes(settings, effective_day, raw_current_minutes + 24 * 60) * 60 + wall.second()
+    } else {
+        seconds_since_midnight(settings, effective_day, now)
+    };
+    let current_minutes = current_seconds / 60;
+
+    // 반차·반반차: 휴가 부분은 출근 전부터 지급하고 남은 근무 구간만 진행
+    let leave = match today_status_override {
+        Some(TodayWorkStatus::PartialLeave(leave)) => Some(leave),
+        _ => None,
+    };
+    let leave_pay = leave.map_or(0.0, |leave| {
+        leave.leave_pay(daily_rate, rates.rounding) as f64
+    });
+    let (work_start_minutes, work_end_minutes) = leave.map_or((shift_start, shift_end), |leave| {
+        leave.working_window(shift_start, shift_end, &breaks, work_minutes_per_day)
+    });
+
+    // 근무일이 아닌 날의 휴가는 지급·공제 모두 없다
+    let full_day_leave = today_status_override
+        .filter(|_| is_work_day)
+        .and_then(|s| s.full_day_leave(settings));
+    let leave_paid = full_day_leave.map(|(percent, status)| {
+        let paid = money::prorate(daily_rate, percent as i64, 100, rates.rounding);
+        (paid, status)
+    });
+
+    let (base_earnings, work_status) =
+        if today_status_override == Some(TodayWorkStatus::PublicHoliday) {
+            (0.0, WorkStatus::PublicHoliday)
+        } else if let Some((paid, status)) = leave_paid.clone() {
+            (paid as f64, status)
+        } else if today_status_override == Some(TodayWorkStatus::DayOff) || !is_work_day {
+            (0.0, WorkStatus::DayOff)
+        } else if current_minutes < work_start_minutes {
+            (leave_pay, WorkStatus::BeforeWork)
+        } else if current_minutes >= work_end_minutes {
+            (daily_rate as f64, WorkStatus::Completed)
+        } else {
+            let elapsed_seconds = current_seconds - work_start_minutes * 60;
+            let break_seconds: u32 = breaks
+                .iter()
+                .map(|&(s, e)| overlap(s * 60, e * 60, work_start_minutes * 60, current_seconds))
+                .sum();
+            let worked_seconds = elapsed_seconds - break_seconds;
+            let on_break = breaks
+                .iter()
+                .any(|&(s, e)| (s..e).contains(&current_minutes));
+            let status = if on_break {
+                WorkStatus::OnBreak
+            } else {
+                WorkStatus::Working
+            };
+            (leave_pay + per_second * worked_seconds as f64, status)
+        };
+
+    let seconds_until_clock_out = match work_status {
+        WorkStatus::Working | WorkStatus::OnBreak => {
+            Some((work_end_minutes * 60).saturating_sub(current_seconds))
+        }
+        _ => None,
+    };
+    let seconds_until_clock_in = (work_status == WorkStatus::BeforeWork)
+        .then(|| (work_start_minutes * 60).saturating_sub(current_seconds));
+
+    let worked_until = match work_status {
+        WorkStatus::Working | WorkStatus::OnBreak => Some(current_seconds),
+        WorkStatus::Completed => Some(work_end_minutes * 60),
+        _ => None,
+    };
+    let segments = worked_until.map_or_else(Vec::new, |until| {
+        worked_segments(work_start_minutes * 60, until, &breaks)
+    });
+    let worked_seconds = segments.iter().map(|(s, e)| e - s).sum();
+    let premium_earnings = worked_until.map_or(0.0, |_| {
+        let is_holiday = today_status_override == Some(TodayWorkStatus::HolidayWork)
+            || !work_days.contains(&day_of_week);
+        let premium = premium_earnings(
+            &settings.premium_rules,
+            &segments,
+            rates.ordinary_hourly(effective_day),
+            overtime_threshold_seconds(settings, effective_day, ctx.workday),
+            is_holiday,
+        );
+        // 퇴근 후에는 지난 날짜 계산과 같은 확정 금액
+        if work_status == WorkStatus::Completed {
+            money::from_f64(premium, rates.rounding) as f64
+        } else {
+            premium
+        }
+    });
+    // 적립 상여금은 근무 진행만큼, 근무하지 않는 날은 하루치 전부
+    let bonus_progress = match work_status {
+        WorkStatus::BeforeWork => 0.0,
+        WorkStatus::Working | WorkStatus::OnBreak if daily_rate > 0 => {
+            base_earnings / daily_rate as f64
+        }
+        WorkStatus::Working | WorkStatus::OnBreak => 0.0,
+        _ => 1.0,
+    };
+    let bonus_earnings = rates.bonus_lump_on(effective_day) as f64
+        + rates.bonus_share_on(effective_day) as f64 * bonus_progress;
+    let today_earnings = base_earnings + premium_earnings + bonus_earnings;
+
+    // 지난 날짜분: 날짜별 workday 캐시 기준, 없으면 요일 기본 일정.
+    // 야간 근무 자정 이후 구간이면 어제 근무는 오늘분으로 계산 중이므로 제외한다.
+    let past_days: Vec<DayEarnings> = period_start
+        .iter_days()
+        .take_while(|&day| day < effective_day)
+        .map(|day| day_earnings(settings, &rates, day, ctx.workday))
+        .collect();
+    let past_earnings: Minor = past_days.iter().map(|d| d.earnings).sum();
+    let worked_days = past_days.iter().filter(|d| d.worked()).count() as u32;
+    let accumulated_earnings = past_earnings + money::from_f64(today_earnings, rates.rounding);
+    let today_deduction = leave_paid.map_or(0, |(paid, _)| daily_rate - paid);
+    let leave_deduction = past_days.iter().map(|d| d.deduction).sum::<Minor>() + today_deduction;
+
+    Some(SalaryTickPayload {
+        daily_rate,
+        hourly_rate,
+        per_second,
+        accumulated_earnings,
+        today_earnings,
+        base_earnings,
+        premium_earnings,
+        bonus_earnings,
+        leave_deduction,
+        work_status,
+        is_work_day,
+        worked_days,
+        worked_seconds,
+        is_payday: today == period_start,
+        seconds_until_clock_out,
+        seconds_until_clock_in,
+        days_until_payday: (period_end - today).num_days() as u32,
+        jobs: vec![],
+        goals: vec![],
+        company_holiday: None,
+    })
+}
+
+/// 하루 전체(퇴근 완료 기준) 결과
+struct DayEarnings {
+    earnings: Minor,
+    /// 유급 근무 시간 (연차·휴무는 0)
+    hours: f64,
+    /// 무급휴가·병가로 빠진 금액
+    deduction: Minor,
+}
+
+impl DayEarnings {
+    const NONE: DayEarnings = DayEarnings {
+        earnings: 0,
+        hours: 0.0,
+        deduction: 0,
+    };
+
+    /// 실제 근무한 날인지 (연차·휴무 제외)
+    fn worked(&self) -> bool {
+        self.hours > 0.0
+    }
+}
+
+/// 하루 전체 급여. 오늘 계산과 같은 규칙을 퇴근 완료 시점 기준으로 적용한다.
+///
+/// 지난 날짜 누적과 앞으로의 예상(계획된 연차 등) 모두에 쓴다.
+/// 캐시가 없으면 요일 기본 일정. 근무일이 아니어도 출퇴근 시각이 기록돼 있으면 추가 근무로 본다.
+/// 주 40시간 연장 판단에 같은 주 앞선 날 기록도 `workday`로 조회한다.
+fn day_earnings(
+    settings: &UserSettings,
+    rates: &PayRates,
+    day: NaiveDate,
+    workday: &dyn Fn(NaiveDate) -> Option<crate::types::WorkdayCache>,
+) -> DayEarnings {
+    let overrides = workday(day).map(overrides_from_cache);
+    let status = overrides.as_ref().and_then(|o| o.status);
+    let schedule = overrides.and_then(|o| o.schedule);
+    let weekday = weekday_index(day);
+    let (start_time, end_time) = schedule
+        .as_ref()
+        .map(|(s, e)| (s.as_str(), e.as_str()))
+        .unwrap_or_else(|| settings.hours_for_weekday(weekday));
+
+    let (start, end, breaks) = shift_minutes(settings, day, start_time, end_time);
+    let break_minutes: u32 = breaks.iter().map(|(s, e)| e - s).sum();
+    let work_minutes = end - start - break_minutes;
+    let daily_rate = rates.daily_rate(settings, day, work_minutes);
+    let is_work_day = settings.work_days.contains(&weekday) || schedule.is_some();
+
+    let full_day_leave = status.and_then(|s| s.full_day_leave(settings));
+    let mut result = match (status, full_day_leave) {
+        // 쉬는 날은 `get_work_minutes_in_period`에서 기간 근무시간(분모)에서도 빠지므로
+        // 월급제는 나머지 날의 일급이 늘어 월급이 그대로다. 공제로 보고하지 않는다
+        (Some(TodayWorkStatus::PublicHoliday | TodayWorkStatus::DayOff), _) => DayEarnings::NONE,
+        // 근무일이 아닌 날의 휴가는 지급·공제 모두 없다
+        _ if !is_work_day => DayEarnings::NONE,
+        (_, Some((percent, _))) => {
+            let paid = money::prorate(daily_rate, percent as i64, 100, rates.rounding);
+            DayEarnings {
+                earnings: paid,
+                hours: 0.0,
+                deduction: daily_rate - paid,
+            }
+        }
+        _ => {
+            // 반차·반반차는 휴가를 뺀 구간만 가산수당·근무시간에 넣는다
+            let (work_start, work_end, leave_minutes) = match status {
+                Some(TodayWorkStatus::PartialLeave(leave)) => {
+                    let (s, e) = leave.working_window(start, end, &breaks, work_minutes);
+                    (s, e, leave.leave_minutes(work_minutes))
+                }
+                _ => (start, end, 0),
+            };
+            let is_holiday = status == Some(TodayWorkStatus::HolidayWork)
+                || !settings.work_days.contains(&weekday);
+            let premium = premium_earnings(
+                &settings.premium_rules,
+                &worked_segments(work_start * 60, work_end * 60, &breaks),
+                rates.ordinary_hourly(day),
+                overtime_threshold_seconds(settings, day, workday),
+                is_holiday,
+            );
+            DayEarnings {
+                earnings: daily_rate + money::from_f64(premium, rates.rounding),
+                hours: (work_minutes - leave_minutes) as f64 / 60.0,
+                deduction: 0,
+            }
+        }
+    };
+    // 상여금은 근무 여부와 관계없이 반영 (적립분은 휴무일이어도 기간 합계에 포함)
+    result.earnings += rates.bonus_lump_on(day) + rates.bonus_share_on(day);
+    result
+}
+
+/// 한 프로필의 오늘 workday override(`ctx.workday`의 오늘 캐시)를 반영한 급여 계산.
+fn calculate_job_salary(
+    settings: &UserSettings,
+    now: DateTime<FixedOffset>,
+    ctx: &SalaryContext,
+) -> Option<SalaryTickPayload> {
+    let overrides = (ctx.workday)(now.date_naive()).map(overrides_from_cache);
+    calculate_with_overrides(settings, overrides, now, ctx)
+}
+
+/// 오늘 override(status·일정·완료)를 반영한 급여 계산.
+fn calculate_with_overrides(
+    settings: &UserSettings,
+    overrides: Option<TickerOverrides>,
+    now: DateTime<FixedOffset>,
+    ctx: &SalaryContext,
+) -> Option<SalaryTickPayload> {
+    let today_status_override = overrides.as_ref().and_then(|o| o.status);
+    let today_override = overrides.as_ref().and_then(|o| o.schedule.clone());
+    let completed_override = overrides.as_ref().is_some_and(|o| o.completed);
+
+    let mut payload = calculate_salary(
+        settings,
+        today_status_override,
+        today_override
+            .as_ref()
+            .map(|(s, e)| (s.as_str(), e.as_str())),
+        now,
+        ctx,
+    )?;
+
+    // 서버가 명시적으로 status=COMPLETED → ticker도 강제 정지
+    if completed_override && !is_non_working_status(&payload.work_status) {
+        payload.work_status = WorkStatus::Completed;
+    }
+    Some(payload)
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn is_non_working_status(work_status: &WorkStatus) -> bool {
+    matches!(
+        work_status,
+        WorkStatus::AnnualLeave
+            | WorkStatus::UnpaidLeave
+            | WorkStatus::SickLeave
+            | WorkStatus::DayOff
+            | WorkStatus::PublicHoliday
+    )
+}
+
+fn partial_leave(quarters: u32, morning: bool) -> TodayWorkStatus {
+    TodayWorkStatus::PartialLeave(PartialLeave { quarters, morning })
+}
+
+/// `kind=Work` → status=None (정상 근무 경로). 그 외 → 해당 status.
+/// `clock_in_time`/`clock_out_time`이 둘 다 있으면 schedule override.
+fn overrides_from_cache(cache: crate::types::WorkdayCache) -> TickerOverrides {
+    let status = match cache.kind {
+        crate::types::WorkdayKind::Work
+            if cache
+                .events
+                .contains(&crate::types::WorkdayCacheEvent::PublicHoliday) =>
+        {
+            Some(TodayWorkStatus::HolidayWork)
+        }
+        crate::types::WorkdayKind::Work => None,
+        crate::types::WorkdayKind::AnnualLeave => Some(TodayWorkStatus::AnnualLeave),
+        crate::types::WorkdayKind::UnpaidLeave => Some(TodayWorkStatus::UnpaidLeave),
+        crate::types::WorkdayKind::SickLeave => Some(TodayWorkStatus::SickLeave),
+        crate::types::WorkdayKind::DayOff => Some(TodayWorkStatus::DayOff),
+        crate::types::WorkdayKind::PublicHoliday => Some(TodayWorkStatus::PublicHoliday),
+        crate::types::WorkdayKind::MorningHalfLeave => Some(partial_leave(2, true)),
+        crate::types::WorkdayKind::AfternoonHalfLeave => Some(partial_leave(2, false)),
+        crate::types::WorkdayKind::MorningQuarterLeave => Some(partial_leave(1, true)),
+        crate::types::WorkdayKind::AfternoonQuarterLeave => Some(partial_leave(1, false)),
+    };
+
+    // 반차·반반차에 출퇴근 override가 있으면 요일 기본 일정 대신 그 일정을 나눈다
+    let schedule = match (cache.clock_in_time, cache.clock_out_time) {
+        (Some(start), Some(end)) => Some((start, end)),
+        _ => None,
+    };
+
+    TickerOverrides {
+        status,
+        schedule,
+        completed: cache.completed,
+    }
+}
+
+/// `day`에 시작하는 `start_time`–`end_time` 근무의 (출근, 퇴근, 휴게 구간) 분.
+///
+/// 근무일 자정부터의 실제 경과 시간이라 DST 전환일에는 전환 이후 시각이 벽시계보다
+/// 한 시간 이르거나(서머타임 시작) 늦다(종료).
+fn shift_minutes(
+    settings: &UserSettings,
+    day: NaiveDate,
+    start_time: &str,
+    end_time: &str,
+) -> (u32, u32, Vec<(u32, u32)>) {
+    let start = time_to_minutes(start_time);
+    let raw_end = time_to_minutes(end_time);
+    // Overnight shift: treat end as next day when end <= start (e.g. 22:00–06:00)
+    // When end == start, it means 24-hour work
+    let end = if raw_end <= start {
+        raw_end + 24 * 60
+    } else {
+        raw_end
+    };
+    let real = |minutes| real_minutes(settings, day, minutes);
+    let breaks = normalize_breaks(&settings.break_times, start, end)
+        .into_iter()
+        .map(|(s, e)| (real(s), real(e)))
+        .collect();
+    (real(start), real(end), breaks)
+}
+
+/// 근무 시간대에서 `day` 자정부터 벽시계 `wall_minutes`(24시 이후 포함)까지 실제 경과 분.
+fn real_minutes(settings: &UserSettings, day: NaiveDate, wall_minutes: u32) -> u32 {
+    let midnight = day.and_time(chrono::NaiveTime::MIN);
+    let wall = midnight + chrono::Duration::minutes(wall_minutes as i64);
+    let shift_seconds = utc_offset_seconds(settings, wall) - utc_offset_seconds(settings, midnight);
+    (wall_minutes as i32 - shift_seconds / 60).max(0) as u32
+}
+
+/// 근무 시간대 벽시계 `local`의 UTC 오프셋 (초). 시간대가 없으면 시스템 시간대.
+///
+/// 두 번 있는 시각(서머타임 종료)은 앞의 오프셋, 없는 시각(서머타임 시작)은 전환 직전 오프셋.
+fn utc_offset_seconds(settings: &UserSettings, local: chrono::NaiveDateTime) -> i32 {
+    fn resolve<Tz: TimeZone>(tz: &Tz, local: chrono::NaiveDateTime) -> i32 {
+        tz.offset_from_local_datetime(&local)
+            .earliest()
+            .or_else(|| {
+                tz.offset_from_local_datetime(&(local - chrono::Duration::hours(1)))
+                    .earliest()
+            })
+            .map_or(0, |offset| offset.fix().local_minus_utc())
+    }
+    match settings.work_tz() {
+        Some(tz) => resolve(&tz, local),
+        None => resolve(&Local, local),
+    }
+}
+
+/// 근무 시간대에서 `day` 자정부터 `now`까지 실제 경과 초.
+///
+/// 벽시계가 아닌 UTC 시각끼리 빼므로 서머타임 종료로 반복되는 한 시간도 두 번 센다.
+fn seconds_since_midnight(
+    settings: &UserSettings,
+    day: NaiveDate,
+    now: DateTime<FixedOffset>,
+) -> u32 {
+    let midnight = work_time(settings, day.and_time(chrono::NaiveTime::MIN));
+    (now - midnight).num_seconds().max(0) as u32
+}
+
+/// 근무 시간대 벽시계 `local`을 UTC 오프셋이 붙은 시각으로 (`utc_offset_seconds`와 같은 규칙)
+pub(crate) fn work_time(
+    settings: &UserSettings,
+    local: chrono::NaiveDateTime,
+) -> DateTime<FixedOffset> {
+    let offset = FixedOffset::east_opt(utc_offset_seconds(settings, local))
+        .unwrap_or_else(|| chrono::Utc.fix());
+    DateTime::from_naive_utc_and_offset(local - offset, offset)
+}
+
+/// 근무 시간대의 현재 시각. 시간대가 없으면 시스템 시간대
+pub fn work_now(settings: &UserSettings) -> DateTime<FixedOffset> {
+    let now = chrono::Utc::now();
+    match settings.work_tz() {
+        Some(tz) => now.with_timezone(&tz).fixed_offset(),
+        None => now.with_timezone(&Local).fixed_offset(),
+    }
+}
+
+/// 휴게시간을 근무 구간 `[work_start, work_end)` 기준 분 단위로 정규화.
+///
+/// 근무 시작 전에 끝나는 휴게는 자정 이후(+24h)로 보고, 근무 구간 밖은 잘라낸다.
+/// 출근 전에 시작해 근무 중에 끝나는 휴게는 출근 시각부터 센다.
+/// 겹치는 구간은 병합해 중복 차감을 막는다.
+fn normalize_breaks(breaks: &[BreakWindow], work_start: u32, work_end: u32) -> Vec<(u32, u32)> {
+    let mut ranges: Vec<(u32, u32)> = breaks
+        .iter()
+        .filter_map(|b| {
+            let mut start = time_to_minutes(&b.start_time);
+            let mut end = time_to_minutes(&b.end_time);
+            if end <= start {
+                end += 24 * 60;
+            }
+            if end <= work_start {
+                start += 24 * 60;
+                end += 24 * 60;
+            }
+            let (start, end) = (start.max(work_start), end.min(work_end));
+            (start < end).then_some((start, end))
+        })
+        .collect();
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Length of the intersection of `[a_start, a_end)` and `[b_start, b_end)`.
+fn overlap(a_start: u32, a_end: u32, b_start: u32, b_end: u32) -> u32 {
+    a_end.min(b_end).saturating_sub(a_start.max(b_start))
+}
+
+/// JS Date.getDay() 기준 요일 (0=Sun, ..., 6=Sat).
+fn weekday_index(date: NaiveDate) -> u8 {
+    date.weekday().num_days_from_sunday() as u8
+}
+
+/// Parse "HH:MM" to minutes since midnight.
+fn time_to_minutes(time: &str) -> u32 {
+    let parts: Vec<&str> = time.split(':').collect();
+    if parts.len() != 2 {
+        return 0;
+    }
+    let hours: u32 = parts[0].parse().unwrap_or(0);
+    let minutes: u32 = parts[1].parse().unwrap_or(0);
+    hours * 60 + minutes
+}
+
+// ============================================================================
+// File I/O helpers
+// ============================================================================
+
+/// 날짜별 `WorkdayCache` 파일 메모 (ticker 스레드 전용).
+///
+/// 누적액 계산이 기간 내 모든 날짜를 조회하므로 파일 읽기를 한 번으로 줄인다.
+#[derive(Default)]
+struct WorkdayHistory {
+    entries: RefCell<HashMap<PathBuf, Option<crate::types::WorkdayCache>>>,
+}
+
+impl WorkdayHistory {
+    /// `{recovery_dir}/workday/{date}.json`. 파일이 없거나 날짜가 다르면 None.
+    fn get(&self, recovery_dir: &Path, date: NaiveDate) -> Option<crate::types::WorkdayCache> {
+        let date_str = date.format("%Y-%m-%d").to_string();
+        let path = recovery_dir
+            .join("workday")
+            .join(format!("{date_str}.json"));
+        self.entries
+            .borrow_mut()
+            .entry(path)
+            .or_insert_with_key(|path| {
+                std::fs::read_to_string(path)
+                    .ok()
+                    .and_then(|contents| {
+                        serde_json::from_str::<crate::types::WorkdayCache>(&contents).ok()
+                    })
+                    .filter(|cache| cache.date == date_str)
+            })
+            .clone()
+    }
+
+    fn clear(&self) {
+        self.entries.borrow_mut().clear();
+    }
+}
+
+fn get_recovery_dir(app: &AppHandle) -> PathBuf {
+    app.path()
+        .app_data_dir()
+        .map(|d| d.join("recovery"))
+        .unwrap_or_default()
+}
+
+/// 공휴일로 기록된 캐시인지 (kind 또는 `PUBLIC_HOLIDAY` 이벤트).
+pub(crate) fn is_public_holiday_cache(cache: &crate::types::WorkdayCache) -> bool {
+    cache.kind == crate::types::WorkdayKind::PublicHoliday
+        || cache
+            .events
+            .contains(&crate::types::WorkdayCacheEvent::PublicHoliday)
+}
+
+/// 내장 달력을 덧씌운 workday 캐시. 서버에서 받은 캐시(`is_dirty=false`)가 우선한다.
+///
+/// 캐시가 없는 공휴일은 공휴일 캐시로, 아직 동기화되지 않은 근무 기록은 휴일 근무로 본다.
+/// 회사 휴무일은 서버가 모르므로 서버 캐시의 기본 근무일도 공휴일로 바꾼다.
+pub(crate) fn with_builtin_holiday(
+    holidays: &HolidayCalendar,
+    date: NaiveDate,
+    cache: Option<crate::types::WorkdayCache>,
+) -> Option<crate::types::WorkdayCache> {
+    let is_company_holiday = holidays.company_holiday(date).is_some();
+    match cache {
+        Some(cache) if is_public_holiday_cache(&cache) => Some(cache),
+        Some(mut cache)
+            if is_company_holiday
+                && !cache.is_dirty
+                && cache.kind == crate::types::WorkdayKind::Work
+                && cache.clock_in_time.is_none() =>
+        {
+            cache.kind = crate::types::WorkdayKind::PublicHoliday;
+            cache
+                .events
+                .push(crate::types::WorkdayCacheEvent::PublicHoliday);
+            Some(cache)
+        }
+        Some(cache) if !cache.is_dirty && !is_company_holiday => Some(cache),
+        // 출근 기록만 휴일 근무로. 연차 등 명시한 종류는 그대로 둔다
+        Some(mut cache)
+            if cache.kind == crate::types::WorkdayKind::Work
+                && cache.clock_in_time.is_some()
+                && holidays.is_holiday(date) =>
+        {
+            cache
+                .events
+                .push(crate::types::WorkdayCacheEvent::PublicHoliday);
+            Some(cache)
+        }
+        Some(cache) => Some(cache),
+        None => holidays
+            .is_holiday(date)
+            .then(|| crate::types::WorkdayCache {
+                date: date.format("%Y-%m-%d").to_string(),
+                kind: crate::types::WorkdayKind::PublicHoliday,
+                clock_in_time: None,
+                clock_out_time: None,
+                completed: false,
+                events: vec![crate::types::WorkdayCacheEvent::PublicHoliday],
+                is_dirty: false,
+            }),
+    }
+}
+
+fn load_settings(app: &AppHandle) -> Option<UserSettings> {
+    let path = app.path().app_data_dir().ok()?.join("user-settings.json");
+
+    let contents = std::fs::read_to_string(&path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::forecast::{SalaryForecast, SettledMemo};
+    use super::rates::{get_work_minutes_in_period, weekly_allowance_hours};
+    use super::*;
+    use crate::deduction;
+    use crate::types::{
+        Bonus, BonusAccrual, BonusKind, MenubarDisplayMode, PayDisplay, PaydayAdjustment,
+        SalaryType, SalaryVersion,
+    };
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    // 테스트는 근무 시간대 벽시계 시각으로 호출한다
+    pub(super) fn calculate_salary(
+        settings: &UserSettings,
+        today_status_override: Option<TodayWorkStatus>,
+        today_override: Option<(&str, &str)>,
+        now: chrono::NaiveDateTime,
+        ctx: &SalaryContext,
+    ) -> Option<SalaryTickPayload> {
+        let now = work_time(settings, now);
+        super::calculate_salary(settings, today_status_override, today_override, now, ctx)
+    }
+
+    pub(super) fn calculate_job_salary(
+        settings: &UserSettings,
+        now: chrono::NaiveDateTime,
+        ctx: &SalaryContext,
+    ) -> Option<SalaryTickPayload> {
+        super::calculate_job_salary(settings, work_time(settings, now), ctx)
+    }
+
+    pub(super) fn forecast_salary(
+        settings: &UserSettings,
+        now: chrono::NaiveDateTime,
+        ctx: &SalaryContext,
+        memo: &mut SettledMemo,
+    ) -> Option<SalaryForecast> {
+        super::forecast::forecast_salary(settings, work_time(settings, now), ctx, memo)
+    }
+
+    pub(super) fn make_settings(salary_amount: u32, pay_day: u8) -> UserSettings {
+        UserSettings {
+            salary_type: SalaryType::Monthly,
+            salary_amount,
+            pay_day,
+            work_days: vec![1, 2, 3, 4, 5], // Mon-Fri
+            work_start_time: "09:00".to_string(),
+            work_end_time: "18:00".to_string(),
+            onboarding_completed: true,
+            menubar_display_mode: MenubarDisplayMode::Daily,
+            // 서머타임 없는 시간대로 고정해 테스트 머신 시간대와 무관하게
+            work_timezone: Some("Asia/Seoul".to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn no_holidays(_: NaiveDate) -> bool {
+        false
+    }
+
+    pub(super) fn no_history(_: NaiveDate) -> Option<crate::types::WorkdayCache> {
+        None
+    }
+
+    /// 공휴일·workday 캐시 없음
+    pub(super) const PLAIN: SalaryContext<'static> = SalaryContext {
+        is_public_holiday: &no_holidays,
+        workday: &no_history,
+    };
+
+    /// 공휴일 없이 `workday` 캐시만 조회
+    pub(super) fn with_workday<'a>(
+        workday: &'a dyn Fn(NaiveDate) -> Option<crate::types::WorkdayCache>,
+    ) -> SalaryContext<'a> {
+        SalaryContext {
+            is_public_holiday: &no_holidays,
+            workday,
+        }
+    }
+
+    pub(super) fn make_temp_dir(name: &str) -> std::path::PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("moa-{name}-{nanos}"));
+        std::fs::create_dir_all(&path).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_time_to_minutes() {
+        assert_eq!(time_to_minutes("09:00"), 540);
+        assert_eq!(time_to_minutes("18:00"), 1080);
+        assert_eq!(time_to_minutes("00:00"), 0);
+    }
+
+    #[test]
+    fn test_calculate_salary_day_off() {
+        let settings = make_settings(3_000_000, 25);
+        // 2025-02-09 is Sunday (day off)
+        let now = NaiveDate::from_ymd_opt(2025, 2, 9)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::DayOff);
+        assert_eq!(result.today_earnings, 0.0);
+    }
+
+    #[test]
+    fn test_calculate_salary_working() {
+        let settings = make_settings(3_000_000, 25);
+        // 2025-02-10 is Monday (work day), at 12:00
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::Working);
+        assert!(result.today_earnings > 0.0);
+    }
+
+    #[test]
+    fn test_calculate_salary_annual_leave() {
+        let settings = make_settings(3_000_000, 25);
+        // Monday but on annual leave
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let result = calculate_salary(
+            &settings,
+            Some(TodayWorkStatus::AnnualLeave),
+            None,
+            now,
+            &PLAIN,
+        )
+        .unwrap();
+        assert_eq!(result.work_status, WorkStatus::AnnualLeave);
+        assert_eq!(result.today_earnings, result.daily_rate as f64);
+    }
+
+    #[test]
+    fn test_calculate_salary_manual_day_off() {
+        let settings = make_settings(3_000_000, 25);
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let result =
+            calculate_salary(&settings, Some(TodayWorkStatus::DayOff), None, now, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::DayOff);
+        assert_eq!(result.today_earnings, 0.0);
+    }
+
+    #[test]
+    fn test_calculate_salary_public_holiday() {
+        let settings = make_settings(3_000_000, 25);
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let result = calculate_salary(
+            &settings,
+            Some(TodayWorkStatus::PublicHoliday),
+            None,
+            now,
+            &PLAIN,
+        )
+        .unwrap();
+        assert_eq!(result.work_status, WorkStatus::PublicHoliday);
+        assert_eq!(result.today_earnings, 0.0);
+    }
+
+    #[test]
+    fn test_workday_overrides_returns_status_for_day_off() {
+        use crate::types::{WorkdayCache, WorkdayKind};
+        let dir = make_temp_dir("workday-overrides");
+        let workday_dir = dir.join("workday");
+        std::fs::create_dir_all(&workday_dir).unwrap();
+        let cache = WorkdayCache {
+            date: "2025-02-10".into(),
+            kind: WorkdayKind::DayOff,
+            clock_in_time: None,
+            clock_out_time: None,
+            completed: false,
+            events: vec![],
+            is_dirty: false,
+        };
+        std::fs::write(
+            workday_dir.join("2025-02-10.json"),
+            serde_json::to_string(&cache).unwrap(),
+        )
+        .unwrap();
+
+        let overrides = WorkdayHistory::default()
+            .get(&dir, NaiveDate::from_ymd_opt(2025, 2, 10).unwrap())
+            .map(overrides_from_cache)
+            .unwrap();
+        assert_eq!(overrides.status, Some(TodayWorkStatus::DayOff));
+        assert!(overrides.schedule.is_none());
+        assert!(!overrides.completed);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_workday_overrides_extracts_schedule_when_both_times_present() {
+        use crate::types::{WorkdayCache, WorkdayKind};
+        let dir = make_temp_dir("workday-schedule");
+        let workday_dir = dir.join("workday");
+        std::fs::create_dir_all(&workday_dir).unwrap();
+        let cache = WorkdayCache {
+            date: "2025-02-10".into(),
+            kind: WorkdayKind::Work,
+            clock_in_time: Some("10:00".into()),
+            clock_out_time: Some("19:00".into()),
+            completed: true,
+            events: vec![],
+            is_dirty: false,
+        };
+        std::fs::write(
+            workday_dir.join("2025-02-10.json"),
+            serde_json::to_string(&cache).unwrap(),
+        )
+        .unwrap();
+
+        let overrides = WorkdayHistory::default()
+            .get(&dir, NaiveDate::from_ymd_opt(2025, 2, 10).unwrap())
+            .map(overrides_from_cache)
+            .unwrap();
+        assert!(overrides.status.is_none()); // Work → no status override
+        assert_eq!(
+            overrides.schedule,
+            Some(("10:00".to_string(), "19:00".to_string()))
+        );
+        assert!(overrides.completed);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_workday_overrides_ignores_mismatched_date() {
+        use crate::types::{WorkdayCache, WorkdayKind};
+        let dir = make_temp_dir("workday-mismatch");
+        let workday_dir = dir.join("workday");
+        std::fs::create_dir_all(&workday_dir).unwrap();
+        let cache = WorkdayCache {
+            date: "2025-02-09".into(), // 다른 날짜
+            kind: WorkdayKind::AnnualLeave,
+            clock_in_time: None,
+            clock_out_time: None,
+            completed: false,
+            events: vec![],
+            is_dirty: false,
+        };
+        std::fs::write(
+            workday_dir.join("2025-02-09.json"),
+            serde_json::to_string(&cache).unwrap(),
+        )
+        .unwrap();
+
+        // today=2025-02-10이지만 파일은 2025-02-09
+        let overrides = WorkdayHistory::default()
+            .get(&dir, NaiveDate::from_ymd_opt(2025, 2, 10).unwrap())
+            .map(overrides_from_cache);
+        assert!(overrides.is_none());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+    // -- Overnight shift tests --
+
+    pub(super) fn make_overnight_settings() -> UserSettings {
+        UserSettings {
+            salary_type: SalaryType::Monthly,
+            salary_amount: 3_000_000,
+            pay_day: 25,
+            work_days: vec![1, 2, 3, 4, 5],
+            work_start_time: "18:00".to_string(),
+            work_end_time: "00:00".to_string(),
+            onboarding_completed: true,
+            menubar_display_mode: MenubarDisplayMode::Daily,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_overnight_working() {
+        let settings = make_overnight_settings();
+        // Monday 20:00 → Working
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(20, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::Working);
+        assert!(result.today_earnings > 0.0);
+    }
+
+    #[test]
+    fn test_overnight_completed_before_start() {
+        let settings = make_overnight_settings();
+        // Monday 15:00 → Completed (shift ended at 00:00, before next shift at 18:00)
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(15, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::Completed);
+    }
+
+    #[test]
+    fn test_overnight_completed_after_midnight() {
+        let settings = make_overnight_settings();
+        // Tuesday 01:00 → Completed (shift ended at 00:00)
+        let now = NaiveDate::from_ymd_opt(2025, 2, 11)
+            .unwrap()
+            .and_hms_opt(1, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::Completed);
+    }
+
+    #[test]
+    fn test_overnight_late_shift_working() {
+        // 22:00–06:00 shift, at 02:00 → Working
+        let settings = UserSettings {
+            work_start_time: "22:00".to_string(),
+            work_end_time: "06:00".to_string(),
+            ..make_overnight_settings()
+        };
+        let now = NaiveDate::from_ymd_opt(2025, 2, 11)
+            .unwrap()
+            .and_hms_opt(2, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::Working);
+        assert!(result.today_earnings > 0.0);
+    }
+
+    #[test]
+    fn test_overnight_cross_day_boundary() {
+        // Friday 22:00–Saturday 06:00, at Saturday 02:00 → Working (not DayOff)
+        // work_days = Mon-Fri, Saturday is NOT a work day, but the shift started on Friday
+        let settings = UserSettings {
+            work_start_time: "22:00".to_string(),
+            work_end_time: "06:00".to_string(),
+            ..make_overnight_settings()
+        };
+        // 2025-02-15 is Saturday, prev day (Friday) is a work day
+        let now = NaiveDate::from_ymd_opt(2025, 2, 15)
+            .unwrap()
+            .and_hms_opt(2, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::Working);
+        assert!(result.today_earnings > 0.0);
+    }
+
+    // -- Break window tests --
+
+    fn lunch_break() -> Vec<BreakWindow> {
+        vec![BreakWindow {
+            start_time: "12:00".to_string(),
+            end_time: "13:00".to_string(),
+        }]
+    }
+
+    #[test]
+    fn test_break_excluded_from_hourly_rate() {
+        let settings = UserSettings {
+            break_times: lunch_break(),
+            ..make_settings(3_000_000, 25)
+        };
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        // 09:00–18:00 with 1h lunch → 8 paid hours
+        assert!((result.hourly_rate - result.daily_rate as f64 / 8.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_on_break_freezes_earnings() {
+        let settings = UserSettings {
+            break_times: lunch_break(),
+            ..make_settings(3_000_000, 25)
+        };
+        let at = |h, m| {
+            let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+                .unwrap()
+                .and_hms_opt(h, m, 0)
+                .unwrap();
+            calculate_salary(&settings, None, None, now, &PLAIN).unwrap()
+        };
+        let noon = at(12, 0);
+        let half_past = at(12, 30);
+        assert_eq!(half_past.work_status, WorkStatus::OnBreak);
+        assert_eq!(noon.today_earnings, half_past.today_earnings);
+        assert_eq!(at(13, 0).today_earnings, half_past.today_earnings);
+        assert_eq!(at(13, 0).work_status, WorkStatus::Working);
+        // 3h paid by 13:00 (09–12)
+        assert!((half_past.today_earnings - half_past.hourly_rate * 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_break_completed_pays_full_daily_rate() {
+        let settings = UserSettings {
+            break_times: lunch_break(),
+            ..make_settings(3_000_000, 25)
+        };
+        let before_end = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(17, 59, 59)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, before_end, &PLAIN).unwrap();
+        // 1초 남음 → 일급에서 초당 금액만큼 모자람
+        assert!(
+            (result.daily_rate as f64 - result.today_earnings - result.per_second).abs() < 1e-6
+        );
+    }
+
+    #[test]
+    fn test_overnight_break_after_midnight() {
+        // 22:00–06:00 with 02:00–03:00 break → 7 paid hours
+        let settings = UserSettings {
+            work_start_time: "22:00".to_string(),
+            work_end_time: "06:00".to_string(),
+            break_times: vec![BreakWindow {
+                start_time: "02:00".to_string(),
+                end_time: "03:00".to_string(),
+            }],
+            ..make_overnight_settings()
+        };
+        let now = NaiveDate::from_ymd_opt(2025, 2, 11)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::OnBreak);
+        assert!((result.hourly_rate - result.daily_rate as f64 / 7.0).abs() < 1e-9);
+        assert!((result.today_earnings - result.hourly_rate * 4.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_normalize_breaks_clips_and_merges() {
+        let breaks = vec![
+            BreakWindow {
+                start_time: "12:00".to_string(),
+                end_time: "13:00".to_string(),
+            },
+            BreakWindow {
+                start_time: "12:30".to_string(),
+                end_time: "13:30".to_string(),
+            },
+            BreakWindow {
+                start_time: "17:30".to_string(),
+                end_time: "19:00".to_string(),
+            },
+            BreakWindow {
+                start_time: "07:00".to_string(),
+                end_time: "08:00".to_string(),
+            },
+        ];
+        assert_eq!(
+            normalize_breaks(&breaks, 540, 1080),
+            vec![(720, 810), (1050, 1080)]
+        );
+    }
+
+    #[test]
+    fn test_normalize_breaks_clips_break_starting_before_shift() {
+        let breaks = vec![BreakWindow {
+            start_time: "08:30".to_string(),
+            end_time: "09:30".to_string(),
+        }];
+        assert_eq!(normalize_breaks(&breaks, 540, 1080), vec![(540, 570)]);
+
+        // 야간 근무 22:00–06:00: 21:30–22:30 휴게는 22:00부터, 01:00–02:00은 자정 이후
+        let breaks = vec![
+            BreakWindow {
+                start_time: "21:30".to_string(),
+                end_time: "22:30".to_string(),
+            },
+            BreakWindow {
+                start_time: "01:00".to_string(),
+                end_time: "02:00".to_string(),
+            },
+        ];
+        assert_eq!(
+            normalize_breaks(&breaks, 1320, 1800),
+            vec![(1320, 1350), (1500, 1560)]
+        );
+    }
+
+    // -- Hourly wage tests --
+
+    pub(super) fn make_hourly_settings(hourly_wage: u32) -> UserSettings {
+        UserSettings {
+            salary_type: SalaryType::Hourly,
+            pay_display: Some(PayDisplay::Gross),
+            break_times: lunch_break(),
+            ..make_settings(hourly_wage, 25)
+        }
+    }
+
+    pub(super) fn all_premiums() -> crate::types::PremiumRules {
+        let mut rules = crate::types::PremiumRules::default();
+        rules.overtime.enabled = true;
+        rules.night.enabled = true;
+        rules.holiday.enabled = true;
+        rules.holiday_overtime.enabled = true;
+        rules
+    }
+
+    #[test]
+    fn test_weekly_allowance_hours() {
+        assert_eq!(weekly_allowance_hours(14.0), 0.0);
+        assert_eq!(weekly_allowance_hours(15.0), 3.0);
+        assert_eq!(weekly_allowance_hours(40.0), 8.0);
+        assert_eq!(weekly_allowance_hours(52.0), 8.0);
+    }
+
+    #[test]
+    fn test_hourly_full_time_includes_weekly_allowance() {
+        // 주 5일 × 8시간 = 40시간 → 주휴 8시간 → 시급 × 1.2
+        let settings = make_hourly_settings(10_030);
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        assert!((result.hourly_rate - 12_036.0).abs() < 1e-6);
+        assert_eq!(result.daily_rate, 12_036 * 8);
+        assert!((result.today_earnings - 12_036.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_paid_point_skips_breaks() {
+        let lunch = [(720, 780)];
+        assert_eq!(paid_point(540, &lunch, 180), 720);
+        assert_eq!(paid_point(540, &lunch, 240), 840);
+        assert_eq!(paid_point(540, &[], 240), 780);
+    }
+
+    #[test]
+    fn test_morning_half_leave_pays_leave_before_work() {
+        // 시급 12,036원 × 8시간 = 96,288원. 오전 반차 → 14–18시 근무
+        let settings = make_hourly_settings(10_030);
+        let leave = Some(partial_leave(2, true));
+        let at = |hour, minute| {
+            let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+                .unwrap()
+                .and_hms_opt(hour, minute, 0)
+                .unwrap();
+            calculate_salary(&settings, leave, None, now, &PLAIN).unwrap()
+        };
+
+        let morning = at(10, 0);
+        assert_eq!(morning.work_status, WorkStatus::BeforeWork);
+        assert_eq!(morning.daily_rate, 96_288);
+        assert_eq!(morning.today_earnings, 48_144.0);
+
+        let working = at(15, 0);
+        assert_eq!(working.work_status, WorkStatus::Working);
+        assert!((working.today_earnings - 60_180.0).abs() < 1e-6);
+        assert_eq!(working.seconds_until_clock_out, Some(3 * 3600));
+
+        let done = at(18, 30);
+        assert_eq!(done.work_status, WorkStatus::Completed);
+        assert_eq!(done.today_earnings, 96_288.0);
+    }
+
+    #[test]
+    fn test_afternoon_quarter_leave_ends_work_early() {
+        // 오후 반반차 → 09–16시 근무 (점심 제외 6시간), 2시간분 24,072원은 미리 지급
+        let settings = make_hourly_settings(10_030);
+        let leave = Some(partial_leave(1, false));
+        let at = |hour| {
+            let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap();
+            calculate_salary(&settings, leave, None, now, &PLAIN).unwrap()
+        };
+
+        let working = at(15);
+        assert_eq!(working.work_status, WorkStatus::Working);
+        assert!((working.today_earnings - (24_072.0 + 12_036.0 * 5.0)).abs() < 1e-6);
+        assert_eq!(working.seconds_until_clock_out, Some(3600));
+
+        let done = at(16);
+        assert_eq!(done.work_status, WorkStatus::Completed);
+        assert_eq!(done.today_earnings, 96_288.0);
+    }
+
+    #[test]
+    fn test_partial_leave_splits_clock_override_and_counts_past_hours() {
+        // 10–19시(점심 제외 8시간) override 오전 반차 → 15–19시 근무
+        let cache = history_cache(
+            "2025-02-07",
+            crate::types::WorkdayKind::MorningHalfLeave,
+            Some(("10:00", "19:00")),
+        );
+        let overrides = overrides_from_cache(cache.clone());
+        assert_eq!(overrides.status, Some(partial_leave(2, true)));
+        assert_eq!(
+            overrides.schedule,
+            Some(("10:00".to_string(), "19:00".to_string()))
+        );
+
+        let settings = make_hourly_settings(10_030);
+        let at = |hour, minute| {
+            let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+                .unwrap()
+                .and_hms_opt(hour, minute, 0)
+                .unwrap();
+            calculate_salary(
+                &settings,
+                overrides.status,
+                Some(("10:00", "19:00")),
+                now,
+                &PLAIN,
+            )
+            .unwrap()
+        };
+        assert_eq!(at(14, 30).work_status, WorkStatus::BeforeWork);
+        let working = at(16, 0);
+        assert_eq!(working.work_status, WorkStatus::Working);
+        assert_eq!(working.seconds_until_clock_out, Some(3 * 3600));
+
+        // 지난 날짜: 일급 전액, 근무시간은 휴가를 뺀 4시간
+        let start = NaiveDate::from_ymd_opt(2025, 1, 25).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 2, 25).unwrap();
+        let rates = pay_rates(&settings, start, end, &PLAIN).unwrap();
+        let day = NaiveDate::from_ymd_opt(2025, 2, 7).unwrap();
+        let earnings = day_earnings(&settings, &rates, day, &|date| {
+            (date == day).then(|| cache.clone())
+        });
+        assert_eq!(earnings.earnings, 96_288);
+        assert_eq!(earnings.hours, 4.0);
+    }
+
+    #[test]
+    fn test_hourly_short_week_has_no_allowance() {
+        // 주 2일 × 5시간 = 10시간 < 15시간 → 주휴수당 없음
+        let settings = UserSettings {
+            work_days: vec![6, 0],
+            work_start_time: "10:00".to_string(),
+            work_end_time: "15:00".to_string(),
+            break_times: vec![],
+            ..make_hourly_settings(10_000)
+        };
+        // 2025-02-08 is Saturday
+        let now = NaiveDate::from_ymd_opt(2025, 2, 8)
+            .unwrap()
+            .and_hms_opt(16, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::Completed);
+        assert_eq!(result.hourly_rate, 10_000.0);
+        assert_eq!(result.today_earnings, 50_000.0);
+    }
+
+    #[test]
+    fn test_dst_night_shift_pays_actual_hours() {
+        // 토요일 22:00–06:00 야간 근무 (베를린)
+        let settings = UserSettings {
+            work_days: vec![6],
+            work_start_time: "22:00".to_string(),
+            work_end_time: "06:00".to_string(),
+            break_times: vec![],
+            work_timezone: Some("Europe/Berlin".to_string()),
+            ..make_hourly_settings(10_000)
+        };
+        let at = |month, day| {
+            NaiveDate::from_ymd_opt(2026, month, day)
+                .unwrap()
+                .and_hms_opt(5, 30, 0)
+                .unwrap()
+        };
+        let calculate = |now| calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+
+        // 평상시: 8시간 근무 중 7시간 30분 경과
+        let regular = calculate(at(3, 22));
+        assert_eq!(regular.daily_rate, 80_000);
+        assert_eq!(regular.today_earnings, 75_000.0);
+        assert_eq!(regular.seconds_until_clock_out, Some(1800));
+
+        // 서머타임 시작 (3/29 02:00 → 03:00): 7시간 근무, 6시간 30분 경과
+        let spring = calculate(at(3, 29));
+        assert_eq!(spring.work_status, WorkStatus::Working);
+        assert_eq!(spring.daily_rate, 70_000);
+        assert_eq!(spring.hourly_rate, 10_000.0);
+        assert_eq!(spring.today_earnings, 65_000.0);
+        assert_eq!(spring.seconds_until_clock_out, Some(1800));
+
+        // 서머타임 종료 (10/25 03:00 → 02:00): 9시간 근무, 8시간 30분 경과
+        let autumn = calculate(at(10, 25));
+        assert_eq!(autumn.daily_rate, 90_000);
+        assert_eq!(autumn.today_earnings, 85_000.0);
+    }
+
+    #[test]
+    fn test_dst_fall_back_counts_repeated_hour() {
+        // 토요일 22:00–06:00 야간 근무 (뉴욕). 11/2 02:00 EDT → 01:00 EST로 01시대가 두 번
+        let settings = UserSettings {
+            work_days: vec![6],
+            work_start_time: "22:00".to_string(),
+            work_end_time: "06:00".to_string(),
+            break_times: vec![],
+            work_timezone: Some("America/New_York".to_string()),
+            ..make_hourly_settings(10_000)
+        };
+        let one_thirty = chrono_tz::America::New_York.with_ymd_and_hms(2025, 11, 2, 1, 30, 0);
+        let calculate = |now: chrono::DateTime<chrono_tz::Tz>| {
+            super::calculate_salary(&settings, None, None, now.fixed_offset(), &PLAIN).unwrap()
+        };
+
+        // 첫 번째 01:30 (EDT): 3시간 30분 경과
+        let first = calculate(one_thirty.earliest().unwrap());
+        assert_eq!(first.daily_rate, 90_000);
+        assert_eq!(first.today_earnings, 35_000.0);
+        assert_eq!(first.seconds_until_clock_out, Some(5 * 3600 + 1800));
+
+        // 두 번째 01:30 (EST): 한 시간 더 경과
+        let second = calculate(one_thirty.latest().unwrap());
+        assert_eq!(second.work_status, WorkStatus::Working);
+        assert_eq!(second.today_earnings, 45_000.0);
+        assert_eq!(second.seconds_until_clock_out, Some(4 * 3600 + 1800));
+    }
+
+    #[test]
+    fn test_utc_offset_follows_work_timezone() {
+        let settings = make_settings(3_000_000, 25);
+        let noon = NaiveDate::from_ymd_opt(2026, 7, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        assert_eq!(utc_offset_seconds(&settings, noon), 9 * 3600);
+
+        let berlin = UserSettings {
+            work_timezone: Some("Europe/Berlin".to_string()),
+            ..settings
+        };
+        assert_eq!(utc_offset_seconds(&berlin, noon), 2 * 3600);
+        // 없는 시각 (서머타임 시작 02:30)은 전환 직전 오프셋
+        let gap = NaiveDate::from_ymd_opt(2026, 3, 29)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+        assert_eq!(utc_offset_seconds(&berlin, gap), 3600);
+    }
+
+    #[test]
+    fn test_hourly_accumulated_uses_regular_hours_for_past_days() {
+        let settings = make_hourly_settings(10_000);
+        // Pay period starts 2025-01-25; 2025-02-10 (Mon) → worked days Jan 27–Feb 7 = 10
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        // 오늘만 09–13시 단축 근무 override (점심 제외 3시간) — 지난 근무일 계산에는 영향 없음
+        let result =
+            calculate_salary(&settings, None, Some(("09:00", "13:00")), now, &PLAIN).unwrap();
+        assert_eq!(result.worked_days, 10);
+        assert_eq!(result.daily_rate, 12_000 * 3);
+        assert_eq!(result.accumulated_earnings, 10 * 8 * 12_000);
+    }
+
+    // -- Gross / net display tests --
+
+    #[test]
+    fn test_yearly_net_display_deducts_from_gross() {
+        let monday_noon = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let gross = UserSettings {
+            salary_type: SalaryType::Yearly,
+            pay_display: Some(PayDisplay::Gross),
+            ..make_settings(48_000_000, 25)
+        };
+        let net = UserSettings {
+            pay_display: Some(PayDisplay::Net),
+            ..gross.clone()
+        };
+        let gross_result = calculate_salary(&gross, None, None, monday_noon, &PLAIN).unwrap();
+        let net_result = calculate_salary(&net, None, None, monday_noon, &PLAIN).unwrap();
+        let expected_net =
+            deduction::estimate_monthly(4_000_000.0, 0.0, 1, deduction::table_for_year(2025)).net;
+        assert!(net_result.daily_rate < gross_result.daily_rate);
+        assert!(
+            (net_result.daily_rate as f64 / gross_result.daily_rate as f64
+                - expected_net / 4_000_000.0)
+                .abs()
+                < 1e-5
+        );
+    }
+
+    #[test]
+    fn test_missing_pay_display_keeps_yearly_amount_gross() {
+        let monday_noon = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        // pay_display 필드가 없는 기존 설정 파일
+        let mut json = serde_json::to_value(UserSettings {
+            salary_type: SalaryType::Yearly,
+            ..make_settings(48_000_000, 25)
+        })
+        .unwrap();
+        json.as_object_mut().unwrap().remove("payDisplay");
+        let legacy: UserSettings = serde_json::from_value(json).unwrap();
+        assert_eq!(legacy.pay_display, None);
+        let gross = UserSettings {
+            pay_display: Some(PayDisplay::Gross),
+            ..legacy.clone()
+        };
+        let legacy_result = calculate_salary(&legacy, None, None, monday_noon, &PLAIN).unwrap();
+        let gross_result = calculate_salary(&gross, None, None, monday_noon, &PLAIN).unwrap();
+        assert_eq!(legacy_result.daily_rate, gross_result.daily_rate);
+    }
+
+    #[test]
+    fn test_monthly_gross_display_grosses_up_net_amount() {
+        let monday_noon = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let net = make_settings(3_000_000, 25);
+        let gross = UserSettings {
+            pay_display: Some(PayDisplay::Gross),
+            ..net.clone()
+        };
+        let net_result = calculate_salary(&net, None, None, monday_noon, &PLAIN).unwrap();
+        let gross_result = calculate_salary(&gross, None, None, monday_noon, &PLAIN).unwrap();
+        assert!(gross_result.daily_rate > net_result.daily_rate);
+    }
+
+    #[test]
+    fn test_hourly_net_display_applies_deduction_ratio() {
+        let monday = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let gross = make_hourly_settings(10_030);
+        let net = UserSettings {
+            pay_display: Some(PayDisplay::Net),
+            ..gross.clone()
+        };
+        let gross_result = calculate_salary(&gross, None, None, monday, &PLAIN).unwrap();
+        let net_result = calculate_salary(&net, None, None, monday, &PLAIN).unwrap();
+        assert!(net_result.hourly_rate < gross_result.hourly_rate);
+        assert!(net_result.hourly_rate > gross_result.hourly_rate * 0.8);
+    }
+
+    #[test]
+    fn test_workday_overrides_marks_holiday_work() {
+        use crate::types::{WorkdayCache, WorkdayCacheEvent, WorkdayKind};
+        let dir = make_temp_dir("workday-holiday-work");
+        let workday_dir = dir.join("workday");
+        std::fs::create_dir_all(&workday_dir).unwrap();
+        let cache = WorkdayCache {
+            date: "2025-03-03".into(),
+            kind: WorkdayKind::Work,
+            clock_in_time: None,
+            clock_out_time: None,
+            completed: false,
+            events: vec![WorkdayCacheEvent::PublicHoliday],
+            is_dirty: false,
+        };
+        std::fs::write(
+            workday_dir.join("2025-03-03.json"),
+            serde_json::to_string(&cache).unwrap(),
+        )
+        .unwrap();
+
+        let overrides = WorkdayHistory::default()
+            .get(&dir, NaiveDate::from_ymd_opt(2025, 3, 3).unwrap())
+            .map(overrides_from_cache)
+            .unwrap();
+        assert_eq!(overrides.status, Some(TodayWorkStatus::HolidayWork));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    // -- Per-weekday hours tests --
+
+    fn short_friday_settings() -> UserSettings {
+        UserSettings {
+            weekday_hours: vec![crate::types::WeekdayHours {
+                weekday: 5,
+                start_time: "09:00".to_string(),
+                end_time: "16:00".to_string(),
+            }],
+            ..make_settings(3_000_000, 25)
+        }
+    }
+
+    #[test]
+    fn test_short_friday_daily_rate_is_proportional() {
+        let settings = short_friday_settings();
+        let monday = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let friday = NaiveDate::from_ymd_opt(2025, 2, 14)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let monday_result = calculate_salary(&settings, None, None, monday, &PLAIN).unwrap();
+        let friday_result = calculate_salary(&settings, None, None, friday, &PLAIN).unwrap();
+        // 기간 배분은 원 단위라 1원 이내 오차
+        assert!(
+            (friday_result.daily_rate as f64 - monday_result.daily_rate as f64 * 7.0 / 9.0).abs()
+                <= 1.0
+        );
+        // 시급은 요일과 무관하게 동일 (일급 원 단위 확정 오차 이내)
+        assert!((friday_result.hourly_rate - monday_result.hourly_rate).abs() < 0.2);
+
+        let friday_done = NaiveDate::from_ymd_opt(2025, 2, 14)
+            .unwrap()
+            .and_hms_opt(16, 0, 0)
+            .unwrap();
+        let done = calculate_salary(&settings, None, None, friday_done, &PLAIN).unwrap();
+        assert_eq!(done.work_status, WorkStatus::Completed);
+    }
+
+    #[test]
+    fn test_monthly_salary_spread_by_hours_in_period() {
+        // 2025-01-25 ~ 2025-02-25: 월~목 17일 × 9h + 금 4일 × 7h = 181h
+        let settings = short_friday_settings();
+        let minutes: u32 = get_work_minutes_in_period(
+            NaiveDate::from_ymd_opt(2025, 1, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 25).unwrap(),
+            &settings,
+            &PLAIN,
+        )
+        .iter()
+        .map(|&(_, m)| m)
+        .sum();
+        let hours = minutes as f64 / 60.0;
+        assert_eq!(hours, 17.0 * 9.0 + 4.0 * 7.0);
+
+        // 02-17(월) 08:00 → 지난 근무일: 01-27 ~ 02-14 (월~목 12일, 금 3일)
+        let now = NaiveDate::from_ymd_opt(2025, 2, 17)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        let expected = 3_000_000.0 / hours * (12.0 * 9.0 + 3.0 * 7.0);
+        assert_eq!(result.accumulated_earnings, expected.round() as Minor);
+    }
+
+    #[test]
+    fn test_accumulated_reconciles_with_salary_on_payday_eve() {
+        // 2025-02-24(월) 퇴근 후 = 01-25 ~ 02-25 기간의 마지막 근무일
+        let now = NaiveDate::from_ymd_opt(2025, 2, 24)
+            .unwrap()
+            .and_hms_opt(19, 0, 0)
+            .unwrap();
+        for rounding_policy in [
+            RoundingPolicy::Floor,
+            RoundingPolicy::HalfUp,
+            RoundingPolicy::HalfEven,
+        ] {
+            let settings = UserSettings {
+                salary_amount: 3_000_001,
+                rounding_policy,
+                ..short_friday_settings()
+            };
+            let result = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+            assert_eq!(result.work_status, WorkStatus::Completed);
+            assert_eq!(result.accumulated_earnings, 3_000_001);
+        }
+    }
+
+    pub(super) fn make_bonus(months: Vec<u8>, kind: crate::types::BonusKind, value: f64) -> Bonus {
+        Bonus {
+            name: "상여".to_string(),
+            months,
+            kind,
+            value,
+        }
+    }
+
+    #[test]
+    fn test_bonus_added_on_payday_of_its_month() {
+        let settings = UserSettings {
+            bonuses: vec![make_bonus(vec![2], BonusKind::PercentOfBase, 100.0)],
+            ..make_settings(3_000_000, 25)
+        };
+        // 02-25(화) 출근 전: 2월 상여 100% 반영
+        let payday = NaiveDate::from_ymd_opt(2025, 2, 25)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, payday, &PLAIN).unwrap();
+        assert_eq!(result.bonus_earnings, 3_000_000.0);
+        assert_eq!(result.today_earnings, 3_000_000.0);
+        assert_eq!(result.accumulated_earnings, 3_000_000);
+
+        // 다음 날에는 지난 날짜분으로 누적에 남음
+        let next_day = NaiveDate::from_ymd_opt(2025, 2, 26)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, next_day, &PLAIN).unwrap();
+        assert_eq!(result.bonus_earnings, 0.0);
+        assert!(result.accumulated_earnings > 3_000_000);
+
+        // 3월 월급날에는 없음
+        let march = NaiveDate::from_ymd_opt(2025, 3, 25)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, march, &PLAIN).unwrap();
+        assert_eq!(result.bonus_earnings, 0.0);
+    }
+
+    #[test]
+    fn test_bonus_month_follows_nominal_payday() {
+        // 06-01(일) 월급날이 05-30(금)으로 당겨져도 6월 상여
+        let settings = UserSettings {
+            payday_adjustment: PaydayAdjustment::PreviousBusinessDay,
+            bonuses: vec![make_bonus(vec![6], BonusKind::Fixed, 500_000.0)],
+            ..make_settings(3_000_000, 1)
+        };
+        let now = NaiveDate::from_ymd_opt(2025, 5, 30)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        assert_eq!(result.bonus_earnings, 500_000.0);
+    }
+
+    #[test]
+    fn test_accrued_bonus_reconciles_on_payday_eve() {
+        let settings = UserSettings {
+            bonus_accrual: BonusAccrual::Accrue,
+            bonuses: vec![make_bonus(vec![2], BonusKind::Fixed, 500_001.0)],
+            ..short_friday_settings()
+        };
+        let eve = NaiveDate::from_ymd_opt(2025, 2, 24)
+            .unwrap()
+            .and_hms_opt(19, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, eve, &PLAIN).unwrap();
+        assert!(result.bonus_earnings > 0.0);
+        assert_eq!(result.accumulated_earnings, 3_500_001);
+
+        // 근무 중에는 진행률만큼 적립
+        let working = NaiveDate::from_ymd_opt(2025, 2, 24)
+            .unwrap()
+            .and_hms_opt(13, 30, 0)
+            .unwrap();
+        let half = calculate_salary(&settings, None, None, working, &PLAIN).unwrap();
+        assert!(half.bonus_earnings > 0.0 && half.bonus_earnings < result.bonus_earnings);
+
+        let forecast =
+            forecast_salary(&settings, working, &PLAIN, &mut SettledMemo::new()).unwrap();
+        assert_eq!(forecast.end_of_period, 3_500_001.0);
+    }
+
+    #[test]
+    fn test_yearly_salary_divided_by_splits() {
+        let settings = UserSettings {
+            salary_type: SalaryType::Yearly,
+            yearly_splits: 14,
+            pay_display: Some(PayDisplay::Gross),
+            ..make_settings(42_000_000, 25)
+        };
+        let eve = NaiveDate::from_ymd_opt(2025, 2, 24)
+            .unwrap()
+            .and_hms_opt(19, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, eve, &PLAIN).unwrap();
+        assert_eq!(result.accumulated_earnings, 3_000_000);
+    }
+
+    fn salary_version(effective_from: &str, salary_amount: u32, pay_percent: u8) -> SalaryVersion {
+        SalaryVersion {
+            effective_from: effective_from.to_string(),
+            salary_type: SalaryType::Monthly,
+            salary_amount,
+            pay_percent,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn test_salary_change_prorates_straddling_period() {
+        // 01-25 ~ 02-25 기간 평일 21일 중 02-10(월)부터 11일은 인상된 급여
+        let before = make_settings(3_000_000, 25);
+        let mut settings = before.clone();
+        assert!(settings.record_salary_change(SalaryType::Monthly, 3_300_000, "2025-02-10"));
+        assert_eq!(settings.salary_amount, 3_300_000);
+        let at = |settings: &UserSettings, day: u32| {
+            let now = NaiveDate::from_ymd_opt(2025, 2, day)
+                .unwrap()
+                .and_hms_opt(19, 0, 0)
+                .unwrap();
+            calculate_salary(settings, None, None, now, &PLAIN).unwrap()
+        };
+        // 변경 전 날짜는 이전 급여 그대로
+        assert_eq!(
+            at(&settings, 7).accumulated_earnings,
+            at(&before, 7).accumulated_earnings
+        );
+        let at = |day: u32| at(&settings, day);
+        assert_eq!(at(7).daily_rate, 142_857);
+        assert_eq!(at(10).daily_rate, 157_142);
+        // 3,000,000 × 10/21 + 3,300,000 × 11/21
+        assert_eq!(at(24).accumulated_earnings, 1_428_571 + 1_728_571);
+
+        // 다음 기간은 인상된 급여 전액
+        let next_eve = NaiveDate::from_ymd_opt(2025, 3, 24)
+            .unwrap()
+            .and_hms_opt(19, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, next_eve, &PLAIN).unwrap();
+        assert_eq!(result.accumulated_earnings, 3_300_000);
+    }
+
+    #[test]
+    fn test_probation_pays_percentage_until_end_date() {
+        // 수습 90% (01-02 ~ 03-31), 04-01부터 100%
+        let settings = UserSettings {
+            salary_history: vec![
+                salary_version("2025-01-02", 3_000_000, 90),
+                salary_version("2025-04-01", 3_000_000, 100),
+            ],
+            ..make_settings(3_000_000, 25)
+        };
+        let eve = |month: u32| {
+            let now = NaiveDate::from_ymd_opt(2025, month, 24)
+                .unwrap()
+                .and_hms_opt(19, 0, 0)
+                .unwrap();
+            calculate_salary(&settings, None, None, now, &PLAIN)
+                .unwrap()
+                .accumulated_earnings
+        };
+        assert_eq!(eve(2), 2_700_000);
+        assert!(eve(4) > 2_700_000 && eve(4) < 3_000_000);
+        assert_eq!(eve(5), 3_000_000);
+    }
+
+    #[test]
+    fn test_per_weekday_overnight_shift() {
+        // 월요일만 22:00–06:00, 나머지는 09:00–18:00
+        let settings = UserSettings {
+            weekday_hours: vec![crate::types::WeekdayHours {
+                weekday: 1,
+                start_time: "22:00".to_string(),
+                end_time: "06:00".to_string(),
+            }],
+            ..make_settings(3_000_000, 25)
+        };
+
+        // 화 03:00 → 월요일 야간 근무 중
+        let tuesday_3am = NaiveDate::from_ymd_opt(2025, 2, 11)
+            .unwrap()
+            .and_hms_opt(3, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, tuesday_3am, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::Working);
+
+        // 화 10:00 → 화요일 주간 근무
+        let tuesday_10am = NaiveDate::from_ymd_opt(2025, 2, 11)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, tuesday_10am, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::Working);
+
+        // 월 03:00 → 일요일은 야간 일정이 아니므로 월요일 출근 전
+        let monday_3am = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(3, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, monday_3am, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::BeforeWork);
+    }
+
+    // -- Workday history tests --
+
+    pub(super) fn history_cache(
+        date: &str,
+        kind: crate::types::WorkdayKind,
+        hours: Option<(&str, &str)>,
+    ) -> crate::types::WorkdayCache {
+        crate::types::WorkdayCache {
+            date: date.into(),
+            kind,
+            clock_in_time: hours.map(|(s, _)| s.to_string()),
+            clock_out_time: hours.map(|(_, e)| e.to_string()),
+            completed: false,
+            events: vec![],
+            is_dirty: false,
+        }
+    }
+
+    #[test]
+    fn test_accumulated_replays_past_workday_caches() {
+        use crate::types::WorkdayKind;
+        // 시급 10,030 → 주휴 포함 12,036, 09–18 (점심 1h) = 8h
+        let settings = make_hourly_settings(10_030);
+        let past_workday = |date: NaiveDate| match date.format("%Y-%m-%d").to_string().as_str() {
+            "2025-02-03" => Some(history_cache("2025-02-03", WorkdayKind::DayOff, None)),
+            "2025-02-04" => Some(history_cache("2025-02-04", WorkdayKind::AnnualLeave, None)),
+            // 20시까지 근무 → 10h
+            "2025-02-05" => Some(history_cache(
+                "2025-02-05",
+                WorkdayKind::Work,
+                Some(("09:00", "20:00")),
+            )),
+            // 토요일 추가 근무 10–14 (점심 제외 3h)
+            "2025-02-08" => Some(history_cache(
+                "2025-02-08",
+                WorkdayKind::Work,
+                Some(("10:00", "14:00")),
+            )),
+            _ => None,
+        };
+        // 02-10(월) 10:00, 기간 시작 01-25 → 지난 근무일 01-27 ~ 02-07 (10일)
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let result =
+            calculate_salary(&settings, None, None, now, &with_workday(&past_workday)).unwrap();
+
+        // 기본 8h × 8일 + 연차 8h + 연장 2h + 토요일 3h + 오늘 1h
+        let hours: f64 = 8.0 * 8.0 + 8.0 + 2.0 + 3.0 + 1.0;
+        assert_eq!(
+            result.accumulated_earnings,
+            (12_036.0 * hours).round() as Minor
+        );
+        // 휴무·연차 제외, 토요일 포함
+        assert_eq!(result.worked_days, 9);
+    }
+
+    #[test]
+    fn test_past_public_holiday_cache_keeps_monthly_total() {
+        use crate::types::WorkdayKind;
+        let settings = make_settings(3_000_000, 25);
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        let holiday = |date: NaiveDate| {
+            (date == NaiveDate::from_ymd_opt(2025, 1, 28).unwrap())
+                .then(|| history_cache("2025-01-28", WorkdayKind::PublicHoliday, None))
+        };
+
+        let fallback = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        let with_holiday =
+            calculate_salary(&settings, None, None, now, &with_workday(&holiday)).unwrap();
+        assert_eq!(fallback.worked_days, 10);
+        assert_eq!(with_holiday.worked_days, 9);
+        // 기간 근무일 21일 → 20일로 나눠 일급이 오르고, 월급날 전날 누적은 그대로 월급 전액
+        assert!(fallback.daily_rate < with_holiday.daily_rate);
+        assert_eq!(with_holiday.daily_rate, 150_000);
+        assert_eq!(with_holiday.accumulated_earnings, 9 * 150_000);
+        let eve = NaiveDate::from_ymd_opt(2025, 2, 24)
+            .unwrap()
+            .and_hms_opt(19, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, eve, &with_workday(&holiday)).unwrap();
+        assert_eq!(result.accumulated_earnings, 3_000_000);
+    }
+
+    #[test]
+    fn test_sick_leave_pays_configured_percent_today() {
+        let settings = UserSettings {
+            sick_leave_pay_percent: 60,
+            ..make_settings(3_000_000, 25)
+        };
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let result = calculate_salary(
+            &settings,
+            Some(TodayWorkStatus::SickLeave),
+            None,
+            now,
+            &PLAIN,
+        )
+        .unwrap();
+        assert_eq!(result.work_status, WorkStatus::SickLeave);
+        let paid = money::prorate(result.daily_rate, 60, 100, settings.rounding_policy);
+        assert_eq!(result.base_earnings, paid as f64);
+        assert_eq!(result.leave_deduction, result.daily_rate - paid);
+
+        let unpaid = calculate_salary(
+            &settings,
+            Some(TodayWorkStatus::UnpaidLeave),
+            None,
+            now,
+            &PLAIN,
+        )
+        .unwrap();
+        assert_eq!(unpaid.work_status, WorkStatus::UnpaidLeave);
+        assert_eq!(unpaid.today_earnings, 0.0);
+        assert_eq!(unpaid.leave_deduction, unpaid.daily_rate);
+    }
+
+    #[test]
+    fn test_leave_on_non_work_day_pays_and_deducts_nothing() {
+        let settings = make_settings(3_000_000, 25);
+        // 2025-02-08 is Saturday
+        let now = NaiveDate::from_ymd_opt(2025, 2, 8)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        for status in [TodayWorkStatus::UnpaidLeave, TodayWorkStatus::AnnualLeave] {
+            let result = calculate_salary(&settings, Some(status), None, now, &PLAIN).unwrap();
+            assert_eq!(result.work_status, WorkStatus::DayOff);
+            assert_eq!(result.today_earnings, 0.0);
+            assert_eq!(result.leave_deduction, 0);
+        }
+    }
+
+    #[test]
+    fn test_unpaid_and_sick_leave_deduct_from_period_total() {
+        use crate::types::WorkdayKind;
+        let settings = UserSettings {
+            sick_leave_pay_percent: 50,
+            ..make_settings(3_000_000, 25)
+        };
+        let leave = |date: NaiveDate| match date.format("%Y-%m-%d").to_string().as_str() {
+            "2025-02-04" => Some(history_cache("2025-02-04", WorkdayKind::UnpaidLeave, None)),
+            "2025-02-05" => Some(history_cache("2025-02-05", WorkdayKind::SickLeave, None)),
+            // 주말 휴무는 월급에서 빠지지 않는다
+            "2025-02-08" => Some(history_cache("2025-02-08", WorkdayKind::DayOff, None)),
+            // 주말 무급휴가는 근무일이 아니라 공제 없음
+            "2025-02-09" => Some(history_cache("2025-02-09", WorkdayKind::UnpaidLeave, None)),
+            // 평일 휴무는 기간 근무시간에서 빠져 공제 없이 월급 유지
+            "2025-02-06" => Some(history_cache("2025-02-06", WorkdayKind::DayOff, None)),
+            _ => None,
+        };
+        // 월급날 전날 퇴근 후: 공제 전 누적은 월급 전액
+        let now = NaiveDate::from_ymd_opt(2025, 2, 24)
+            .unwrap()
+            .and_hms_opt(19, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, now, &with_workday(&leave)).unwrap();
+        let rates = pay_rates(
+            &settings,
+            NaiveDate::from_ymd_opt(2025, 1, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 25).unwrap(),
+            &with_workday(&leave),
+        )
+        .unwrap();
+        let daily = |day| rates.daily_rate(&settings, day, 540);
+        let unpaid_day = daily(NaiveDate::from_ymd_opt(2025, 2, 4).unwrap());
+        let sick_day = daily(NaiveDate::from_ymd_opt(2025, 2, 5).unwrap());
+        let expected =
+            unpaid_day + sick_day - money::prorate(sick_day, 50, 100, settings.rounding_policy);
+        assert_eq!(result.leave_deduction, expected);
+        assert_eq!(result.accumulated_earnings, 3_000_000 - expected);
+        assert_eq!(
+            result.accumulated_earnings + result.leave_deduction,
+            3_000_000
+        );
+
+        // 02-10 시점 예상에도 같은 공제가 반영된다
+        let earlier = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let forecast = forecast_salary(
+            &settings,
+            earlier,
+            &with_workday(&leave),
+            &mut SettledMemo::new(),
+        )
+        .unwrap();
+        assert_eq!(forecast.leave_deduction, expected as f64);
+        assert_eq!(forecast.end_of_period, (3_000_000 - expected) as f64);
+    }
+
+    #[test]
+    fn test_builtin_holiday_yields_to_server_cache() {
+        use crate::types::WorkdayKind;
+        let holidays = HolidayCalendar::bundled();
+        let chuseok = NaiveDate::from_ymd_opt(2025, 10, 6).unwrap();
+
+        let offline = with_builtin_holiday(&holidays, chuseok, None).unwrap();
+        assert!(is_public_holiday_cache(&offline));
+        assert_eq!(
+            overrides_from_cache(offline).status,
+            Some(TodayWorkStatus::PublicHoliday)
+        );
+        let weekday = NaiveDate::from_ymd_opt(2025, 10, 10).unwrap();
+        assert!(with_builtin_holiday(&holidays, weekday, None).is_none());
+
+        // 서버가 근무일로 내려준 날은 그대로 (회사 지정 근무일)
+        let server = history_cache("2025-10-06", WorkdayKind::Work, None);
+        let kept = with_builtin_holiday(&holidays, chuseok, Some(server.clone())).unwrap();
+        assert_eq!(kept, server);
+
+        // 동기화 전 로컬 출근 기록은 휴일 근무
+        let local = crate::types::WorkdayCache {
+            is_dirty: true,
+            ..history_cache("2025-10-06", WorkdayKind::Work, Some(("09:00", "13:00")))
+        };
+        let marked = with_builtin_holiday(&holidays, chuseok, Some(local)).unwrap();
+        assert_eq!(
+            overrides_from_cache(marked).status,
+            Some(TodayWorkStatus::HolidayWork)
+        );
+
+        // 동기화 전 연차는 공휴일 이벤트 없이 연차 그대로
+        let leave = crate::types::WorkdayCache {
+            is_dirty: true,
+            ..history_cache("2025-10-06", WorkdayKind::AnnualLeave, None)
+        };
+        let kept = with_builtin_holiday(&holidays, chuseok, Some(leave.clone())).unwrap();
+        assert_eq!(kept, leave);
+        assert_eq!(
+            overrides_from_cache(kept).status,
+            Some(TodayWorkStatus::AnnualLeave)
+        );
+    }
+
+    #[test]
+    fn test_company_holiday_overrides_default_server_workday() {
+        use crate::ics::IcsEvent;
+        use crate::types::{CompanyHolidayBook, WorkdayKind};
+        let mut book = CompanyHolidayBook::new();
+        book.insert(
+            "회사".into(),
+            vec![IcsEvent {
+                summary: "창립기념일".into(),
+                start: NaiveDate::from_ymd_opt(2025, 4, 15).unwrap(),
+                end: NaiveDate::from_ymd_opt(2025, 4, 16).unwrap(),
+                recurrence: None,
+                exceptions: vec![],
+            }],
+        );
+        let holidays = HolidayCalendar::bundled().with_company_holidays(&book);
+        let founding = NaiveDate::from_ymd_opt(2025, 4, 15).unwrap();
+
+        let server = history_cache("2025-04-15", WorkdayKind::Work, None);
+        let day_off = with_builtin_holiday(&holidays, founding, Some(server)).unwrap();
+        assert_eq!(
+            overrides_from_cache(day_off).status,
+            Some(TodayWorkStatus::PublicHoliday)
+        );
+
+        // 출근 기록이 있으면 휴일 근무, 연차 등 명시한 종류는 그대로
+        let worked = history_cache("2025-04-15", WorkdayKind::Work, Some(("09:00", "12:00")));
+        let worked = with_builtin_holiday(&holidays, founding, Some(worked)).unwrap();
+        assert_eq!(
+            overrides_from_cache(worked).status,
+            Some(TodayWorkStatus::HolidayWork)
+        );
+        let leave = history_cache("2025-04-15", WorkdayKind::AnnualLeave, None);
+        let leave = with_builtin_holiday(&holidays, founding, Some(leave)).unwrap();
+        assert_eq!(
+            overrides_from_cache(leave).status,
+            Some(TodayWorkStatus::AnnualLeave)
+        );
+    }
+
+    #[test]
+    fn test_offline_chuseok_is_paid_day_off_outside_period_hours() {
+        // 2025-09-25 ~ 10-25: 평일 22일 중 개천절·추석 연휴·대체공휴일·한글날 5일 제외
+        let settings = make_settings(3_000_000, 25);
+        let holidays = HolidayCalendar::bundled();
+        let is_public_holiday = |date| holidays.is_holiday(date);
+        let workday = |date| with_builtin_holiday(&holidays, date, None);
+        let period = (
+            NaiveDate::from_ymd_opt(2025, 9, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 10, 25).unwrap(),
+        );
+        let all = get_work_minutes_in_period(period.0, period.1, &settings, &PLAIN);
+        let ctx = SalaryContext {
+            is_public_holiday: &is_public_holiday,
+            workday: &workday,
+        };
+        let worked = get_work_minutes_in_period(period.0, period.1, &settings, &ctx);
+        assert_eq!(all.len() - worked.len(), 5);
+
+        let chuseok_noon = NaiveDate::from_ymd_opt(2025, 10, 6)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let result = calculate_job_salary(&settings, chuseok_noon, &ctx).unwrap();
+        assert_eq!(result.work_status, WorkStatus::PublicHoliday);
+        assert_eq!(result.today_earnings, 0.0);
+
+        // 공휴일을 뺀 근무일로 나눠도 월급날 전날 누적은 월급 전액
+        let eve = NaiveDate::from_ymd_opt(2025, 10, 24)
+            .unwrap()
+            .and_hms_opt(19, 0, 0)
+            .unwrap();
+        let result = calculate_job_salary(&settings, eve, &ctx).unwrap();
+        assert_eq!(result.accumulated_earnings, 3_000_000);
+    }
+}
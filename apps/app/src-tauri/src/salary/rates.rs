@@ -0,0 +1,1126 @@
+//! 급여 기간과 단가.
+//!
+//! 월급날 보정, 기간 소정근로시간 배분, 주휴수당, 연장·야간·휴일 가산수당을 계산한다.
+
+use std::collections::HashMap;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::deduction;
+use crate::money::{self, Minor};
+use crate::types::{
+    BonusAccrual, BonusKind, Currency, PayCycle, PayDisplay, PaydayAdjustment, PremiumRules,
+    RoundingPolicy, SalaryType, UserSettings,
+};
+
+use super::{
+    is_public_holiday_cache, normalize_breaks, overlap, overrides_from_cache, shift_minutes,
+    time_to_minutes, weekday_index, SalaryContext, TodayWorkStatus,
+};
+
+/// 급여 기간의 단가. 기간 중 급여가 바뀌면 버전별로 나뉜다.
+pub(super) struct PayRates {
+    pub(super) rounding: RoundingPolicy,
+    /// (적용 시작일, 단가). 첫 항목은 기간 시작일, 이후 기간 중 급여 변경일 순
+    versions: Vec<(NaiveDate, VersionRates)>,
+    period_start: NaiveDate,
+    /// 이번 월급날(`period_start`)에 한 번에 반영하는 상여금
+    bonus_lump: Minor,
+    /// 다음 월급날 지급분을 기간 근무일에 나눠 적립한 상여금
+    bonus_shares: HashMap<NaiveDate, Minor>,
+}
+
+/// 급여 버전 하나의 단가. 월급·연봉은 기간 전체에 배분한 뒤 적용 날짜분만 쓴다.
+struct VersionRates {
+    /// 시급제: 주휴수당 포함 시급. 월급·연봉: 기간 평균 시급 (표시용)
+    per_hour: f64,
+    /// 가산수당 산정 기준 통상시급
+    ordinary_hourly: f64,
+    /// 월급·연봉의 기간 배분 (시급제는 None)
+    monthly: Option<MonthlyAllocation>,
+    /// 상여금 비율 산정 기준 월 기본급
+    base_monthly: Minor,
+    /// 세전·세후 표시 비율 (정액 상여금에 적용)
+    display_ratio: f64,
+}
+
+/// 기간 월급을 근무일별 소정근로시간 비례로 나눈 결과. 근무일 배분액 합계 = `total`
+struct MonthlyAllocation {
+    total: Minor,
+    period_minutes: i64,
+    days: HashMap<NaiveDate, Minor>,
+}
+
+impl PayRates {
+    /// `day`에 적용되는 급여 버전의 단가
+    fn version(&self, day: NaiveDate) -> &VersionRates {
+        let (_, rates) = self
+            .versions
+            .iter()
+            .rev()
+            .find(|(from, _)| *from <= day)
+            .unwrap_or(&self.versions[0]);
+        rates
+    }
+
+    pub(super) fn ordinary_hourly(&self, day: NaiveDate) -> f64 {
+        self.version(day).ordinary_hourly
+    }
+
+    /// `day`에 `work_minutes` 유급 시간 일할 때의 일급.
+    ///
+    /// 월급·연봉은 기간 배분액 기준이고, 연장근로 규칙이 켜져 있으면
+    /// 소정근로시간을 넘긴 시간도 통상시급으로 기본급 지급한다.
+    pub(super) fn daily_rate(
+        &self,
+        settings: &UserSettings,
+        day: NaiveDate,
+        work_minutes: u32,
+    ) -> Minor {
+        let rates = self.version(day);
+        let Some(monthly) = &rates.monthly else {
+            return money::from_f64(rates.per_hour * work_minutes as f64 / 60.0, self.rounding);
+        };
+        let regular_minutes = paid_minutes_on(settings, day);
+        // 기간 밖이거나 근무 요일이 아닌 날(추가 근무)은 요일 소정근로시간 비례
+        let rate = monthly.days.get(&day).copied().unwrap_or_else(|| {
+            money::prorate(
+                monthly.total,
+                regular_minutes as i64,
+                monthly.period_minutes,
+                self.rounding,
+            )
+        });
+        if settings.premium_rules.overtime.enabled {
+            let extra_hours = work_minutes.saturating_sub(regular_minutes) as f64 / 60.0;
+            rate + money::from_f64(rates.ordinary_hourly * extra_hours, self.rounding)
+        } else {
+            rate
+        }
+    }
+
+    pub(super) fn bonus_lump_on(&self, day: NaiveDate) -> Minor {
+        if day == self.period_start {
+            self.bonus_lump
+        } else {
+            0
+        }
+    }
+
+    pub(super) fn bonus_share_on(&self, day: NaiveDate) -> Minor {
+        self.bonus_shares.get(&day).copied().unwrap_or(0)
+    }
+}
+
+/// `[period_start, period_end)` 기간의 단가. 기간 소정근로시간이 0이면 None.
+///
+/// 기간 중 `salary_history`로 급여가 바뀌면 변경일 전후를 각 버전 단가로 일할 계산한다.
+pub(super) fn pay_rates(
+    settings: &UserSettings,
+    period_start: NaiveDate,
+    period_end: NaiveDate,
+    ctx: &SalaryContext,
+) -> Option<PayRates> {
+    let year = period_start.year();
+    let rounding = settings.rounding_policy;
+    // 기간 내 소정근로시간 비례 배분 (짧은 요일은 일급도 적음)
+    let work_minutes = get_work_minutes_in_period(period_start, period_end, settings, ctx);
+    let weights: Vec<i64> = work_minutes.iter().map(|&(_, m)| m as i64).collect();
+    let period_minutes: i64 = weights.iter().sum();
+
+    let mut change_days: Vec<NaiveDate> = settings
+        .salary_history
+        .iter()
+        .filter_map(|version| NaiveDate::parse_from_str(&version.effective_from, "%Y-%m-%d").ok())
+        .filter(|&day| period_start < day && day < period_end)
+        .collect();
+    change_days.sort();
+    let versions = std::iter::once(period_start)
+        .chain(change_days)
+        .map(|from| {
+            let versioned = settings.with_salary_on(&from.format("%Y-%m-%d").to_string());
+            Some((from, version_rates(&versioned, &work_minutes, year)?))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    // 상여금: 지급 월은 보정 전 월급날의 월 기준, 금액은 그 월급날에 가까운 급여 버전 기준
+    let bonus_total = |payday: NaiveDate, rates: &VersionRates| -> Minor {
+        let Some(month) = bonus_month(settings, payday, ctx.is_public_holiday) else {
+            return 0;
+        };
+        let month = month as u8;
+        settings
+            .bonuses
+            .iter()
+            .filter(|bonus| bonus.months.contains(&month))
+            .map(|bonus| match bonus.kind {
+                BonusKind::Fixed => money::from_f64(bonus.value * rates.display_ratio, rounding),
+                BonusKind::PercentOfBase => {
+                    money::from_f64(rates.base_monthly as f64 * bonus.value / 100.0, rounding)
+                }
+            })
+            .sum()
+    };
+    let (first, last) = (&versions[0].1, &versions[versions.len() - 1].1);
+    let mut bonus_lump = 0;
+    let mut bonus_shares = HashMap::new();
+    match settings.bonus_accrual {
+        BonusAccrual::OnPayday => bonus_lump = bonus_total(period_start, first),
+        BonusAccrual::Accrue if period_minutes > 0 => {
+            bonus_shares = work_minutes
+                .iter()
+                .map(|&(day, _)| day)
+                .zip(money::allocate(
+                    bonus_total(period_end, last),
+                    &weights,
+                    rounding,
+                ))
+                .collect();
+        }
+        // 근무일이 없는 기간은 월급날에 한 번에
+        BonusAccrual::Accrue => bonus_lump = bonus_total(period_end, last),
+    }
+
+    Some(PayRates {
+        rounding,
+        versions,
+        period_start,
+        bonus_lump,
+        bonus_shares,
+    })
+}
+
+/// 급여 버전 하나(`settings`의 최상위 급여 필드)의 단가.
+/// `work_minutes`는 기간 내 근무일별 소정근로시간.
+fn version_rates(
+    settings: &UserSettings,
+    work_minutes: &[(NaiveDate, u32)],
+    year: i32,
+) -> Option<VersionRates> {
+    let rounding = settings.rounding_policy;
+    let weekly_hours: f64 = settings
+        .work_days
+        .iter()
+        .map(|&d| regular_paid_hours(settings, d))
+        .sum();
+
+    match settings.salary_type {
+        SalaryType::Hourly => {
+            let gross_hourly =
+                hourly_wage_with_allowance(settings.salary_amount as f64, weekly_hours);
+            let gross_monthly = gross_hourly * weekly_hours * WEEKS_PER_MONTH;
+            let display_ratio = if gross_monthly > 0.0 {
+                to_display_amount(settings, gross_monthly, false, year) / gross_monthly
+            } else {
+                1.0
+            };
+            Some(VersionRates {
+                per_hour: gross_hourly * display_ratio,
+                ordinary_hourly: settings.salary_amount as f64 * display_ratio,
+                monthly: None,
+                base_monthly: money::from_f64(gross_monthly * display_ratio, rounding),
+                display_ratio,
+            })
+        }
+        SalaryType::Monthly | SalaryType::Yearly => {
+            // 월급은 세후, 연봉은 세전 입력으로 본다
+            let (raw_monthly, monthly_salary) = if settings.salary_type == SalaryType::Yearly {
+                let raw = settings.salary_amount as f64 / settings.yearly_splits.max(1) as f64;
+                (raw, to_display_amount(settings, raw, false, year))
+            } else {
+                let raw = settings.salary_amount as f64;
+                (raw, to_display_amount(settings, raw, true, year))
+            };
+            let monthly_total = money::from_f64(monthly_salary, rounding);
+            // 주급·격주급 등은 월 금액을 연 지급 횟수로 환산
+            let total = match settings.pay_cycle {
+                PayCycle::Monthly => monthly_total,
+                cycle => money::from_f64(
+                    monthly_salary * 12.0 / cycle.periods_per_year() as f64,
+                    rounding,
+                ),
+            };
+            let weights: Vec<i64> = work_minutes.iter().map(|&(_, m)| m as i64).collect();
+            let period_minutes: i64 = weights.iter().sum();
+            if period_minutes <= 0 {
+                return None;
+            }
+            let days = work_minutes
+                .iter()
+                .map(|&(day, _)| day)
+                .zip(money::allocate(total, &weights, rounding))
+                .collect();
+            // 월 소정근로시간 + 주휴시간 (주 40시간 → 약 209시간)
+            let monthly_paid_hours =
+                (weekly_hours + weekly_allowance_hours(weekly_hours)) * WEEKS_PER_MONTH;
+            let ordinary_hourly = if monthly_paid_hours > 0.0 {
+                monthly_total as f64 / monthly_paid_hours
+            } else {
+                0.0
+            };
+            Some(VersionRates {
+                per_hour: total as f64 * 60.0 / period_minutes as f64,
+                ordinary_hourly,
+                monthly: Some(MonthlyAllocation {
+                    total,
+                    period_minutes,
+                    days,
+                }),
+                base_monthly: monthly_total,
+                display_ratio: if raw_monthly > 0.0 {
+                    monthly_salary / raw_monthly
+                } else {
+                    1.0
+                },
+            })
+        }
+    }
+}
+
+/// 월 환산 주 수 (365 / 7 / 12)
+const WEEKS_PER_MONTH: f64 = 365.0 / 7.0 / 12.0;
+
+/// 월 금액을 `pay_display`(세전/세후) 기준으로 환산.
+///
+/// `is_net`은 입력 금액이 이미 세후인지 여부. 필요한 방향으로만 공제를 적용/역산한다.
+fn to_display_amount(settings: &UserSettings, monthly: f64, is_net: bool, year: i32) -> f64 {
+    // 공제 테이블은 국내 기준이라 원화 급여에만 적용
+    if settings.currency != Currency::Krw {
+        return monthly;
+    }
+    let table = deduction::table_for_year(year);
+    let tax_free = settings.tax_free_allowance as f64;
+    match (settings.pay_display, is_net) {
+        (Some(PayDisplay::Net), false) => {
+            deduction::estimate_monthly(monthly, tax_free, settings.dependents, table).net
+        }
+        (Some(PayDisplay::Gross), true) => {
+            deduction::gross_from_net(monthly, tax_free, settings.dependents, table)
+        }
+        _ => monthly,
+    }
+}
+
+/// 주휴수당 발생 최소 주 소정근로시간 (근로기준법 제18조 제3항)
+const WEEKLY_ALLOWANCE_MIN_HOURS: f64 = 15.0;
+/// 주휴수당 산정 시 인정되는 주 소정근로시간 상한
+const WEEKLY_ALLOWANCE_MAX_HOURS: f64 = 40.0;
+/// 주 40시간 기준 주휴시간
+const WEEKLY_ALLOWANCE_FULL_HOURS: f64 = 8.0;
+
+/// 주 소정근로시간에 대한 주휴시간. 15시간 미만이면 0.
+///
+/// `min(주 소정근로시간, 40) / 40 × 8`
+pub(super) fn weekly_allowance_hours(weekly_hours: f64) -> f64 {
+    if weekly_hours < WEEKLY_ALLOWANCE_MIN_HOURS {
+        return 0.0;
+    }
+    weekly_hours.min(WEEKLY_ALLOWANCE_MAX_HOURS) / WEEKLY_ALLOWANCE_MAX_HOURS
+        * WEEKLY_ALLOWANCE_FULL_HOURS
+}
+
+/// 주휴수당을 실근로시간에 나눠 얹은 실효 시급.
+///
+/// 주휴수당은 소정근로일을 개근한 주에 지급되지만, ticker는 초 단위로 적립하므로
+/// 근로시간 비례로 분배한다 (주 40시간 → 시급 × 1.2).
+fn hourly_wage_with_allowance(hourly_wage: f64, weekly_hours: f64) -> f64 {
+    if weekly_hours <= 0.0 {
+        return hourly_wage;
+    }
+    hourly_wage * (1.0 + weekly_allowance_hours(weekly_hours) / weekly_hours)
+}
+
+/// 연장근로 판단 기준 1일 근로시간 (초)
+const DAILY_LIMIT_SECONDS: u32 = 8 * 3600;
+/// 연장근로 판단 기준 주 근로시간
+const WEEKLY_LIMIT_HOURS: f64 = 40.0;
+/// 야간근로 구간 (초, 근무 시작일 자정 기준): 22:00–06:00
+const NIGHT_WINDOWS: [(u32, u32); 3] = [
+    (0, 6 * 3600),
+    (22 * 3600, 30 * 3600),
+    (46 * 3600, 54 * 3600),
+];
+
+/// 오늘 연장근로로 보기 시작하는 실근로 시간 (초).
+///
+/// 1일 8시간과, 이번 주(월요일 시작) 앞선 날의 실근로(`workday` 기록, 하루 8시간까지)를 뺀
+/// 주 40시간 잔여 중 작은 값.
+pub(super) fn overtime_threshold_seconds(
+    settings: &UserSettings,
+    day: NaiveDate,
+    workday: &dyn Fn(NaiveDate) -> Option<crate::types::WorkdayCache>,
+) -> u32 {
+    let prior_seconds: u32 = (1..=day.weekday().num_days_from_monday())
+        .filter_map(|n| day.checked_sub_days(chrono::Days::new(n as u64)))
+        .map(|d| (weekly_worked_minutes(settings, d, workday(d)) * 60).min(DAILY_LIMIT_SECONDS))
+        .sum();
+    let weekly_limit = (WEEKLY_LIMIT_HOURS * 3600.0) as u32;
+    weekly_limit
+        .saturating_sub(prior_seconds)
+        .min(DAILY_LIMIT_SECONDS)
+}
+
+/// 주 40시간에 넣는 그날 실근로 (분, 휴게 제외).
+/// 휴일 근무·휴가·휴무는 0이고, 반차·반반차는 휴가 부분을 뺀다
+fn weekly_worked_minutes(
+    settings: &UserSettings,
+    day: NaiveDate,
+    cache: Option<crate::types::WorkdayCache>,
+) -> u32 {
+    let weekday = weekday_index(day);
+    if !settings.work_days.contains(&weekday) {
+        return 0;
+    }
+    let overrides = cache.map(overrides_from_cache);
+    let status = overrides.as_ref().and_then(|o| o.status);
+    let schedule = overrides.and_then(|o| o.schedule);
+    let (start_time, end_time) = schedule
+        .as_ref()
+        .map(|(s, e)| (s.as_str(), e.as_str()))
+        .unwrap_or_else(|| settings.hours_for_weekday(weekday));
+    let (start, end, breaks) = shift_minutes(settings, day, start_time, end_time);
+    let break_minutes: u32 = breaks.iter().map(|(s, e)| e - s).sum();
+    let work_minutes = end - start - break_minutes;
+    match status {
+        None => work_minutes,
+        Some(TodayWorkStatus::PartialLeave(leave)) => {
+            work_minutes - leave.leave_minutes(work_minutes)
+        }
+        Some(_) => 0,
+    }
+}
+
+/// `[start, until)`에서 휴게 구간(분, 정규화됨)을 뺀 실근로 구간 (초).
+pub(super) fn worked_segments(start: u32, until: u32, breaks: &[(u32, u32)]) -> Vec<(u32, u32)> {
+    let mut segments = Vec::with_capacity(breaks.len() + 1);
+    let mut cursor = start;
+    for &(break_start, break_end) in breaks {
+        let segment_end = (break_start * 60).min(until);
+        if cursor < segment_end {
+            segments.push((cursor, segment_end));
+        }
+        cursor = cursor.max(break_end * 60);
+    }
+    if cursor < until {
+        segments.push((cursor, until));
+    }
+    segments
+}
+
+/// 실근로 구간에 대한 가산수당 (기본급 제외, 가산분만).
+///
+/// 휴일에는 연장 대신 휴일 8시간 이내/초과 가산을 적용하고,
+/// 야간 가산은 연장·휴일과 중복 적용한다.
+pub(super) fn premium_earnings(
+    rules: &PremiumRules,
+    segments: &[(u32, u32)],
+    ordinary_hourly: f64,
+    overtime_threshold: u32,
+    is_holiday: bool,
+) -> f64 {
+    let worked: u32 = segments.iter().map(|(s, e)| e - s).sum();
+    let mut weighted_seconds = 0.0;
+
+    if is_holiday {
+        if rules.holiday.enabled {
+            weighted_seconds += rules.holiday.rate * worked.min(DAILY_LIMIT_SECONDS) as f64;
+        }
+        if rules.holiday_overtime.enabled {
+            weighted_seconds +=
+                rules.holiday_overtime.rate * worked.saturating_sub(DAILY_LIMIT_SECONDS) as f64;
+        }
+    } else if rules.overtime.enabled {
+        weighted_seconds += rules.overtime.rate * worked.saturating_sub(overtime_threshold) as f64;
+    }
+
+    if rules.night.enabled {
+        let night: u32 = segments
+            .iter()
+            .flat_map(|&(s, e)| {
+                NIGHT_WINDOWS
+                    .iter()
+                    .map(move |&(ns, ne)| overlap(s, e, ns, ne))
+            })
+            .sum();
+        weighted_seconds += rules.night.rate * night as f64;
+    }
+
+    ordinary_hourly * weighted_seconds / 3600.0
+}
+
+/// 해당 요일의 기본 출퇴근 시간 기준 하루 유급 시간 (휴게 제외).
+fn regular_paid_hours(settings: &UserSettings, weekday: u8) -> f64 {
+    regular_paid_minutes(settings, weekday) as f64 / 60.0
+}
+
+/// 해당 요일의 기본 출퇴근 시간 기준 하루 유급 분 (휴게 제외).
+fn regular_paid_minutes(settings: &UserSettings, weekday: u8) -> u32 {
+    let (start_time, end_time) = settings.hours_for_weekday(weekday);
+    let start = time_to_minutes(start_time);
+    let raw_end = time_to_minutes(end_time);
+    let end = if raw_end <= start {
+        raw_end + 24 * 60
+    } else {
+        raw_end
+    };
+    let break_minutes: u32 = normalize_breaks(&settings.break_times, start, end)
+        .iter()
+        .map(|(s, e)| e - s)
+        .sum();
+    end - start - break_minutes
+}
+
+/// `day` 요일 기본 일정의 유급 근무 시간 (분). DST 전환일은 실제 경과 시간 기준
+fn paid_minutes_on(settings: &UserSettings, day: NaiveDate) -> u32 {
+    let (start_time, end_time) = settings.hours_for_weekday(weekday_index(day));
+    let (start, end, breaks) = shift_minutes(settings, day, start_time, end_time);
+    let break_minutes: u32 = breaks.iter().map(|(s, e)| e - s).sum();
+    end.saturating_sub(start + break_minutes)
+}
+
+/// Days in a given month (1-indexed month).
+fn days_in_month(year: i32, month: u32) -> u32 {
+    // Next month's 1st day, then go back 1 day
+    if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    }
+    .and_then(|d| d.pred_opt())
+    .map(|d| d.day())
+    .unwrap_or(30)
+}
+
+/// 해당 월의 보정 전 월급날. 말일보다 크면 말일로 맞춘다.
+fn nominal_payday(year: i32, month: u32, pay_day: u8) -> NaiveDate {
+    let day = std::cmp::min(pay_day as u32, days_in_month(year, month));
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// 월급날이 근무 요일이 아니거나 공휴일이면 정책에 따라 앞/뒤 영업일로 이동.
+fn adjust_payday(
+    payday: NaiveDate,
+    adjustment: &PaydayAdjustment,
+    work_days: &[u8],
+    is_public_holiday: &dyn Fn(NaiveDate) -> bool,
+) -> NaiveDate {
+    let step = match adjustment {
+        PaydayAdjustment::None => return payday,
+        PaydayAdjustment::PreviousBusinessDay => chrono::Duration::days(-1),
+        PaydayAdjustment::NextBusinessDay => chrono::Duration::days(1),
+    };
+    let is_business_day = |d: NaiveDate| {
+        work_days.contains(&(d.weekday().num_days_from_sunday() as u8)) && !is_public_holiday(d)
+    };
+
+    let mut date = payday;
+    // 근무 요일이 비어 있는 등 영업일을 못 찾으면 원래 날짜 유지
+    for _ in 0..31 {
+        if is_business_day(date) {
+            return date;
+        }
+        date += step;
+    }
+    payday
+}
+
+/// 설정의 지급 주기·월급날·보정 정책 기준으로 `today`가 속한 급여 기간.
+/// 주급·격주급인데 기준 지급일이 없으면 None.
+pub(super) fn pay_period(
+    settings: &UserSettings,
+    today: NaiveDate,
+    is_public_holiday: &dyn Fn(NaiveDate) -> bool,
+) -> Option<(NaiveDate, NaiveDate)> {
+    let adjust = |payday| {
+        adjust_payday(
+            payday,
+            &settings.payday_adjustment,
+            &settings.work_days,
+            is_public_holiday,
+        )
+    };
+    match settings.pay_cycle {
+        PayCycle::Monthly => Some(get_pay_period(today, |year, month| {
+            adjust(nominal_payday(year, month, settings.pay_day))
+        })),
+        _ => {
+            let paydays: Vec<NaiveDate> = nominal_paydays(settings, today)?
+                .into_iter()
+                .map(adjust)
+                .collect();
+            Some(period_containing(today, &paydays))
+        }
+    }
+}
+
+/// `[from, until]` 안의 (보정 반영) 월급날. 급여 기간 시작일을 따라간다
+pub(crate) fn paydays_between(
+    settings: &UserSettings,
+    from: NaiveDate,
+    until: NaiveDate,
+    is_public_holiday: &dyn Fn(NaiveDate) -> bool,
+) -> Vec<NaiveDate> {
+    let mut paydays = vec![];
+    let mut day = from;
+    while day <= until {
+        let Some((start, end)) = pay_period(settings, day, is_public_holiday) else {
+            break;
+        };
+        if start >= from {
+            paydays.push(start);
+        }
+        if end <= day {
+            break;
+        }
+        day = end;
+    }
+    paydays
+}
+
+/// `around` 앞뒤로 두 주기 이상을 덮는 보정 전 지급일 (오름차순).
+///
+/// 주급·격주급은 `pay_anchor_date`부터 7·14일 간격이고, 기준일이 없으면 None
+/// (저장할 때 `validate_pay_cycle`로 막는다).
+fn nominal_paydays(settings: &UserSettings, around: NaiveDate) -> Option<Vec<NaiveDate>> {
+    let month_index = around.year() * 12 + around.month0() as i32;
+    let months = (-2..=2).map(|offset| {
+        let index = month_index + offset;
+        (index.div_euclid(12), index.rem_euclid(12) as u32 + 1)
+    });
+    let paydays = match settings.pay_cycle {
+        PayCycle::Monthly => months
+            .map(|(year, month)| nominal_payday(year, month, settings.pay_day))
+            .collect(),
+        PayCycle::SemiMonthly => months
+            .flat_map(|(year, month)| {
+                [
+                    nominal_payday(year, month, 15),
+                    nominal_payday(year, month, 31),
+                ]
+            })
+            .collect(),
+        PayCycle::BiWeekly | PayCycle::Weekly => {
+            let step = if settings.pay_cycle == PayCycle::Weekly {
+                7
+            } else {
+                14
+            };
+            let anchor = settings
+                .pay_anchor_date
+                .as_deref()
+                .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())?;
+            let index = (around - anchor).num_days().div_euclid(step);
+            (index - 2..=index + 2)
+                .map(|i| anchor + chrono::Duration::days(i * step))
+                .collect()
+        }
+    };
+    Some(paydays)
+}
+
+/// 보정된 지급일 `payday`에 주는 상여금의 월 (1–12). 보정으로 월이 바뀌어도 원래 월.
+///
+/// 한 달에 여러 번 지급하는 주기는 그 달 첫 지급일에만 상여금을 준다.
+fn bonus_month(
+    settings: &UserSettings,
+    payday: NaiveDate,
+    is_public_holiday: &dyn Fn(NaiveDate) -> bool,
+) -> Option<u32> {
+    let nominal = nominal_paydays(settings, payday)?;
+    let index = nominal.iter().position(|&day| {
+        adjust_payday(
+            day,
+            &settings.payday_adjustment,
+            &settings.work_days,
+            is_public_holiday,
+        ) == payday
+    })?;
+    let month = nominal[index].month();
+    let first_in_month = index == 0 || nominal[index - 1].month() != month;
+    first_in_month.then_some(month)
+}
+
+/// Get pay period (start inclusive, end exclusive).
+///
+/// `payday_of(year, month)`는 해당 월의 실제 월급날. 보정으로 월 경계를 넘을 수 있어
+/// 앞뒤 몇 달의 월급날 중 오늘 이전 가장 최근 날을 시작으로 잡는다.
+fn get_pay_period(
+    today: NaiveDate,
+    payday_of: impl Fn(i32, u32) -> NaiveDate,
+) -> (NaiveDate, NaiveDate) {
+    let month_index = today.year() * 12 + today.month0() as i32;
+    let paydays: Vec<NaiveDate> = (-2..=2)
+        .map(|offset| {
+            let index = month_index + offset;
+            payday_of(index.div_euclid(12), index.rem_euclid(12) as u32 + 1)
+        })
+        .collect();
+    period_containing(today, &paydays)
+}
+
+/// 오름차순 지급일 목록에서 `today`가 속한 (시작, 끝) 구간
+fn period_containing(today: NaiveDate, paydays: &[NaiveDate]) -> (NaiveDate, NaiveDate) {
+    let current = paydays.iter().rposition(|d| *d <= today).unwrap_or(0);
+    (paydays[current], paydays[current + 1])
+}
+
+/// Scheduled paid minutes of each work day in range [start, end).
+///
+/// 공휴일과 workday 캐시의 휴무(`DayOff`)·공휴일은 빼서, 쉬는 날이 있어도 기간 월급 합계는 그대로다.
+pub(super) fn get_work_minutes_in_period(
+    start: NaiveDate,
+    end: NaiveDate,
+    settings: &UserSettings,
+    ctx: &SalaryContext,
+) -> Vec<(NaiveDate, u32)> {
+    let is_day_off = |day| {
+        (ctx.is_public_holiday)(day)
+            || (ctx.workday)(day).is_some_and(|cache| {
+                cache.kind == crate::types::WorkdayKind::DayOff || is_public_holiday_cache(&cache)
+            })
+    };
+    start
+        .iter_days()
+        .take_while(|&day| day < end)
+        .filter(|&day| settings.work_days.contains(&weekday_index(day)) && !is_day_off(day))
+        .map(|day| (day, paid_minutes_on(settings, day)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::salary::tests::{
+        all_premiums, calculate_salary, history_cache, make_bonus, make_hourly_settings,
+        make_settings, no_holidays, with_workday, PLAIN,
+    };
+    use crate::types::WorkStatus;
+
+    #[test]
+    fn test_days_in_month() {
+        assert_eq!(days_in_month(2025, 1), 31);
+        assert_eq!(days_in_month(2025, 2), 28);
+        assert_eq!(days_in_month(2024, 2), 29); // leap year
+        assert_eq!(days_in_month(2025, 12), 31);
+    }
+
+    #[test]
+    fn test_pay_period_after_pay_day() {
+        // Feb 26, pay day 25 → period: Feb 25 ~ Mar 25
+        let today = NaiveDate::from_ymd_opt(2025, 2, 26).unwrap();
+        let (start, end) = get_pay_period(today, |y, m| nominal_payday(y, m, 25));
+        assert_eq!(start, NaiveDate::from_ymd_opt(2025, 2, 25).unwrap());
+        assert_eq!(end, NaiveDate::from_ymd_opt(2025, 3, 25).unwrap());
+    }
+
+    #[test]
+    fn test_pay_period_before_pay_day() {
+        // Feb 10, pay day 25 → period: Jan 25 ~ Feb 25
+        let today = NaiveDate::from_ymd_opt(2025, 2, 10).unwrap();
+        let (start, end) = get_pay_period(today, |y, m| nominal_payday(y, m, 25));
+        assert_eq!(start, NaiveDate::from_ymd_opt(2025, 1, 25).unwrap());
+        assert_eq!(end, NaiveDate::from_ymd_opt(2025, 2, 25).unwrap());
+    }
+
+    #[test]
+    fn test_pay_period_day_31_in_feb() {
+        // Pay day 31, in February → clamp to 28
+        let today = NaiveDate::from_ymd_opt(2025, 2, 10).unwrap();
+        let (start, end) = get_pay_period(today, |y, m| nominal_payday(y, m, 31));
+        assert_eq!(start, NaiveDate::from_ymd_opt(2025, 1, 31).unwrap());
+        assert_eq!(end, NaiveDate::from_ymd_opt(2025, 2, 28).unwrap());
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn cycle_settings(pay_cycle: PayCycle) -> UserSettings {
+        UserSettings {
+            pay_cycle,
+            // 금요일
+            pay_anchor_date: Some("2025-01-03".to_string()),
+            ..make_settings(3_000_000, 25)
+        }
+    }
+
+    #[test]
+    fn test_pay_period_weekly_and_bi_weekly() {
+        let weekly = cycle_settings(PayCycle::Weekly);
+        assert_eq!(
+            pay_period(&weekly, date(2025, 2, 12), &no_holidays),
+            Some((date(2025, 2, 7), date(2025, 2, 14)))
+        );
+        // 기준일 이전 날짜도 같은 간격
+        assert_eq!(
+            pay_period(&weekly, date(2024, 12, 30), &no_holidays),
+            Some((date(2024, 12, 27), date(2025, 1, 3)))
+        );
+
+        let bi_weekly = cycle_settings(PayCycle::BiWeekly);
+        assert_eq!(
+            pay_period(&bi_weekly, date(2025, 2, 12), &no_holidays),
+            Some((date(2025, 1, 31), date(2025, 2, 14)))
+        );
+        assert_eq!(
+            pay_period(&bi_weekly, date(2025, 2, 14), &no_holidays),
+            Some((date(2025, 2, 14), date(2025, 2, 28)))
+        );
+
+        // 기준 지급일이 없으면 기간을 정하지 않는다 (저장할 때 검증)
+        let no_anchor = UserSettings {
+            pay_anchor_date: None,
+            ..cycle_settings(PayCycle::Weekly)
+        };
+        assert_eq!(
+            pay_period(&no_anchor, date(2025, 2, 12), &no_holidays),
+            None
+        );
+        let now = date(2025, 2, 12).and_hms_opt(12, 0, 0).unwrap();
+        assert!(calculate_salary(&no_anchor, None, None, now, &PLAIN).is_none());
+    }
+
+    #[test]
+    fn test_pay_period_semi_monthly() {
+        let settings = cycle_settings(PayCycle::SemiMonthly);
+        assert_eq!(
+            pay_period(&settings, date(2025, 2, 10), &no_holidays),
+            Some((date(2025, 1, 31), date(2025, 2, 15)))
+        );
+        assert_eq!(
+            pay_period(&settings, date(2025, 2, 20), &no_holidays),
+            Some((date(2025, 2, 15), date(2025, 2, 28)))
+        );
+
+        // 2025-03-15 = 토요일 → 03-14 (금)
+        let adjusted = UserSettings {
+            payday_adjustment: PaydayAdjustment::PreviousBusinessDay,
+            ..settings
+        };
+        assert_eq!(
+            pay_period(&adjusted, date(2025, 3, 14), &no_holidays),
+            Some((date(2025, 3, 14), date(2025, 3, 31)))
+        );
+    }
+
+    #[test]
+    fn test_weekly_cycle_pays_monthly_salary_per_week() {
+        let settings = cycle_settings(PayCycle::Weekly);
+        // 02-13(목) 퇴근 후 = 02-07 ~ 02-14 기간 마지막 근무일
+        let eve = date(2025, 2, 13).and_hms_opt(19, 0, 0).unwrap();
+        let result = calculate_salary(&settings, None, None, eve, &PLAIN).unwrap();
+        // 3,000,000 × 12 / 52
+        assert_eq!(result.accumulated_earnings, 692_308);
+        assert_eq!(result.worked_days, 4);
+
+        // 금요일 지급일에 누적 초기화
+        let payday = date(2025, 2, 14).and_hms_opt(8, 0, 0).unwrap();
+        let result = calculate_salary(&settings, None, None, payday, &PLAIN).unwrap();
+        assert!(result.is_payday);
+        assert_eq!(result.accumulated_earnings, 0);
+    }
+
+    #[test]
+    fn test_semi_monthly_bonus_on_first_payday_of_month() {
+        let settings = UserSettings {
+            bonuses: vec![make_bonus(vec![2], BonusKind::Fixed, 100_000.0)],
+            ..cycle_settings(PayCycle::SemiMonthly)
+        };
+        let bonus_on = |day: u32| {
+            let now = date(2025, 2, day).and_hms_opt(8, 0, 0).unwrap();
+            calculate_salary(&settings, None, None, now, &PLAIN)
+                .unwrap()
+                .bonus_earnings
+        };
+        assert_eq!(bonus_on(14), 0.0);
+        assert_eq!(bonus_on(17), 0.0);
+        let mid_month = date(2025, 2, 15).and_hms_opt(8, 0, 0).unwrap();
+        let result = calculate_salary(&settings, None, None, mid_month, &PLAIN).unwrap();
+        assert_eq!(result.bonus_earnings, 100_000.0);
+        let month_end = date(2025, 2, 28).and_hms_opt(8, 0, 0).unwrap();
+        let result = calculate_salary(&settings, None, None, month_end, &PLAIN).unwrap();
+        assert_eq!(result.bonus_earnings, 0.0);
+    }
+
+    fn weekdays() -> Vec<u8> {
+        vec![1, 2, 3, 4, 5]
+    }
+
+    #[test]
+    fn test_adjust_payday_moves_off_weekend() {
+        // 2025-05-25 = 일요일
+        let sunday = NaiveDate::from_ymd_opt(2025, 5, 25).unwrap();
+        let previous = adjust_payday(
+            sunday,
+            &PaydayAdjustment::PreviousBusinessDay,
+            &weekdays(),
+            &no_holidays,
+        );
+        let next = adjust_payday(
+            sunday,
+            &PaydayAdjustment::NextBusinessDay,
+            &weekdays(),
+            &no_holidays,
+        );
+        let none = adjust_payday(sunday, &PaydayAdjustment::None, &weekdays(), &no_holidays);
+        assert_eq!(previous, NaiveDate::from_ymd_opt(2025, 5, 23).unwrap());
+        assert_eq!(next, NaiveDate::from_ymd_opt(2025, 5, 26).unwrap());
+        assert_eq!(none, sunday);
+    }
+
+    #[test]
+    fn test_adjust_payday_skips_public_holiday() {
+        // 2025-10-06 추석 (월) → 직전 영업일은 금요일 10-03도 개천절이라 10-02
+        let holidays = [
+            NaiveDate::from_ymd_opt(2025, 10, 3).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 10, 6).unwrap(),
+        ];
+        let is_holiday = |d: NaiveDate| holidays.contains(&d);
+        let adjusted = adjust_payday(
+            NaiveDate::from_ymd_opt(2025, 10, 6).unwrap(),
+            &PaydayAdjustment::PreviousBusinessDay,
+            &weekdays(),
+            &is_holiday,
+        );
+        assert_eq!(adjusted, NaiveDate::from_ymd_opt(2025, 10, 2).unwrap());
+    }
+
+    #[test]
+    fn test_pay_period_starts_on_adjusted_payday_across_months() {
+        // 월급날 1일, 2025-02-01·03-01 = 토요일 → 01-31·02-28 (금)
+        let payday_of = |y, m| {
+            adjust_payday(
+                nominal_payday(y, m, 1),
+                &PaydayAdjustment::PreviousBusinessDay,
+                &weekdays(),
+                &no_holidays,
+            )
+        };
+        let (start, end) = get_pay_period(NaiveDate::from_ymd_opt(2025, 2, 28).unwrap(), payday_of);
+        assert_eq!(start, NaiveDate::from_ymd_opt(2025, 2, 28).unwrap());
+        assert_eq!(end, NaiveDate::from_ymd_opt(2025, 4, 1).unwrap());
+
+        let (start, end) = get_pay_period(NaiveDate::from_ymd_opt(2025, 2, 27).unwrap(), payday_of);
+        assert_eq!(start, NaiveDate::from_ymd_opt(2025, 1, 31).unwrap());
+        assert_eq!(end, NaiveDate::from_ymd_opt(2025, 2, 28).unwrap());
+    }
+
+    #[test]
+    fn test_adjusted_payday_resets_accumulated_earnings() {
+        // 월급날 25일, 2025-05-25 = 일요일 → 05-23 (금)부터 새 기간
+        let settings = UserSettings {
+            payday_adjustment: PaydayAdjustment::PreviousBusinessDay,
+            ..make_settings(3_000_000, 25)
+        };
+        let friday = NaiveDate::from_ymd_opt(2025, 5, 23)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, friday, &PLAIN).unwrap();
+        assert!(result.is_payday);
+        assert_eq!(result.worked_days, 0);
+        assert_eq!(result.accumulated_earnings, 0);
+
+        let unadjusted =
+            calculate_salary(&make_settings(3_000_000, 25), None, None, friday, &PLAIN).unwrap();
+        assert!(!unadjusted.is_payday);
+        assert!(unadjusted.worked_days > 0);
+    }
+
+    // -- Premium pay tests --
+
+    #[test]
+    fn test_premiums_disabled_by_default() {
+        let settings = make_hourly_settings(10_000);
+        let evening = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(21, 0, 0)
+            .unwrap();
+        let result =
+            calculate_salary(&settings, None, Some(("09:00", "20:00")), evening, &PLAIN).unwrap();
+        assert_eq!(result.premium_earnings, 0.0);
+        assert_eq!(result.today_earnings, result.base_earnings);
+    }
+
+    #[test]
+    fn test_daily_overtime_premium() {
+        // 09:00–20:00, 점심 1시간 → 실근로 10시간 → 2시간 연장
+        let settings = UserSettings {
+            premium_rules: all_premiums(),
+            ..make_hourly_settings(10_000)
+        };
+        let evening = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(21, 0, 0)
+            .unwrap();
+        let result =
+            calculate_salary(&settings, None, Some(("09:00", "20:00")), evening, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::Completed);
+        assert!((result.base_earnings - 12_000.0 * 10.0).abs() < 0.01);
+        assert!((result.premium_earnings - 10_000.0).abs() < 0.01);
+        assert!((result.today_earnings - 130_000.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_weekly_overtime_premium_after_40_hours() {
+        // 월~토 8시간 → 토요일은 주 40시간을 넘긴 전부 연장
+        let settings = UserSettings {
+            work_days: vec![1, 2, 3, 4, 5, 6],
+            premium_rules: all_premiums(),
+            ..make_hourly_settings(10_000)
+        };
+        let saturday_evening = NaiveDate::from_ymd_opt(2025, 2, 15)
+            .unwrap()
+            .and_hms_opt(19, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, saturday_evening, &PLAIN).unwrap();
+        assert!((result.premium_earnings - 0.5 * 8.0 * 10_000.0).abs() < 0.01);
+
+        let monday_evening = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(19, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, monday_evening, &PLAIN).unwrap();
+        assert_eq!(result.premium_earnings, 0.0);
+    }
+
+    #[test]
+    fn test_weekly_overtime_counts_recorded_hours_of_the_week() {
+        use crate::types::WorkdayKind;
+        // 월~토 8시간. 주중 기록에 따라 토요일 연장 시작점이 달라진다
+        let settings = UserSettings {
+            work_days: vec![1, 2, 3, 4, 5, 6],
+            premium_rules: all_premiums(),
+            ..make_hourly_settings(10_000)
+        };
+        let saturday_evening = NaiveDate::from_ymd_opt(2025, 2, 15)
+            .unwrap()
+            .and_hms_opt(19, 0, 0)
+            .unwrap();
+        let saturday_with = |date: &'static str, kind: WorkdayKind| {
+            let recorded = move |day: NaiveDate| {
+                (day.to_string() == date).then(|| history_cache(date, kind.clone(), None))
+            };
+            calculate_salary(
+                &settings,
+                None,
+                None,
+                saturday_evening,
+                &with_workday(&recorded),
+            )
+            .unwrap()
+            .premium_earnings
+        };
+
+        // 수요일 연차 → 앞선 실근로 32시간, 토요일 8시간은 연장 아님
+        assert_eq!(saturday_with("2025-02-12", WorkdayKind::AnnualLeave), 0.0);
+        // 화요일 오전 반차 → 36시간, 토요일 4시간부터 연장
+        let premium = saturday_with("2025-02-11", WorkdayKind::MorningHalfLeave);
+        assert!((premium - 0.5 * 4.0 * 10_000.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_night_premium_for_overnight_shift() {
+        let mut rules = crate::types::PremiumRules::default();
+        rules.night.enabled = true;
+        let settings = UserSettings {
+            work_start_time: "22:00".to_string(),
+            work_end_time: "06:00".to_string(),
+            break_times: vec![],
+            premium_rules: rules,
+            ..make_hourly_settings(10_000)
+        };
+
+        // 월요일 22:00 시작, 화요일 00:00 → 2시간 모두 야간
+        let midnight = NaiveDate::from_ymd_opt(2025, 2, 11)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, midnight, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::Working);
+        assert!((result.premium_earnings - 0.5 * 2.0 * 10_000.0).abs() < 0.01);
+
+        // 06:00 퇴근 이후 → 8시간 모두 야간
+        let morning = NaiveDate::from_ymd_opt(2025, 2, 11)
+            .unwrap()
+            .and_hms_opt(7, 0, 0)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, morning, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::Completed);
+        assert!((result.premium_earnings - 0.5 * 8.0 * 10_000.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_holiday_premium_replaces_overtime() {
+        // 일요일 09:00–19:00, 점심 1시간 → 9시간 = 휴일 8시간(50%) + 초과 1시간(100%)
+        let settings = UserSettings {
+            premium_rules: all_premiums(),
+            ..make_hourly_settings(10_000)
+        };
+        let sunday_evening = NaiveDate::from_ymd_opt(2025, 2, 16)
+            .unwrap()
+            .and_hms_opt(20, 0, 0)
+            .unwrap();
+        let result = calculate_salary(
+            &settings,
+            None,
+            Some(("09:00", "19:00")),
+            sunday_evening,
+            &PLAIN,
+        )
+        .unwrap();
+        let expected = 0.5 * 8.0 * 10_000.0 + 1.0 * 10_000.0;
+        assert!((result.premium_earnings - expected).abs() < 0.01);
+
+        // 공휴일 근무도 휴일 가산
+        let monday_evening = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(20, 0, 0)
+            .unwrap();
+        let result = calculate_salary(
+            &settings,
+            Some(TodayWorkStatus::HolidayWork),
+            None,
+            monday_evening,
+            &PLAIN,
+        )
+        .unwrap();
+        assert!((result.premium_earnings - 0.5 * 8.0 * 10_000.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_monthly_overtime_pays_extra_hours_at_ordinary_wage() {
+        // 09:00–18:00 (휴게 없음) 9시간 소정, 오늘 09:00–20:00 → 2시간 추가, 8시간 초과 3시간 연장
+        let mut rules = crate::types::PremiumRules::default();
+        rules.overtime.enabled = true;
+        let settings = UserSettings {
+            premium_rules: rules,
+            ..make_settings(3_000_000, 25)
+        };
+        let monday_night = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(21, 0, 0)
+            .unwrap();
+        let plain = calculate_salary(
+            &make_settings(3_000_000, 25),
+            None,
+            None,
+            monday_night,
+            &PLAIN,
+        )
+        .unwrap();
+        let result = calculate_salary(
+            &settings,
+            None,
+            Some(("09:00", "20:00")),
+            monday_night,
+            &PLAIN,
+        )
+        .unwrap();
+
+        let ordinary = 3_000_000.0 / ((45.0 + 8.0) * WEEKS_PER_MONTH);
+        // 퇴근 후 금액은 원 단위로 확정
+        assert!((result.base_earnings - (plain.daily_rate as f64 + ordinary * 2.0)).abs() <= 0.5);
+        assert!((result.premium_earnings - ordinary * 0.5 * 3.0).abs() <= 0.5);
+    }
+}
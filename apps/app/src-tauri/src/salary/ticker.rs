@@ -0,0 +1,1146 @@
+//! 메뉴바 급여 ticker.
+//!
+//! 백그라운드 스레드가 설정·휴일·workday 캐시를 메모리에 두고 변경 알림(채널)을 받을 때만 다시 읽는다.
+//! 전체 계산은 근무 중 1분마다, 아니면 출근·자정에만 하고, 그 사이에는 분 구간 양 끝 값을
+//! 보간해 트레이 타이틀과 `salary-tick`을 매초 갱신한다.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, Timelike};
+use tauri::{AppHandle, Emitter};
+
+use crate::goals::GoalTracker;
+use crate::holidays::HolidayCalendar;
+use crate::money::{self, Minor};
+use crate::tray;
+use crate::types::{
+    JobProfile, MenubarDisplayMode, RoundingPolicy, UserSettings, WorkStatus, PRIMARY_JOB_ID,
+};
+
+use super::forecast::{
+    clear_settled_memo, earnings_since, forget_settled, hire_date, year_start, SettledMemo,
+    SETTLED_MEMO,
+};
+use super::{
+    calculate_job_salary, get_recovery_dir, is_non_working_status, is_public_holiday_cache,
+    load_settings, with_builtin_holiday, work_now, work_time, JobTick, SalaryContext,
+    SalaryTickPayload, WorkdayHistory,
+};
+
+// ============================================================================
+// Ticker messages
+// ============================================================================
+
+/// ticker 스레드로 보내는 변경 알림. ticker는 알림이나 예약한 시각에만 다시 계산한다.
+enum TickerMessage {
+    /// 설정·회사 휴무일 변경: 설정과 달력을 다시 읽는다
+    SettingsChanged,
+    /// workday 캐시 파일 변경: 날짜별 메모를 비운다
+    WorkdayChanged,
+    /// 목표 파일 변경: 목표를 다시 읽고 바로 다시 발행한다
+    GoalsChanged,
+    /// 창(label)이 salary-tick을 보기 시작하거나 그만둠
+    Watch { window: String, watching: bool },
+}
+
+type TickerChannel = (
+    Sender<TickerMessage>,
+    Mutex<Option<Receiver<TickerMessage>>>,
+);
+
+/// ticker 시작 전에 보낸 알림도 큐에 남는다. 수신 측은 `start_salary_ticker`가 가져간다.
+static TICKER_CHANNEL: LazyLock<TickerChannel> = LazyLock::new(|| {
+    let (sender, receiver) = mpsc::channel();
+    (sender, Mutex::new(Some(receiver)))
+});
+
+fn send_to_ticker(message: TickerMessage) {
+    // 수신 측은 앱이 끝날 때까지 살아 있으므로 실패는 무시
+    let _ = TICKER_CHANNEL.0.send(message);
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn notify_settings_changed() {
+    clear_settled_memo();
+    send_to_ticker(TickerMessage::SettingsChanged);
+    log::debug!("설정 변경 알림 수신");
+}
+
+/// `date`(YYYY-MM-DD)의 workday 캐시 파일이 바뀌었음을 알림. 지난 날짜 메모를 비우고 누적액을 다시 계산한다.
+pub fn invalidate_workday_history(date: &str) {
+    match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        Ok(date) => SETTLED_MEMO
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values_mut()
+            .for_each(|memo| forget_settled(memo, date)),
+        Err(_) => clear_settled_memo(),
+    }
+    send_to_ticker(TickerMessage::WorkdayChanged);
+}
+
+/// 목표 파일이 바뀌었음을 알림. ticker가 목표를 다시 읽고, 근무 중이 아니어도 바로 진행률을 다시 발행한다.
+pub fn notify_goals_changed() {
+    send_to_ticker(TickerMessage::GoalsChanged);
+}
+
+/// 창이 salary-tick을 보고 있는지 알림 (화면에 보일 때 true, 가려지거나 닫힐 때 false).
+///
+/// 근무 중 매초 발행은 보는 창이 있거나 메뉴바 타이틀이 매초 바뀔 때만 한다.
+#[tauri::command]
+#[specta::specta]
+pub fn watch_salary_tick(window: tauri::Window, watching: bool) {
+    send_to_ticker(TickerMessage::Watch {
+        window: window.label().to_string(),
+        watching,
+    });
+}
+
+// ============================================================================
+// Ticker
+// ============================================================================
+
+/// 서버 sync polling 간격 (5분)
+const SYNC_INTERVAL_SECS: u64 = 300;
+
+/// 알림도 예약도 없을 때 최대 대기 시간. 잠자기로 벽시계가 건너뛰어도 이 간격 안에 예약 시각을 확인한다
+const MAX_IDLE_WAIT: Duration = Duration::from_secs(60);
+
+/// 구간 끝 표본의 분 시작 후 초
+const SEGMENT_END_SECONDS: u32 = 59;
+
+pub fn start_salary_ticker(app_handle: AppHandle) {
+    let Some(receiver) = TICKER_CHANNEL
+        .1
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+    else {
+        log::warn!("급여 타이머가 이미 실행 중입니다");
+        return;
+    };
+
+    // 주기적 서버 sync 스레드. 동시성은 sync_from_server 내부 RAII guard가 담당.
+    {
+        let app = app_handle.clone();
+        std::thread::spawn(move || {
+            // 앱 시작 후 10초 대기 (초기화 완료 대기)
+            std::thread::sleep(Duration::from_secs(10));
+            loop {
+                let app_clone = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    let _ = crate::commands::auth::sync_from_server(app_clone).await;
+                });
+                std::thread::sleep(Duration::from_secs(SYNC_INTERVAL_SECS));
+            }
+        });
+    }
+
+    std::thread::spawn(move || {
+        let mut ticker = Ticker::new(app_handle);
+        ticker.tick();
+        loop {
+            match receiver.recv_timeout(ticker.wait()) {
+                Ok(message) => {
+                    ticker.handle(message);
+                    // 서버 sync처럼 한꺼번에 온 알림은 한 번만 다시 계산
+                    while let Ok(message) = receiver.try_recv() {
+                        ticker.handle(message);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+            ticker.tick();
+        }
+    });
+
+    log::info!("급여 타이머 시작");
+}
+
+/// 한 분 동안의 프로필 급여: 분 시작과 `SEGMENT_END_SECONDS`초 후 표본.
+///
+/// 근무 상태·휴게·야간·연장 구간은 분 단위로 바뀌므로 분 안에서 금액은 선형이다.
+struct JobSegment {
+    rounding: RoundingPolicy,
+    start: SalaryTickPayload,
+    end: SalaryTickPayload,
+}
+
+impl JobSegment {
+    /// 분 시작 후 `elapsed`초의 급여. 확정 누적액은 계산 경로와 같이 오늘 금액을 정수화해 더한다
+    fn at(&self, elapsed: u32) -> SalaryTickPayload {
+        let t = elapsed.min(SEGMENT_END_SECONDS) as f64 / SEGMENT_END_SECONDS as f64;
+        let lerp = |from: f64, to: f64| from + (to - from) * t;
+        let settled = self.start.accumulated_earnings
+            - money::from_f64(self.start.today_earnings, self.rounding);
+        let today_earnings = lerp(self.start.today_earnings, self.end.today_earnings);
+        SalaryTickPayload {
+            accumulated_earnings: settled + money::from_f64(today_earnings, self.rounding),
+            today_earnings,
+            base_earnings: lerp(self.start.base_earnings, self.end.base_earnings),
+            premium_earnings: lerp(self.start.premium_earnings, self.end.premium_earnings),
+            bonus_earnings: lerp(self.start.bonus_earnings, self.end.bonus_earnings),
+            worked_seconds: lerp(
+                self.start.worked_seconds as f64,
+                self.end.worked_seconds as f64,
+            )
+            .round() as u32,
+            seconds_until_clock_out: self
+                .start
+                .seconds_until_clock_out
+                .map(|seconds| seconds.saturating_sub(elapsed)),
+            seconds_until_clock_in: self
+                .start
+                .seconds_until_clock_in
+                .map(|seconds| seconds.saturating_sub(elapsed)),
+            ..self.start.clone()
+        }
+    }
+}
+
+/// 마지막 전체 계산 결과. 다음 전체 계산(`next_change`)까지는 이 값으로만 발행한다.
+struct TickSegment {
+    /// 분 시작 (근무 시간대)
+    start: DateTime<FixedOffset>,
+    /// 다음 전체 계산 시각: 근무 중이면 다음 분, 아니면 출근·자정 중 이른 시각
+    next_change: DateTime<FixedOffset>,
+    primary: JobSegment,
+    jobs: Vec<(JobProfile, JobSegment)>,
+    /// 근무·휴게 중인 프로필이 있는지
+    working: bool,
+    /// 올해·입사 이후 누적 모드: (합산할 프로필 id, 지난 기간 확정액 보정)
+    total_since: Option<(Vec<String>, Minor)>,
+}
+
+impl TickSegment {
+    fn payload_at(&self, now: DateTime<FixedOffset>) -> SalaryTickPayload {
+        let elapsed = (now - self.start).num_seconds().max(0) as u32;
+        let jobs = self
+            .jobs
+            .iter()
+            .map(|(job, segment)| (job, segment.at(elapsed)))
+            .collect();
+        combine_jobs(self.primary.at(elapsed), jobs)
+    }
+
+    fn total_since(&self, payload: &SalaryTickPayload) -> Option<Minor> {
+        self.total_since.as_ref().map(|(ids, settled)| {
+            payload
+                .jobs
+                .iter()
+                .filter(|job| ids.contains(&job.id))
+                .map(|job| job.accumulated_earnings)
+                .sum::<Minor>()
+                + settled
+        })
+    }
+}
+
+/// 본업과 추가 프로필을 `now`가 속한 1분 구간 양 끝에서 계산한다.
+/// 추가 프로필은 계산되는 것만 남기고, 본업이 계산되지 않으면 None
+fn build_segment(
+    s: &UserSettings,
+    recovery_dir: &Path,
+    holidays: &HolidayCalendar,
+    history: &WorkdayHistory,
+    settled_memo: &mut HashMap<String, SettledMemo>,
+    now: DateTime<FixedOffset>,
+) -> Option<TickSegment> {
+    let start = now.with_second(0)?.with_nanosecond(0)?;
+    let end = start + chrono::Duration::seconds(SEGMENT_END_SECONDS as i64);
+
+    let workday_on =
+        |dir: &Path, date| with_builtin_holiday(holidays, date, history.get(dir, date));
+    let is_public_holiday =
+        |date| workday_on(recovery_dir, date).is_some_and(|cache| is_public_holiday_cache(&cache));
+    let segment = |settings: &UserSettings, dir: &Path| {
+        let workday = |date| workday_on(dir, date);
+        let ctx = SalaryContext {
+            is_public_holiday: &is_public_holiday,
+            workday: &workday,
+        };
+        let sample = |at| calculate_job_salary(settings, at, &ctx);
+        Some(JobSegment {
+            rounding: settings.rounding_policy,
+            start: sample(start)?,
+            end: sample(end)?,
+        })
+    };
+    let primary = segment(s, recovery_dir)?;
+    let jobs: Vec<(JobProfile, JobSegment)> = s
+        .job_profiles
+        .iter()
+        .filter_map(|job| {
+            let job_dir = recovery_dir.join("jobs").join(&job.id);
+            Some((job.clone(), segment(&s.for_job(job), &job_dir)?))
+        })
+        .collect();
+
+    let all_jobs = || std::iter::once(&primary).chain(jobs.iter().map(|(_, job)| job));
+    let working = all_jobs().any(|job| {
+        matches!(
+            job.start.work_status,
+            WorkStatus::Working | WorkStatus::OnBreak
+        )
+    });
+    let today = now.date_naive();
+    let midnight = work_time(
+        s,
+        (today + chrono::Days::new(1)).and_time(chrono::NaiveTime::MIN),
+    );
+    let next_change = if working {
+        start + chrono::Duration::minutes(1)
+    } else {
+        all_jobs()
+            .filter_map(|job| job.start.seconds_until_clock_in)
+            .map(|seconds| start + chrono::Duration::seconds(seconds as i64))
+            .fold(midnight, DateTime::min)
+    };
+
+    let mut segment = TickSegment {
+        start,
+        next_change,
+        primary,
+        jobs,
+        working,
+        total_since: None,
+    };
+    if let Some(from) = totals_since(s, today) {
+        let payload = segment.payload_at(start);
+        let profiles = title_profiles(s, recovery_dir, &payload);
+        let settled = profiles
+            .iter()
+            .map(|(id, profile, workday_dir)| {
+                let accumulated = payload
+                    .jobs
+                    .iter()
+                    .find(|job| job.id == *id)
+                    .map_or(0, |job| job.accumulated_earnings);
+                earnings_since(
+                    profile,
+                    from,
+                    accumulated,
+                    today,
+                    &SalaryContext {
+                        is_public_holiday: &is_public_holiday,
+                        workday: &|date| workday_on(workday_dir, date),
+                    },
+                    settled_memo.entry(id.clone()).or_default(),
+                ) - accumulated
+            })
+            .sum();
+        let ids = profiles.into_iter().map(|(id, _, _)| id).collect();
+        segment.total_since = Some((ids, settled));
+    }
+    Some(segment)
+}
+
+/// ticker 스레드 상태. 설정·달력·workday 캐시는 알림을 받을 때만 다시 읽는다.
+struct Ticker {
+    app: AppHandle,
+    settings: Option<UserSettings>,
+    holidays: HolidayCalendar,
+    history: WorkdayHistory,
+    history_date: Option<NaiveDate>,
+    goal_tracker: GoalTracker,
+    /// salary-tick을 보고 있는 창 label
+    watchers: HashSet<String>,
+    segment: Option<TickSegment>,
+    /// 알림을 받아 다음 tick에 발행해야 하는지
+    pending_emit: bool,
+    prev_title: Option<String>,
+    prev_work_status: Option<WorkStatus>,
+}
+
+impl Ticker {
+    fn new(app: AppHandle) -> Ticker {
+        Ticker {
+            settings: load_settings(&app),
+            holidays: HolidayCalendar::load(&app),
+            history: WorkdayHistory::default(),
+            history_date: None,
+            goal_tracker: GoalTracker::load(&app),
+            watchers: HashSet::new(),
+            segment: None,
+            pending_emit: true,
+            prev_title: None,
+            prev_work_status: None,
+            app,
+        }
+    }
+
+    fn handle(&mut self, message: TickerMessage) {
+        match message {
+            TickerMessage::SettingsChanged => {
+                self.settings = load_settings(&self.app);
+                self.holidays = HolidayCalendar::load(&self.app);
+                if let Some(ref s) = self.settings {
+                    tray::update_menu_check_states(s);
+                    tray::refresh_icon_theme(&self.app, s);
+                }
+                self.invalidate();
+            }
+            TickerMessage::WorkdayChanged => self.invalidate(),
+            TickerMessage::GoalsChanged => self.goal_tracker.reload(&self.app),
+            TickerMessage::Watch { window, watching } => {
+                if watching {
+                    self.watchers.insert(window);
+                } else {
+                    self.watchers.remove(&window);
+                }
+            }
+        }
+        self.pending_emit = true;
+    }
+
+    fn invalidate(&mut self) {
+        self.history.clear();
+        self.segment = None;
+    }
+
+    fn active_settings(&self) -> Option<&UserSettings> {
+        self.settings.as_ref().filter(|s| s.onboarding_completed)
+    }
+
+    /// 매초 발행할지: 근무 중이고 보는 창이 있거나 메뉴바 타이틀이 매초 바뀔 때
+    fn is_live(&self, settings: &UserSettings) -> bool {
+        self.segment.as_ref().is_some_and(|segment| segment.working)
+            && (!self.watchers.is_empty() || title_ticks(settings))
+    }
+
+    /// 다음 tick까지 대기 시간
+    fn wait(&self) -> Duration {
+        let Some(s) = self.active_settings() else {
+            return MAX_IDLE_WAIT;
+        };
+        let Some(ref segment) = self.segment else {
+            return MAX_IDLE_WAIT;
+        };
+        let now = work_now(s);
+        if self.is_live(s) {
+            // 다음 정각 초
+            return Duration::from_nanos(1_000_000_000 - (now.nanosecond() % 1_000_000_000) as u64);
+        }
+        (segment.next_change - now)
+            .to_std()
+            .unwrap_or(Duration::ZERO)
+            .min(MAX_IDLE_WAIT)
+    }
+
+    fn tick(&mut self) {
+        let Some(s) = self.active_settings() else {
+            self.segment = None;
+            return;
+        };
+        // 시스템 시간대가 아닌 근무 시간대 기준 (출장·원격 근무)
+        let now = work_now(s);
+        let is_due = self
+            .segment
+            .as_ref()
+            .is_none_or(|segment| now >= segment.next_change || now < segment.start);
+        if !is_due && !self.pending_emit && !self.is_live(s) {
+            return;
+        }
+        if is_due {
+            // 날짜가 바뀌면 어제 파일이 지난 날짜로 편입되므로 메모 초기화
+            if self.history_date != Some(now.date_naive()) {
+                self.history.clear();
+                self.history_date = Some(now.date_naive());
+            }
+            self.segment = self.build_segment(now);
+        }
+        self.pending_emit = false;
+        self.emit(now);
+    }
+
+    /// 이번 분의 전체 계산. 급여 기간·지난 날짜 확정액은 여기서만 계산한다
+    fn build_segment(&mut self, now: DateTime<FixedOffset>) -> Option<TickSegment> {
+        let s = self.settings.as_ref()?;
+        build_segment(
+            s,
+            &get_recovery_dir(&self.app),
+            &self.holidays,
+            &self.history,
+            &mut SETTLED_MEMO.lock().unwrap_or_else(|e| e.into_inner()),
+            now,
+        )
+    }
+
+    /// 메뉴바 타이틀·아이콘 갱신과 salary-tick 발행. 계산 없이 구간 값만 보간한다
+    fn emit(&mut self, now: DateTime<FixedOffset>) {
+        let (Some(s), Some(segment)) = (self.settings.as_ref(), self.segment.as_ref()) else {
+            return;
+        };
+        let mut payload = segment.payload_at(now);
+        payload.company_holiday = self.holidays.company_holiday(now.date_naive());
+        payload.goals = self.goal_tracker.tick(
+            &self.app,
+            payload.accumulated_earnings,
+            payload.today_earnings,
+            now.date_naive(),
+            s.currency,
+        );
+
+        // Update tray title
+        let title_source = tray_source(&payload, s.menubar_job_id.as_deref());
+        let new_title = Some(tray_title(&title_source, segment.total_since(&payload), s));
+
+        if new_title != self.prev_title {
+            #[cfg(target_os = "macos")]
+            if let Some(tray_icon) = self.app.tray_by_id("tray") {
+                let is_completed = title_source.work_status == WorkStatus::Completed;
+                if let Err(e) =
+                    tray::set_tray_attributed_title(&tray_icon, new_title.as_deref(), is_completed)
+                {
+                    log::warn!("트레이 타이틀 설정 실패: {e}");
+                }
+            }
+            self.prev_title = new_title;
+        }
+
+        // Update tray icon state
+        if self.prev_work_status.as_ref() != Some(&payload.work_status) {
+            tray::update_icon_state(&self.app, &payload.work_status);
+            self.prev_work_status = Some(payload.work_status.clone());
+        }
+
+        // Emit event to frontend
+        let _ = self.app.emit("salary-tick", &payload);
+    }
+}
+
+/// 근무 중 메뉴바 타이틀이 매초 바뀌는 표시 모드인지 (타이틀은 macOS에서만 보인다)
+fn title_ticks(settings: &UserSettings) -> bool {
+    cfg!(target_os = "macos")
+        && matches!(
+            settings.menubar_display_mode,
+            MenubarDisplayMode::Daily
+                | MenubarDisplayMode::Accumulated
+                | MenubarDisplayMode::Countdown
+                | MenubarDisplayMode::YearToDate
+                | MenubarDisplayMode::Lifetime
+        )
+}
+
+/// 본업 표시 이름 (`JobTick.name`)
+const PRIMARY_JOB_NAME: &str = "본업";
+
+/// 본업 + 추가 프로필 결과 합산.
+///
+/// 금액은 합계, 초당 금액은 근무 중인 프로필만 합산한다. 상태·시급은 본업 기준이되
+/// 본업이 근무 중이 아니고 다른 프로필이 근무 중이면 그 프로필을 따른다.
+fn combine_jobs(
+    primary: SalaryTickPayload,
+    jobs: Vec<(&JobProfile, SalaryTickPayload)>,
+) -> SalaryTickPayload {
+    let is_active =
+        |p: &SalaryTickPayload| matches!(p.work_status, WorkStatus::Working | WorkStatus::OnBreak);
+
+    let mut combined = primary.clone();
+    combined.jobs = vec![JobTick::from_payload(
+        PRIMARY_JOB_ID,
+        PRIMARY_JOB_NAME,
+        &primary,
+    )];
+    // 프로필이 본업뿐이면 기존 값 유지
+    if !jobs.is_empty() {
+        combined.per_second = std::iter::once(&primary)
+            .chain(jobs.iter().map(|(_, p)| p))
+            .filter(|p| p.work_status == WorkStatus::Working)
+            .map(|p| p.per_second)
+            .sum();
+    }
+
+    for (job, payload) in &jobs {
+        combined.daily_rate += payload.daily_rate;
+        combined.accumulated_earnings += payload.accumulated_earnings;
+        combined.today_earnings += payload.today_earnings;
+        combined.base_earnings += payload.base_earnings;
+        combined.premium_earnings += payload.premium_earnings;
+        combined.bonus_earnings += payload.bonus_earnings;
+        combined.leave_deduction += payload.leave_deduction;
+        combined.is_work_day |= payload.is_work_day;
+        combined.is_payday |= payload.is_payday;
+        if !is_active(&combined) && is_active(payload) {
+            combined.work_status = payload.work_status.clone();
+            combined.hourly_rate = payload.hourly_rate;
+            combined.seconds_until_clock_out = payload.seconds_until_clock_out;
+            combined.seconds_until_clock_in = None;
+        }
+        combined
+            .jobs
+            .push(JobTick::from_payload(&job.id, &job.name, payload));
+    }
+    combined
+}
+
+/// 메뉴바에 표시할 프로필 요약. 선택 프로필이 없거나 못 찾으면 합계.
+fn tray_source(payload: &SalaryTickPayload, menubar_job_id: Option<&str>) -> JobTick {
+    menubar_job_id
+        .and_then(|id| payload.jobs.iter().find(|job| job.id == id))
+        .cloned()
+        .unwrap_or_else(|| JobTick::from_payload("", "", payload))
+}
+
+/// `menubar_display_mode`에 따른 메뉴바 타이틀. 빈 문자열이면 아이콘만 표시.
+///
+/// 오늘 금액·시급 모드는 연차·휴무·공휴일에 비우고, 카운트다운 모드는 쉬는 날에도 월급날 D-day를 보여준다.
+/// `total_since`는 올해·입사 이후 누적 모드에서만 계산해 넘긴다 (입사일이 없으면 None → 비움).
+fn tray_title(source: &JobTick, total_since: Option<Minor>, settings: &UserSettings) -> String {
+    let payday_countdown = || {
+        if source.is_payday {
+            "월급날 D-day".to_string()
+        } else {
+            format!("월급날 D-{}", source.days_until_payday)
+        }
+    };
+    match settings.menubar_display_mode {
+        MenubarDisplayMode::None => String::new(),
+        MenubarDisplayMode::Daily
+        | MenubarDisplayMode::Accumulated
+        | MenubarDisplayMode::Hourly
+        | MenubarDisplayMode::PerMinute
+            if is_non_working_status(&source.work_status) =>
+        {
+            String::new()
+        }
+        MenubarDisplayMode::Daily => format_tray_title(source.today_earnings, settings),
+        MenubarDisplayMode::Accumulated => {
+            format_tray_title(source.accumulated_earnings as f64, settings)
+        }
+        MenubarDisplayMode::Countdown => match source.seconds_until_clock_out {
+            Some(seconds) => format!("퇴근까지 {}", format_countdown(seconds)),
+            None => payday_countdown(),
+        },
+        MenubarDisplayMode::Payday => payday_countdown(),
+        MenubarDisplayMode::Hourly => {
+            format!("시급{}", format_tray_title(source.hourly_rate, settings))
+        }
+        MenubarDisplayMode::PerMinute => {
+            format!(
+                "분당{}",
+                format_tray_title(source.hourly_rate / 60.0, settings)
+            )
+        }
+        MenubarDisplayMode::YearToDate | MenubarDisplayMode::Lifetime => total_since
+            .map(|total| format_tray_title(total as f64, settings))
+            .unwrap_or_default(),
+    }
+}
+
+/// 올해·입사 이후 누적 모드의 시작일. 다른 모드이거나 입사일이 없으면 None
+pub(super) fn totals_since(settings: &UserSettings, today: NaiveDate) -> Option<NaiveDate> {
+    match settings.menubar_display_mode {
+        MenubarDisplayMode::YearToDate => year_start(settings, today),
+        MenubarDisplayMode::Lifetime => hire_date(settings),
+        _ => None,
+    }
+}
+
+/// 메뉴바 누적 모드에 합산할 (프로필 id, 설정, workday 디렉터리). 선택 프로필이 없거나 못 찾으면 전체.
+fn title_profiles(
+    settings: &UserSettings,
+    recovery_dir: &Path,
+    payload: &SalaryTickPayload,
+) -> Vec<(String, UserSettings, PathBuf)> {
+    let selected = settings
+        .menubar_job_id
+        .as_deref()
+        .filter(|id| payload.jobs.iter().any(|job| job.id == *id));
+    std::iter::once((
+        PRIMARY_JOB_ID.to_string(),
+        settings.clone(),
+        recovery_dir.to_path_buf(),
+    ))
+    .chain(settings.job_profiles.iter().map(|job| {
+        (
+            job.id.clone(),
+            settings.for_job(job),
+            recovery_dir.join("jobs").join(&job.id),
+        )
+    }))
+    .filter(|(id, _, _)| selected.is_none_or(|selected| selected == id))
+    .collect()
+}
+
+/// 남은 초를 `H:MM:SS`로. 예: 5025 → "1:23:45"
+fn format_countdown(seconds: u32) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+/// Format amount (minor units) for the tray: thin space + 통화별 로캘 표기 (예: 1,234,567원).
+///
+/// `secondary_currency`가 있고 환율표로 환산되면 뒤에 `(≈ $912.34)`를 붙인다.
+fn format_tray_title(amount: f64, settings: &UserSettings) -> String {
+    let minor = amount.floor() as Minor;
+    let primary = money::format_amount(minor, settings.currency);
+    let secondary = settings
+        .secondary_currency
+        .filter(|&to| to != settings.currency)
+        .and_then(|to| {
+            money::convert(
+                minor,
+                settings.currency,
+                to,
+                &settings.exchange_rates,
+                settings.rounding_policy,
+            )
+            .map(|converted| money::format_amount(converted, to))
+        });
+    match secondary {
+        Some(secondary) => format!("\u{2009}{primary} (≈ {secondary})"),
+        None => format!("\u{2009}{primary}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::salary::tests::{
+        all_premiums, calculate_salary, history_cache, make_hourly_settings, make_settings,
+        make_temp_dir, no_history, with_workday, PLAIN,
+    };
+    use crate::types::{Currency, PayCycle, SalaryType};
+
+    #[test]
+    fn test_format_tray_title() {
+        let settings = UserSettings::default();
+        assert_eq!(
+            format_tray_title(1234567.89, &settings),
+            "\u{2009}1,234,567원"
+        );
+        assert_eq!(format_tray_title(0.0, &settings), "\u{2009}0원");
+        assert_eq!(format_tray_title(999.5, &settings), "\u{2009}999원");
+    }
+
+    #[test]
+    fn test_format_tray_title_with_currency_and_secondary() {
+        let usd = UserSettings {
+            currency: Currency::Usd,
+            ..Default::default()
+        };
+        assert_eq!(format_tray_title(123_456.7, &usd), "\u{2009}$1,234.56");
+
+        let with_secondary = UserSettings {
+            secondary_currency: Some(Currency::Usd),
+            exchange_rates: vec![crate::types::ExchangeRate {
+                from: Currency::Usd,
+                to: Currency::Krw,
+                rate: 1_400.0,
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            format_tray_title(14_000.0, &with_secondary),
+            "\u{2009}14,000원 (≈ $10.00)"
+        );
+        // 환율이 없으면 보조 통화 생략
+        let missing_rate = UserSettings {
+            secondary_currency: Some(Currency::Jpy),
+            ..with_secondary
+        };
+        assert_eq!(
+            format_tray_title(14_000.0, &missing_rate),
+            "\u{2009}14,000원"
+        );
+    }
+
+    #[test]
+    fn test_cached_public_holiday_lookup() {
+        use crate::types::{WorkdayCache, WorkdayKind};
+        let dir = make_temp_dir("cached-holiday");
+        let workday_dir = dir.join("workday");
+        std::fs::create_dir_all(&workday_dir).unwrap();
+        let cache = WorkdayCache {
+            date: "2025-10-06".into(),
+            kind: WorkdayKind::PublicHoliday,
+            clock_in_time: None,
+            clock_out_time: None,
+            completed: false,
+            events: vec![],
+            is_dirty: false,
+        };
+        std::fs::write(
+            workday_dir.join("2025-10-06.json"),
+            serde_json::to_string(&cache).unwrap(),
+        )
+        .unwrap();
+
+        let history = WorkdayHistory::default();
+        let is_holiday = |date| {
+            history
+                .get(&dir, date)
+                .is_some_and(|cache| is_public_holiday_cache(&cache))
+        };
+        assert!(is_holiday(NaiveDate::from_ymd_opt(2025, 10, 6).unwrap()));
+        assert!(!is_holiday(NaiveDate::from_ymd_opt(2025, 10, 7).unwrap()));
+
+        // 메모는 clear 전까지 파일 변경을 반영하지 않는다
+        std::fs::remove_file(workday_dir.join("2025-10-06.json")).unwrap();
+        assert!(is_holiday(NaiveDate::from_ymd_opt(2025, 10, 6).unwrap()));
+        history.clear();
+        assert!(!is_holiday(NaiveDate::from_ymd_opt(2025, 10, 6).unwrap()));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    // -- Multiple job profile tests --
+
+    fn weekend_gig() -> JobProfile {
+        JobProfile {
+            id: "weekend-cafe".to_string(),
+            name: "주말 카페".to_string(),
+            salary_type: SalaryType::Hourly,
+            salary_amount: 10_000,
+            pay_day: 10,
+            pay_cycle: PayCycle::default(),
+            pay_anchor_date: None,
+            work_days: vec![0, 6],
+            work_start_time: "10:00".to_string(),
+            work_end_time: "14:00".to_string(),
+            weekday_hours: vec![],
+            break_times: vec![],
+            rounding_policy: RoundingPolicy::default(),
+        }
+    }
+
+    /// ticker와 같은 경로(`build_segment` → `payload_at`)로 `now` 시점 payload 계산
+    fn calculate_all(
+        settings: &UserSettings,
+        recovery_dir: &Path,
+        now: chrono::NaiveDateTime,
+    ) -> SalaryTickPayload {
+        let now = work_time(settings, now);
+        build_segment(
+            settings,
+            recovery_dir,
+            &HolidayCalendar::bundled(),
+            &WorkdayHistory::default(),
+            &mut HashMap::new(),
+            now,
+        )
+        .unwrap()
+        .payload_at(now)
+    }
+
+    #[test]
+    fn test_single_job_payload_has_primary_breakdown() {
+        let dir = make_temp_dir("single-job");
+        let settings = make_settings(3_000_000, 25);
+        // 급여 기간(3/25~4/24)에 공휴일이 없는 날 — ticker는 내장 달력을 쓴다
+        let now = NaiveDate::from_ymd_opt(2025, 4, 10)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let alone = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        let payload = calculate_all(&settings, &dir, now);
+        assert_eq!(payload.jobs.len(), 1);
+        assert_eq!(payload.jobs[0].id, PRIMARY_JOB_ID);
+        assert_eq!(payload.per_second, alone.per_second);
+        assert_eq!(payload.today_earnings, alone.today_earnings);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_weekend_gig_adds_to_total_while_primary_is_off() {
+        let dir = make_temp_dir("weekend-gig");
+        let settings = UserSettings {
+            job_profiles: vec![weekend_gig()],
+            ..make_settings(3_000_000, 25)
+        };
+        // 2025-02-15 토요일 12:00 → 본업 휴무, 카페 2시간 근무
+        let saturday_noon = NaiveDate::from_ymd_opt(2025, 2, 15)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let payload = calculate_all(&settings, &dir, saturday_noon);
+
+        assert_eq!(payload.jobs.len(), 2);
+        assert_eq!(payload.jobs[0].work_status, WorkStatus::DayOff);
+        assert_eq!(payload.jobs[1].work_status, WorkStatus::Working);
+        assert_eq!(payload.work_status, WorkStatus::Working);
+        let gig = &payload.jobs[1];
+        assert_eq!(payload.today_earnings, gig.today_earnings);
+        assert_eq!(
+            payload.accumulated_earnings,
+            payload.jobs[0].accumulated_earnings + gig.accumulated_earnings
+        );
+        assert!(payload.per_second > 0.0);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_job_profile_uses_its_own_workday_override() {
+        use crate::types::{WorkdayCache, WorkdayKind};
+        let dir = make_temp_dir("job-override");
+        let job_workday_dir = dir.join("jobs").join("weekend-cafe").join("workday");
+        std::fs::create_dir_all(&job_workday_dir).unwrap();
+        let cache = WorkdayCache {
+            date: "2025-02-15".into(),
+            kind: WorkdayKind::DayOff,
+            clock_in_time: None,
+            clock_out_time: None,
+            completed: false,
+            events: vec![],
+            is_dirty: false,
+        };
+        std::fs::write(
+            job_workday_dir.join("2025-02-15.json"),
+            serde_json::to_string(&cache).unwrap(),
+        )
+        .unwrap();
+
+        let settings = UserSettings {
+            job_profiles: vec![weekend_gig()],
+            ..make_settings(3_000_000, 25)
+        };
+        let saturday_noon = NaiveDate::from_ymd_opt(2025, 2, 15)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let payload = calculate_all(&settings, &dir, saturday_noon);
+        assert_eq!(payload.jobs[1].work_status, WorkStatus::DayOff);
+        assert_eq!(payload.today_earnings, 0.0);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_tray_source_picks_selected_job_or_total() {
+        let dir = make_temp_dir("tray-source");
+        let settings = UserSettings {
+            job_profiles: vec![weekend_gig()],
+            ..make_settings(3_000_000, 25)
+        };
+        let saturday_noon = NaiveDate::from_ymd_opt(2025, 2, 15)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let payload = calculate_all(&settings, &dir, saturday_noon);
+
+        let total = tray_source(&payload, None);
+        assert_eq!(total.work_status, WorkStatus::Working);
+        assert_eq!(total.accumulated_earnings, payload.accumulated_earnings);
+
+        let primary = tray_source(&payload, Some(PRIMARY_JOB_ID));
+        assert_eq!(primary.work_status, WorkStatus::DayOff);
+        assert_eq!(primary.today_earnings, 0.0);
+
+        let missing = tray_source(&payload, Some("missing"));
+        assert_eq!(missing.today_earnings, payload.today_earnings);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    fn countdown_title(
+        mode: MenubarDisplayMode,
+        settings: &UserSettings,
+        today_override: Option<(&str, &str)>,
+        now: chrono::NaiveDateTime,
+    ) -> String {
+        let settings = UserSettings {
+            menubar_display_mode: mode,
+            ..settings.clone()
+        };
+        let payload = calculate_salary(&settings, None, today_override, now, &PLAIN).unwrap();
+        tray_title(&JobTick::from_payload("", "", &payload), None, &settings)
+    }
+
+    #[test]
+    fn test_countdown_title_until_clock_out_or_payday() {
+        let settings = make_settings(3_000_000, 25);
+        let at = |month: u32, day: u32, h: u32, m: u32, s: u32| {
+            NaiveDate::from_ymd_opt(2025, month, day)
+                .unwrap()
+                .and_hms_opt(h, m, s)
+                .unwrap()
+        };
+        let countdown = MenubarDisplayMode::Countdown;
+
+        assert_eq!(
+            countdown_title(countdown.clone(), &settings, None, at(2, 10, 16, 36, 15)),
+            "퇴근까지 1:23:45"
+        );
+        // 오늘 workday 캐시의 출퇴근 시각 기준
+        assert_eq!(
+            countdown_title(
+                countdown.clone(),
+                &settings,
+                Some(("09:00", "15:00")),
+                at(2, 10, 14, 0, 0)
+            ),
+            "퇴근까지 1:00:00"
+        );
+        // 퇴근 후·쉬는 날은 월급날 D-day
+        assert_eq!(
+            countdown_title(countdown.clone(), &settings, None, at(2, 10, 19, 0, 0)),
+            "월급날 D-15"
+        );
+        assert_eq!(
+            countdown_title(countdown.clone(), &settings, None, at(2, 15, 12, 0, 0)),
+            "월급날 D-10"
+        );
+        assert_eq!(
+            countdown_title(
+                MenubarDisplayMode::Payday,
+                &settings,
+                None,
+                at(2, 25, 12, 0, 0)
+            ),
+            "월급날 D-day"
+        );
+        // 금액 모드는 쉬는 날 비움
+        assert_eq!(
+            countdown_title(
+                MenubarDisplayMode::Daily,
+                &settings,
+                None,
+                at(2, 15, 12, 0, 0)
+            ),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_rate_titles() {
+        let settings = make_hourly_settings(10_030);
+        let now = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        // 주휴 포함 시급 12,036원, 분당 200.6원
+        assert_eq!(
+            countdown_title(MenubarDisplayMode::Hourly, &settings, None, now),
+            "시급\u{2009}12,036원"
+        );
+        assert_eq!(
+            countdown_title(MenubarDisplayMode::PerMinute, &settings, None, now),
+            "분당\u{2009}200원"
+        );
+    }
+
+    #[test]
+    fn test_lifetime_earnings_since_hire_date() {
+        let settings = make_settings(3_000_000, 25);
+        // 03-10(월) 출근 전, 기간 02-25 ~ 03-25 평일 20일 × 150,000원
+        let now = NaiveDate::from_ymd_opt(2025, 3, 10)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        let current = calculate_salary(&settings, None, None, now, &PLAIN).unwrap();
+        assert_eq!(current.accumulated_earnings, 1_350_000);
+        let since =
+            |from: NaiveDate, workday: &dyn Fn(NaiveDate) -> Option<crate::types::WorkdayCache>| {
+                earnings_since(
+                    &settings,
+                    from,
+                    current.accumulated_earnings,
+                    now.date(),
+                    &with_workday(workday),
+                    &mut SettledMemo::new(),
+                )
+            };
+
+        // 이번 기간 중 입사: 03-03 ~ 03-07 5일분만
+        let hired_this_period = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+        assert_eq!(since(hired_this_period, &no_history), 750_000);
+
+        // 지난 기간(01-25 ~ 02-25) 전체 + 01-02 ~ 01-24 일할분 + 이번 기간
+        let hired = NaiveDate::from_ymd_opt(2025, 1, 2).unwrap();
+        let lifetime = since(hired, &no_history);
+        assert!(lifetime > 3_000_000 + 1_350_000);
+
+        // 지난 기간의 휴무는 기간 월급 합계를 바꾸지 않고, 무급휴가는 공제된다
+        let past_day = |kind: crate::types::WorkdayKind| {
+            move |date: NaiveDate| {
+                (date == NaiveDate::from_ymd_opt(2025, 2, 3).unwrap())
+                    .then(|| history_cache("2025-02-03", kind.clone(), None))
+            }
+        };
+        assert_eq!(
+            since(hired, &past_day(crate::types::WorkdayKind::DayOff)),
+            lifetime
+        );
+        assert!(since(hired, &past_day(crate::types::WorkdayKind::UnpaidLeave)) < lifetime);
+    }
+
+    #[test]
+    fn test_countdown_title_for_overnight_shift() {
+        let settings = UserSettings {
+            work_start_time: "22:00".to_string(),
+            work_end_time: "06:00".to_string(),
+            ..make_settings(3_000_000, 25)
+        };
+        // 화 03:00 → 월요일 야간 근무 중
+        let now = NaiveDate::from_ymd_opt(2025, 2, 11)
+            .unwrap()
+            .and_hms_opt(3, 0, 0)
+            .unwrap();
+        assert_eq!(
+            countdown_title(MenubarDisplayMode::Countdown, &settings, None, now),
+            "퇴근까지 3:00:00"
+        );
+    }
+
+    // -- Ticker tests --
+
+    #[test]
+    fn test_seconds_until_clock_in_only_before_work() {
+        let settings = make_settings(3_000_000, 25);
+        let morning = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(8, 30, 15)
+            .unwrap();
+        let result = calculate_salary(&settings, None, None, morning, &PLAIN).unwrap();
+        assert_eq!(result.work_status, WorkStatus::BeforeWork);
+        assert_eq!(result.seconds_until_clock_in, Some(29 * 60 + 45));
+
+        let noon = morning.date().and_hms_opt(12, 0, 0).unwrap();
+        let result = calculate_salary(&settings, None, None, noon, &PLAIN).unwrap();
+        assert_eq!(result.seconds_until_clock_in, None);
+    }
+
+    #[test]
+    fn test_segment_interpolation_matches_full_calculation() {
+        // 22:00 분: 연장 + 야간 가산이 함께 붙는 구간
+        let settings = UserSettings {
+            premium_rules: all_premiums(),
+            ..make_hourly_settings(10_000)
+        };
+        let calculate =
+            |at| calculate_salary(&settings, None, Some(("13:00", "23:30")), at, &PLAIN).unwrap();
+        let start = NaiveDate::from_ymd_opt(2025, 2, 10)
+            .unwrap()
+            .and_hms_opt(22, 0, 0)
+            .unwrap();
+        let segment = JobSegment {
+            rounding: settings.rounding_policy,
+            start: calculate(start),
+            end: calculate(start + chrono::Duration::seconds(SEGMENT_END_SECONDS as i64)),
+        };
+        assert!(segment.start.premium_earnings > 0.0);
+        for elapsed in 0..=SEGMENT_END_SECONDS {
+            let exact = calculate(start + chrono::Duration::seconds(elapsed as i64));
+            let interpolated = segment.at(elapsed);
+            assert!((interpolated.today_earnings - exact.today_earnings).abs() < 1e-6);
+            assert!((interpolated.premium_earnings - exact.premium_earnings).abs() < 1e-6);
+            assert_eq!(
+                interpolated.accumulated_earnings,
+                exact.accumulated_earnings
+            );
+            assert_eq!(
+                interpolated.seconds_until_clock_out,
+                exact.seconds_until_clock_out
+            );
+            assert_eq!(interpolated.work_status, exact.work_status);
+        }
+    }
+}
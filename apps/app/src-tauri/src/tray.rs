@@ -111,6 +111,12 @@ struct MenuItems {
     none_item: CheckMenuItem<tauri::Wry>,
     daily_item: CheckMenuItem<tauri::Wry>,
     accumulated_item: CheckMenuItem<tauri::Wry>,
+    countdown_item: CheckMenuItem<tauri::Wry>,
+    payday_item: CheckMenuItem<tauri::Wry>,
+    hourly_item: CheckMenuItem<tauri::Wry>,
+    per_minute_item: CheckMenuItem<tauri::Wry>,
+    year_to_date_item: CheckMenuItem<tauri::Wry>,
+    lifetime_item: CheckMenuItem<tauri::Wry>,
     icon_light_item: CheckMenuItem<tauri::Wry>,
     icon_dark_item: CheckMenuItem<tauri::Wry>,
 }
@@ -152,12 +158,70 @@ pub fn create(app_handle: &AppHandle) -> tauri::Result<TrayIcon> {
         settings.menubar_display_mode == MenubarDisplayMode::Accumulated,
         None::<&str>,
     )?;
+    let countdown_item = CheckMenuItem::with_id(
+        app_handle,
+        "display_countdown",
+        "퇴근 카운트다운",
+        true,
+        settings.menubar_display_mode == MenubarDisplayMode::Countdown,
+        None::<&str>,
+    )?;
+    let payday_item = CheckMenuItem::with_id(
+        app_handle,
+        "display_payday",
+        "월급날 D-day",
+        true,
+        settings.menubar_display_mode == MenubarDisplayMode::Payday,
+        None::<&str>,
+    )?;
+    let hourly_item = CheckMenuItem::with_id(
+        app_handle,
+        "display_hourly",
+        "시급",
+        true,
+        settings.menubar_display_mode == MenubarDisplayMode::Hourly,
+        None::<&str>,
+    )?;
+    let per_minute_item = CheckMenuItem::with_id(
+        app_handle,
+        "display_per_minute",
+        "분당 금액",
+        true,
+        settings.menubar_display_mode == MenubarDisplayMode::PerMinute,
+        None::<&str>,
+    )?;
+    let year_to_date_item = CheckMenuItem::with_id(
+        app_handle,
+        "display_year_to_date",
+        "올해 누적",
+        true,
+        settings.menubar_display_mode == MenubarDisplayMode::YearToDate,
+        None::<&str>,
+    )?;
+    let lifetime_item = CheckMenuItem::with_id(
+        app_handle,
+        "display_lifetime",
+        "입사 이후 누적",
+        true,
+        settings.menubar_display_mode == MenubarDisplayMode::Lifetime,
+        None::<&str>,
+    )?;
 
     let display_submenu = Submenu::with_items(
         app_handle,
         "금액 표기",
         true,
-        &[&none_item, &daily_item, &accumulated_item],
+        &[
+            &none_item,
+            &daily_item,
+            &accumulated_item,
+            &countdown_item,
+            &payday_item,
+            &hourly_item,
+            &per_minute_item,
+            &year_to_date_item,
+            &lifetime_item,
+        ],
     )?;
 
     // 아이콘 테마 서브메뉴
@@ -190,6 +254,12 @@ pub fn create(app_handle: &AppHandle) -> tauri::Result<TrayIcon> {
         none_item: none_item.clone(),
         daily_item: daily_item.clone(),
         accumulated_item: accumulated_item.clone(),
+        countdown_item: countdown_item.clone(),
+        payday_item: payday_item.clone(),
+        hourly_item: hourly_item.clone(),
+        per_minute_item: per_minute_item.clone(),
+        year_to_date_item: year_to_date_item.clone(),
+        lifetime_item: lifetime_item.clone(),
         icon_light_item: icon_light_item.clone(),
         icon_dark_item: icon_dark_item.clone(),
     });
@@ -247,7 +317,15 @@ pub fn create(app_handle: &AppHandle) -> tauri::Result<TrayIcon> {
                 let _ = app.emit("open-salary-settings", ());
                 show_main_window(app);
             }
-            id @ ("display_none" | "display_daily" | "display_accumulated") => {
+            id @ ("display_none"
+            | "display_daily"
+            | "display_accumulated"
+            | "display_countdown"
+            | "display_payday"
+            | "display_hourly"
+            | "display_per_minute"
+            | "display_year_to_date"
+            | "display_lifetime") => {
                 handle_display_mode_change(app, id);
             }
             id @ ("icon_light" | "icon_dark") => {
@@ -351,6 +429,12 @@ fn handle_display_mode_change(app: &AppHandle, menu_id: &str) {
         "display_none" => MenubarDisplayMode::None,
         "display_daily" => MenubarDisplayMode::Daily,
         "display_accumulated" => MenubarDisplayMode::Accumulated,
+        "display_countdown" => MenubarDisplayMode::Countdown,
+        "display_payday" => MenubarDisplayMode::Payday,
+        "display_hourly" => MenubarDisplayMode::Hourly,
+        "display_per_minute" => MenubarDisplayMode::PerMinute,
+        "display_year_to_date" => MenubarDisplayMode::YearToDate,
+        "display_lifetime" => MenubarDisplayMode::Lifetime,
         _ => return,
     };
 
@@ -390,7 +474,7 @@ fn save_and_notify(app: &AppHandle, settings: &UserSettings, context: &str) {
         return;
     }
 
-    crate::salary::notify_settings_changed();
+    crate::salary::ticker::notify_settings_changed();
     let _ = app.emit("user-settings-changed", ());
     log::debug!("트레이 메뉴: {context}");
 }
@@ -408,6 +492,24 @@ pub fn update_menu_check_states(settings: &UserSettings) {
             let _ = items
                 .accumulated_item
                 .set_checked(settings.menubar_display_mode == MenubarDisplayMode::Accumulated);
+            let _ = items
+                .countdown_item
+                .set_checked(settings.menubar_display_mode == MenubarDisplayMode::Countdown);
+            let _ = items
+                .payday_item
+                .set_checked(settings.menubar_display_mode == MenubarDisplayMode::Payday);
+            let _ = items
+                .hourly_item
+                .set_checked(settings.menubar_display_mode == MenubarDisplayMode::Hourly);
+            let _ = items
+                .per_minute_item
+                .set_checked(settings.menubar_display_mode == MenubarDisplayMode::PerMinute);
+            let _ = items
+                .year_to_date_item
+                .set_checked(settings.menubar_display_mode == MenubarDisplayMode::YearToDate);
+            let _ = items
+                .lifetime_item
+                .set_checked(settings.menubar_display_mode == MenubarDisplayMode::Lifetime);
             let _ = items
                 .icon_light_item
                 .set_checked(settings.menubar_icon_theme == MenubarIconTheme::Light);
@@ -485,7 +587,10 @@ pub fn update_icon_state(app: &AppHandle, work_status: &WorkStatus) {
             log::debug!("트레이 아이콘: 근무 완료 (초록)");
         }
         WorkStatus::BeforeWork
+        | WorkStatus::OnBreak
         | WorkStatus::AnnualLeave
+        | WorkStatus::UnpaidLeave
+        | WorkStatus::SickLeave
         | WorkStatus::DayOff
         | WorkStatus::PublicHoliday => {
             ANIMATING.store(false, Ordering::SeqCst);
@@ -123,8 +123,14 @@ pub fn validate_theme(theme: &str) -> Result<(), String> {
 pub enum WorkStatus {
     BeforeWork,
     Working,
+    /// 휴게시간 (무급) — 금액 고정
+    OnBreak,
     Completed,
     AnnualLeave,
+    /// 무급휴가 — 일급 공제
+    UnpaidLeave,
+    /// 병가 — `sick_leave_pay_percent`만큼 지급
+    SickLeave,
     DayOff,
     PublicHoliday,
 }
@@ -142,6 +148,18 @@ pub enum WorkdayKind {
     AnnualLeave,
     DayOff,
     PublicHoliday,
+    /// 오전 반차 (소정근로시간 앞 절반 휴가)
+    MorningHalfLeave,
+    /// 오후 반차 (뒤 절반 휴가)
+    AfternoonHalfLeave,
+    /// 오전 반반차 (앞 1/4 휴가)
+    MorningQuarterLeave,
+    /// 오후 반반차 (뒤 1/4 휴가)
+    AfternoonQuarterLeave,
+    /// 무급휴가. 쉬는 날(`DayOff`)과 달리 월급에서 그날 일급만큼 공제
+    UnpaidLeave,
+    /// 병가. `UserSettings.sick_leave_pay_percent`만큼 지급하고 나머지는 공제
+    SickLeave,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
@@ -183,6 +201,100 @@ pub enum SalaryType {
     #[default]
     Monthly,
     Yearly,
+    /// 시급 — 실근로시간 × 시급 (+ 주 15시간 이상이면 주휴수당)
+    Hourly,
+}
+
+/// 월급날이 주말·공휴일일 때 보정 정책
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum PaydayAdjustment {
+    /// 보정 없음
+    #[default]
+    None,
+    /// 직전 영업일 지급 (국내 대부분)
+    PreviousBusinessDay,
+    /// 다음 영업일 지급
+    NextBusinessDay,
+}
+
+/// 급여 지급 주기
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum PayCycle {
+    /// 매월 `pay_day`
+    #[default]
+    Monthly,
+    /// 매월 15일·말일
+    SemiMonthly,
+    /// 2주마다 (`pay_anchor_date` 기준)
+    BiWeekly,
+    /// 매주 (`pay_anchor_date`의 요일)
+    Weekly,
+}
+
+impl PayCycle {
+    pub const fn periods_per_year(self) -> u32 {
+        match self {
+            PayCycle::Monthly => 12,
+            PayCycle::SemiMonthly => 24,
+            PayCycle::BiWeekly => 26,
+            PayCycle::Weekly => 52,
+        }
+    }
+}
+
+/// 급여 통화 (ISO 4217)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, Default, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Currency {
+    #[default]
+    Krw,
+    Usd,
+    Jpy,
+    Eur,
+}
+
+impl Currency {
+    /// 최소 단위 자릿수 (KRW·JPY 0, USD·EUR 2)
+    pub const fn minor_digits(self) -> u32 {
+        match self {
+            Currency::Krw | Currency::Jpy => 0,
+            Currency::Usd | Currency::Eur => 2,
+        }
+    }
+}
+
+/// 사용자가 관리하는 오프라인 환율. `from` 1단위 = `to` `rate`단위
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeRate {
+    pub from: Currency,
+    pub to: Currency,
+    pub rate: f64,
+}
+
+/// 금액을 최소 단위로 확정할 때 반올림 방식 (회사 정책)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RoundingPolicy {
+    /// 절사
+    Floor,
+    /// 사사오입
+    #[default]
+    HalfUp,
+    /// 오사오입 (은행가 반올림)
+    HalfEven,
+}
+
+/// 금액 표시 기준 (세전/세후)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum PayDisplay {
+    /// 세전 (4대보험·소득세 공제 전)
+    Gross,
+    /// 세후 실수령액
+    Net,
 }
 
 /// Menubar icon theme
@@ -207,28 +319,262 @@ pub enum MenubarDisplayMode {
     Daily,
     /// 누적 월급
     Accumulated,
+    /// 근무 중에는 퇴근까지 남은 시간, 그 외에는 월급날 D-day
+    Countdown,
+    /// 월급날 D-day
+    Payday,
+    /// 현재 시급
+    Hourly,
+    /// 현재 분당 금액
+    #[serde(rename = "per-minute")]
+    PerMinute,
+    /// 올해 누적
+    #[serde(rename = "year-to-date")]
+    YearToDate,
+    /// 입사일(`hire_date`) 이후 누적
+    Lifetime,
+}
+
+/// 무급 휴게시간 구간 (예: 점심 12:00–13:00)
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakWindow {
+    /// Break start time (HH:MM format)
+    pub start_time: String,
+    /// Break end time (HH:MM format)
+    pub end_time: String,
+}
+
+/// 특정 요일의 출퇴근 시간 (예: 금요일 09:00–16:00)
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WeekdayHours {
+    /// 0=Sunday, 1=Monday, ..., 6=Saturday
+    pub weekday: u8,
+    /// Work start time (HH:MM format)
+    pub start_time: String,
+    /// Work end time (HH:MM format)
+    pub end_time: String,
+}
+
+/// 가산수당 규칙 하나. `rate`는 통상시급 대비 가산율 (0.5 → 50% 가산)
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumRule {
+    pub enabled: bool,
+    pub rate: f64,
+}
+
+impl PremiumRule {
+    const fn disabled(rate: f64) -> Self {
+        Self {
+            enabled: false,
+            rate,
+        }
+    }
+}
+
+/// 근로기준법 제56조 가산수당 규칙.
+///
+/// 5인 미만 사업장은 적용 제외라 기본은 모두 꺼져 있고, 사용자가 규칙별로 켠다.
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PremiumRules {
+    /// 연장근로 (1일 8시간 또는 주 40시간 초과) — 50% 가산
+    pub overtime: PremiumRule,
+    /// 야간근로 (22:00–06:00) — 50% 가산, 연장·휴일과 중복 적용
+    pub night: PremiumRule,
+    /// 휴일근로 8시간 이내 — 50% 가산
+    pub holiday: PremiumRule,
+    /// 휴일근로 8시간 초과분 — 100% 가산
+    pub holiday_overtime: PremiumRule,
+}
+
+impl Default for PremiumRules {
+    fn default() -> Self {
+        Self {
+            overtime: PremiumRule::disabled(0.5),
+            night: PremiumRule::disabled(0.5),
+            holiday: PremiumRule::disabled(0.5),
+            holiday_overtime: PremiumRule::disabled(1.0),
+        }
+    }
+}
+
+/// 상여금 금액 산정 방식
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum BonusKind {
+    /// 정액 (`value` = 통화 최소 단위 금액)
+    #[default]
+    Fixed,
+    /// 기본 월급 대비 비율 (`value` = %, 100 → 월급 100%)
+    PercentOfBase,
+}
+
+/// 상여금 하나 (예: 설·추석 상여 100%, 2·9월)
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Bonus {
+    pub name: String,
+    /// 지급 월 (1–12). 해당 월 월급날에 지급
+    pub months: Vec<u8>,
+    #[serde(default)]
+    pub kind: BonusKind,
+    pub value: f64,
+}
+
+/// 상여금을 누적액에 반영하는 방식
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum BonusAccrual {
+    /// 지급 월 월급날에 한 번에 반영
+    #[default]
+    OnPayday,
+    /// 지급 월 월급날까지의 급여 기간 동안 근무일에 나눠 적립
+    Accrue,
+}
+
+/// 본업(`UserSettings` 최상위 급여 필드)을 가리키는 프로필 id
+pub const PRIMARY_JOB_ID: &str = "primary";
+
+/// 추가 근무 프로필 (부업 등). 세금·가산수당 규칙은 `UserSettings` 값을 공유
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JobProfile {
+    /// 프로필 id (영문·숫자·`-`·`_`). 프로필별 workday override 경로에 사용
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub salary_type: SalaryType,
+    pub salary_amount: u32,
+    pub pay_day: u8,
+    #[serde(default)]
+    pub pay_cycle: PayCycle,
+    #[serde(default)]
+    pub pay_anchor_date: Option<String>,
+    #[serde(default = "default_work_days")]
+    pub work_days: Vec<u8>,
+    #[serde(default = "default_work_start_time")]
+    pub work_start_time: String,
+    #[serde(default = "default_work_end_time")]
+    pub work_end_time: String,
+    #[serde(default)]
+    pub weekday_hours: Vec<WeekdayHours>,
+    #[serde(default)]
+    pub break_times: Vec<BreakWindow>,
+    #[serde(default)]
+    pub rounding_policy: RoundingPolicy,
+}
+
+/// 급여 이력 한 항목. `effective_from`부터 다음 항목 전날까지 적용
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SalaryVersion {
+    /// 적용 시작일 (YYYY-MM-DD)
+    pub effective_from: String,
+    #[serde(default)]
+    pub salary_type: SalaryType,
+    pub salary_amount: u32,
+    /// 지급 비율 (%). 수습 기간 90% 등, 기본 100
+    #[serde(default = "default_pay_percent")]
+    pub pay_percent: u8,
+    /// 메모 (예: "수습", "연봉 인상")
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+impl SalaryVersion {
+    /// 지급 비율을 반영한 금액
+    pub fn paid_amount(&self) -> u32 {
+        (self.salary_amount as u64 * self.pay_percent as u64 / 100) as u32
+    }
 }
 
 /// User settings for salary calculation (MVP)
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct UserSettings {
-    /// Salary type (monthly or yearly)
+    /// Salary type (monthly, yearly or hourly)
     #[serde(default)]
     pub salary_type: SalaryType,
-    /// Salary amount in KRW (monthly net salary or yearly salary)
+    /// Salary amount in `currency` minor units (monthly net salary, yearly salary or hourly wage).
+    /// KRW·JPY는 원·엔, USD·EUR는 센트
     pub salary_amount: u32,
-    /// Pay day of month (1-31, default: 25)
+    /// 급여 이력 (적용 시작일 순). 첫 항목 이전 날짜도 첫 항목 급여.
+    /// 이력이 있으면 최상위 `salary_type`·`salary_amount`는 오늘 적용 중인 항목과 같게 유지한다
+    #[serde(default)]
+    pub salary_history: Vec<SalaryVersion>,
+    /// 급여 통화. 세후 환산(4대보험·소득세)은 KRW에만 적용
+    #[serde(default)]
+    pub currency: Currency,
+    /// 메뉴바에 함께 표시할 환산 통화. `exchange_rates`에 환율이 없으면 표시 안 함
+    #[serde(default)]
+    pub secondary_currency: Option<Currency>,
+    /// 오프라인 환율표
+    #[serde(default)]
+    pub exchange_rates: Vec<ExchangeRate>,
+    /// 금액 표시 기준. 월급은 세후, 연봉·시급은 세전 입력으로 보고 필요 시 환산.
+    /// 없으면 입력한 금액 그대로 표시 (기존 설정 파일은 공제 전과 같은 금액)
+    #[serde(default)]
+    pub pay_display: Option<PayDisplay>,
+    /// 월 비과세 수당 (식대 등). 4대보험·소득세 산정 기준에서 제외
+    #[serde(default)]
+    pub tax_free_allowance: u32,
+    /// 본인 포함 공제대상 가족 수 (소득세 추정용, 기본 1)
+    #[serde(default = "default_dependents")]
+    pub dependents: u8,
+    /// Pay day of month (1-31, default: 25). `pay_cycle`이 월 단위일 때만 사용
     pub pay_day: u8,
+    /// 입사일 (YYYY-MM-DD). 입사 이후 누적과 올해 누적의 시작 기준
+    #[serde(default)]
+    pub hire_date: Option<String>,
+    /// 급여 지급 주기. 월급·연봉은 주기당 금액으로 나눈다 (예: 주급 = 월급 × 12 / 52)
+    #[serde(default)]
+    pub pay_cycle: PayCycle,
+    /// 주급·격주급 기준 지급일 (YYYY-MM-DD). 이 날짜부터 7·14일 간격
+    #[serde(default)]
+    pub pay_anchor_date: Option<String>,
+    /// 월급날이 주말(근무 요일 외)·공휴일이면 앞/뒤 영업일로 옮길지
+    #[serde(default)]
+    pub payday_adjustment: PaydayAdjustment,
     /// Work days (0=Sunday, 1=Monday, ..., 6=Saturday)
     #[serde(default = "default_work_days")]
     pub work_days: Vec<u8>,
+    /// 근무 시간대 (IANA, 예: "Asia/Seoul"). 출퇴근 시각·날짜·급여 기간을 이 시간대로 본다.
+    /// 없으면 시스템 시간대
+    #[serde(default)]
+    pub work_timezone: Option<String>,
     /// Work start time (HH:MM format, default: "09:00")
     #[serde(default = "default_work_start_time")]
     pub work_start_time: String,
     /// Work end time (HH:MM format, default: "18:00")
     #[serde(default = "default_work_end_time")]
     pub work_end_time: String,
+    /// 요일별 출퇴근 시간. 없는 요일은 `work_start_time`/`work_end_time` 사용
+    #[serde(default)]
+    pub weekday_hours: Vec<WeekdayHours>,
+    /// 무급 휴게시간 목록. 근무시간과 겹치는 부분만 유급 시간에서 제외
+    #[serde(default)]
+    pub break_times: Vec<BreakWindow>,
+    /// 연장·야간·휴일 가산수당 규칙
+    #[serde(default)]
+    pub premium_rules: PremiumRules,
+    /// 일급·누적액 최소 단위 반올림 방식
+    #[serde(default)]
+    pub rounding_policy: RoundingPolicy,
+    /// 연봉 분할 지급 횟수 (기본 12). 14·16분할이면 월 기본급 = 연봉 / 분할 수,
+    /// 12회를 넘는 분은 `bonuses`에 기본급 대비 비율로 지정한다
+    #[serde(default = "default_yearly_splits")]
+    pub yearly_splits: u8,
+    /// 상여금 일정
+    #[serde(default)]
+    pub bonuses: Vec<Bonus>,
+    #[serde(default)]
+    pub bonus_accrual: BonusAccrual,
+    /// 병가 지급 비율 (%). 100이면 유급 병가, 0이면 무급휴가와 같다
+    #[serde(default = "default_pay_percent")]
+    pub sick_leave_pay_percent: u8,
     /// Whether onboarding is completed
     pub onboarding_completed: bool,
     /// 필수 약관(TOS, PRIVACY) 모두 동의했는지 — 서버 has_required_terms_agreed 미러
@@ -237,12 +583,161 @@ pub struct UserSettings {
     /// 마케팅 정보 수신 동의(선택) 여부
     #[serde(default)]
     pub terms_marketing_agreed: bool,
-    /// Menubar display mode (macOS only): none, daily, accumulated
+    /// Menubar display mode (macOS only): amounts, countdowns, rates or totals
     #[serde(default)]
     pub menubar_display_mode: MenubarDisplayMode,
     /// Menubar icon theme: light (white) or dark (black)
     #[serde(default)]
     pub menubar_icon_theme: MenubarIconTheme,
+    /// 본업 외 추가 근무 프로필. 금액은 본업과 합산
+    #[serde(default)]
+    pub job_profiles: Vec<JobProfile>,
+    /// 메뉴바에 표시할 프로필 id. None이면 합계, `PRIMARY_JOB_ID`면 본업만
+    #[serde(default)]
+    pub menubar_job_id: Option<String>,
+    /// 앱 데이터 디렉토리의 근무 일정 .ics 구독 파일을 workday 변경마다 다시 쓸지
+    #[serde(default)]
+    pub calendar_feed_enabled: bool,
+}
+
+impl UserSettings {
+    /// `date`(YYYY-MM-DD)에 적용되는 급여 이력 항목. 첫 항목 이전이면 첫 항목, 이력이 없으면 None
+    pub fn salary_version_on(&self, date: &str) -> Option<&SalaryVersion> {
+        self.salary_history
+            .iter()
+            .filter(|version| version.effective_from.as_str() <= date)
+            .max_by(|a, b| a.effective_from.cmp(&b.effective_from))
+            .or_else(|| {
+                self.salary_history
+                    .iter()
+                    .min_by(|a, b| a.effective_from.cmp(&b.effective_from))
+            })
+    }
+
+    /// `today`부터 `salary_type`·`salary_amount`로 바뀐 급여를 이력에 남긴다. 변경 여부 반환.
+    ///
+    /// 급여가 아직 없으면(온보딩 전) 이력 없이 최상위 필드만 바꾼다. 이력이 비어 있으면 바뀌기 전
+    /// 급여를 첫 항목으로 남겨 지난 날짜는 이전 급여로 계산되게 한다. 같은 날 항목은 교체하고 지급 비율은
+    /// 이어받으며, 최상위 급여 필드는 오늘 적용 중인 항목으로 맞춘다.
+    pub fn record_salary_change(
+        &mut self,
+        salary_type: SalaryType,
+        salary_amount: u32,
+        today: &str,
+    ) -> bool {
+        if self.salary_history.is_empty() {
+            if self.salary_type == salary_type && self.salary_amount == salary_amount {
+                return false;
+            }
+            if self.salary_amount == 0 {
+                self.salary_type = salary_type;
+                self.salary_amount = salary_amount;
+                return true;
+            }
+            // 첫 항목은 이전 날짜 전체에 적용되므로 날짜는 입사일 또는 전날
+            let base_from = self
+                .hire_date
+                .clone()
+                .filter(|hire_date| hire_date.as_str() < today)
+                .or_else(|| {
+                    chrono::NaiveDate::parse_from_str(today, "%Y-%m-%d")
+                        .ok()?
+                        .pred_opt()
+                        .map(|day| day.format("%Y-%m-%d").to_string())
+                })
+                .unwrap_or_else(|| today.to_string());
+            self.salary_history.push(SalaryVersion {
+                effective_from: base_from,
+                salary_type: self.salary_type.clone(),
+                salary_amount: self.salary_amount,
+                pay_percent: 100,
+                note: None,
+            });
+        }
+
+        let current = self.salary_version_on(today).cloned();
+        let changed = current.as_ref().is_none_or(|version| {
+            version.salary_type != salary_type || version.salary_amount != salary_amount
+        });
+        if changed {
+            self.salary_history
+                .retain(|version| version.effective_from != today);
+            self.salary_history.push(SalaryVersion {
+                effective_from: today.to_string(),
+                salary_type,
+                salary_amount,
+                pay_percent: current.map_or(100, |version| version.pay_percent),
+                note: None,
+            });
+            self.salary_history
+                .sort_by(|a, b| a.effective_from.cmp(&b.effective_from));
+        }
+        if let Some(version) = self.salary_version_on(today).cloned() {
+            self.salary_type = version.salary_type;
+            self.salary_amount = version.salary_amount;
+        }
+        changed
+    }
+
+    /// `date`에 적용되는 급여로 바꾼 설정 (지급 비율 반영)
+    pub fn with_salary_on(&self, date: &str) -> UserSettings {
+        let mut settings = self.clone();
+        if let Some(version) = self.salary_version_on(date) {
+            settings.salary_type = version.salary_type.clone();
+            settings.salary_amount = version.paid_amount();
+        }
+        settings
+    }
+
+    /// 근무 시간대. 없거나 알 수 없는 이름이면 None (시스템 시간대)
+    pub fn work_tz(&self) -> Option<chrono_tz::Tz> {
+        self.work_timezone.as_deref()?.parse().ok()
+    }
+
+    /// 해당 요일(0=일)의 출퇴근 시간. 요일별 설정이 없으면 전역 값
+    pub fn hours_for_weekday(&self, weekday: u8) -> (&str, &str) {
+        self.weekday_hours
+            .iter()
+            .find(|h| h.weekday == weekday)
+            .map(|h| (h.start_time.as_str(), h.end_time.as_str()))
+            .unwrap_or((&self.work_start_time, &self.work_end_time))
+    }
+
+    /// 추가 프로필을 본업 자리에 넣은 설정. 급여 계산을 프로필별로 재사용하기 위함
+    pub fn for_job(&self, job: &JobProfile) -> UserSettings {
+        UserSettings {
+            salary_type: job.salary_type.clone(),
+            salary_amount: job.salary_amount,
+            pay_day: job.pay_day,
+            pay_cycle: job.pay_cycle,
+            pay_anchor_date: job.pay_anchor_date.clone(),
+            work_days: job.work_days.clone(),
+            work_start_time: job.work_start_time.clone(),
+            work_end_time: job.work_end_time.clone(),
+            weekday_hours: job.weekday_hours.clone(),
+            break_times: job.break_times.clone(),
+            rounding_policy: job.rounding_policy,
+            // 급여 이력·상여금·연봉 분할은 본업 기준
+            salary_history: vec![],
+            yearly_splits: 12,
+            bonuses: vec![],
+            job_profiles: vec![],
+            menubar_job_id: None,
+            ..self.clone()
+        }
+    }
+}
+
+fn default_dependents() -> u8 {
+    1
+}
+
+fn default_pay_percent() -> u8 {
+    100
+}
+
+fn default_yearly_splits() -> u8 {
+    12
 }
 
 fn default_work_days() -> Vec<u8> {
@@ -262,15 +757,38 @@ impl Default for UserSettings {
         Self {
             salary_type: SalaryType::default(),
             salary_amount: 0,
+            salary_history: vec![],
+            currency: Currency::default(),
+            secondary_currency: None,
+            exchange_rates: vec![],
+            pay_display: None,
+            tax_free_allowance: 0,
+            dependents: default_dependents(),
             pay_day: 25,
+            pay_cycle: PayCycle::default(),
+            pay_anchor_date: None,
+            hire_date: None,
+            payday_adjustment: PaydayAdjustment::default(),
             work_days: default_work_days(),
+            work_timezone: None,
             work_start_time: default_work_start_time(),
             work_end_time: default_work_end_time(),
+            weekday_hours: vec![],
+            break_times: vec![],
+            premium_rules: PremiumRules::default(),
+            rounding_policy: RoundingPolicy::default(),
+            yearly_splits: default_yearly_splits(),
+            bonuses: vec![],
+            bonus_accrual: BonusAccrual::default(),
+            sick_leave_pay_percent: default_pay_percent(),
             onboarding_completed: false,
             terms_agreed: false,
             terms_marketing_agreed: false,
             menubar_display_mode: MenubarDisplayMode::default(),
             menubar_icon_theme: MenubarIconTheme::default(),
+            job_profiles: vec![],
+            menubar_job_id: None,
+            calendar_feed_enabled: false,
         }
     }
 }
@@ -283,6 +801,26 @@ pub fn validate_salary_amount(amount: u32) -> Result<(), String> {
     Ok(())
 }
 
+/// Validates salary history (YYYY-MM-DD, unique dates, amount > 0, pay percent 1–100)
+pub fn validate_salary_history(history: &[SalaryVersion]) -> Result<(), String> {
+    for (i, version) in history.iter().enumerate() {
+        if chrono::NaiveDate::parse_from_str(&version.effective_from, "%Y-%m-%d").is_err() {
+            return Err("급여 적용 시작일은 YYYY-MM-DD 형식이어야 합니다".to_string());
+        }
+        if history[..i]
+            .iter()
+            .any(|other| other.effective_from == version.effective_from)
+        {
+            return Err("같은 날짜에 적용되는 급여 이력이 중복되었습니다".to_string());
+        }
+        validate_salary_amount(version.salary_amount)?;
+        if !(1..=100).contains(&version.pay_percent) {
+            return Err("급여 지급 비율은 1~100% 사이여야 합니다".to_string());
+        }
+    }
+    Ok(())
+}
+
 /// Validates pay day (1-31)
 pub fn validate_pay_day(day: u8) -> Result<(), String> {
     if !(1..=31).contains(&day) {
@@ -290,3 +828,369 @@ pub fn validate_pay_day(day: u8) -> Result<(), String> {
     }
     Ok(())
 }
+
+/// Validates hire date (YYYY-MM-DD)
+pub fn validate_hire_date(hire_date: Option<&str>) -> Result<(), String> {
+    if hire_date.is_some_and(|date| chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").is_err()) {
+        return Err("입사일은 YYYY-MM-DD 형식이어야 합니다".to_string());
+    }
+    Ok(())
+}
+
+/// Validates work timezone (IANA name such as "Asia/Seoul")
+pub fn validate_work_timezone(timezone: Option<&str>) -> Result<(), String> {
+    if timezone.is_some_and(|tz| tz.parse::<chrono_tz::Tz>().is_err()) {
+        return Err("알 수 없는 시간대입니다".to_string());
+    }
+    Ok(())
+}
+
+/// Validates pay cycle (weekly·bi-weekly need a YYYY-MM-DD anchor date)
+pub fn validate_pay_cycle(cycle: PayCycle, anchor_date: Option<&str>) -> Result<(), String> {
+    let has_anchor =
+        anchor_date.is_some_and(|date| chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok());
+    if matches!(cycle, PayCycle::Weekly | PayCycle::BiWeekly) && !has_anchor {
+        return Err("주급·격주급은 기준 지급일(YYYY-MM-DD)이 필요합니다".to_string());
+    }
+    Ok(())
+}
+
+/// Validates break windows (HH:MM, start != end)
+pub fn validate_break_times(break_times: &[BreakWindow]) -> Result<(), String> {
+    for window in break_times {
+        if !is_valid_hhmm(&window.start_time) || !is_valid_hhmm(&window.end_time) {
+            return Err("휴게시간은 HH:MM 형식이어야 합니다".to_string());
+        }
+        if window.start_time == window.end_time {
+            return Err("휴게시간 시작과 종료가 같을 수 없습니다".to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Validates per-weekday hours (weekday 0–6, no duplicates, HH:MM)
+pub fn validate_weekday_hours(weekday_hours: &[WeekdayHours]) -> Result<(), String> {
+    for (i, hours) in weekday_hours.iter().enumerate() {
+        if hours.weekday > 6 {
+            return Err("요일은 0~6 사이여야 합니다".to_string());
+        }
+        if weekday_hours[..i]
+            .iter()
+            .any(|h| h.weekday == hours.weekday)
+        {
+            return Err("같은 요일의 근무시간이 중복됩니다".to_string());
+        }
+        if !is_valid_hhmm(&hours.start_time) || !is_valid_hhmm(&hours.end_time) {
+            return Err("근무시간은 HH:MM 형식이어야 합니다".to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Validates premium rates (0–300%)
+pub fn validate_premium_rules(rules: &PremiumRules) -> Result<(), String> {
+    let rates = [
+        rules.overtime.rate,
+        rules.night.rate,
+        rules.holiday.rate,
+        rules.holiday_overtime.rate,
+    ];
+    if rates.iter().any(|r| !(0.0..=3.0).contains(r)) {
+        return Err("가산율은 0~300% 사이여야 합니다".to_string());
+    }
+    Ok(())
+}
+
+/// Validates yearly salary splits (12–24)
+pub fn validate_yearly_splits(splits: u8) -> Result<(), String> {
+    if !(12..=24).contains(&splits) {
+        return Err("연봉 분할 횟수는 12~24 사이여야 합니다".to_string());
+    }
+    Ok(())
+}
+
+/// Validates dependents (1–20, including the user)
+pub fn validate_dependents(dependents: u8) -> Result<(), String> {
+    if !(1..=20).contains(&dependents) {
+        return Err("공제대상 가족 수는 본인 포함 1~20명이어야 합니다".to_string());
+    }
+    Ok(())
+}
+
+/// Validates sick leave pay percent (0–100)
+pub fn validate_sick_leave_pay_percent(percent: u8) -> Result<(), String> {
+    if percent > 100 {
+        return Err("병가 지급 비율은 0~100% 사이여야 합니다".to_string());
+    }
+    Ok(())
+}
+
+/// Validates bonus schedule (months 1–12, non-negative amounts, percent up to 1000%)
+pub fn validate_bonuses(bonuses: &[Bonus]) -> Result<(), String> {
+    for bonus in bonuses {
+        if bonus.months.is_empty() || bonus.months.iter().any(|m| !(1..=12).contains(m)) {
+            return Err("상여금 지급 월은 1~12 사이여야 합니다".to_string());
+        }
+        if !bonus.value.is_finite() || bonus.value < 0.0 {
+            return Err("상여금 금액은 0 이상이어야 합니다".to_string());
+        }
+        if bonus.kind == BonusKind::PercentOfBase && bonus.value > 1000.0 {
+            return Err("상여금 비율은 1000% 이하여야 합니다".to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Validates exchange rates (positive, distinct currencies, no duplicate pairs)
+pub fn validate_exchange_rates(rates: &[ExchangeRate]) -> Result<(), String> {
+    for (i, rate) in rates.iter().enumerate() {
+        if !rate.rate.is_finite() || rate.rate <= 0.0 {
+            return Err("환율은 0보다 커야 합니다".to_string());
+        }
+        if rate.from == rate.to {
+            return Err("환율의 두 통화가 같습니다".to_string());
+        }
+        let duplicated = rates[..i].iter().any(|other| {
+            (other.from, other.to) == (rate.from, rate.to)
+                || (other.from, other.to) == (rate.to, rate.from)
+        });
+        if duplicated {
+            return Err("같은 통화쌍의 환율이 중복되었습니다".to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Validates job profile id (filesystem-safe, not the primary id)
+pub fn validate_job_id(id: &str) -> Result<(), String> {
+    let valid = !id.is_empty()
+        && id.len() <= 40
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid || id == PRIMARY_JOB_ID {
+        return Err("프로필 id가 올바르지 않습니다".to_string());
+    }
+    Ok(())
+}
+
+/// Validates additional job profiles (unique ids + same rules as the primary job)
+pub fn validate_job_profiles(jobs: &[JobProfile]) -> Result<(), String> {
+    for (i, job) in jobs.iter().enumerate() {
+        validate_job_id(&job.id)?;
+        if jobs[..i].iter().any(|other| other.id == job.id) {
+            return Err("프로필 id가 중복됩니다".to_string());
+        }
+        validate_string_input(&job.name, 50, "Job name")?;
+        validate_salary_amount(job.salary_amount)?;
+        validate_pay_day(job.pay_day)?;
+        validate_pay_cycle(job.pay_cycle, job.pay_anchor_date.as_deref())?;
+        validate_weekday_hours(&job.weekday_hours)?;
+        validate_break_times(&job.break_times)?;
+    }
+    Ok(())
+}
+
+fn is_valid_hhmm(time: &str) -> bool {
+    let Some((h, m)) = time.split_once(':') else {
+        return false;
+    };
+    matches!((h.parse::<u32>(), m.parse::<u32>()), (Ok(h), Ok(m)) if h < 24 && m < 60)
+}
+
+// ============================================================================
+// Goals (저축·구매 목표)
+// ============================================================================
+
+/// 목표 진행률 기준 금액
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum GoalBasis {
+    /// 이번 급여 기간 누적 (`accumulated_earnings`)
+    #[default]
+    Accumulated,
+    /// 오늘 번 금액 (`today_earnings`)
+    Today,
+}
+
+/// 저축·구매 목표 (예: 노트북 1,200,000원, 오늘 점심값)
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Goal {
+    pub id: String,
+    pub name: String,
+    /// 목표 금액 (`salary_amount`와 같은 통화 최소 단위)
+    pub target_amount: u32,
+    #[serde(default)]
+    pub basis: GoalBasis,
+    /// 목표 시점 (YYYY-MM)
+    #[serde(default)]
+    pub deadline: Option<String>,
+    /// 달성 축하를 보낸 날짜 (YYYY-MM-DD). 목표당 한 번, `Today` 목표는 하루에 한 번 축하한다
+    #[serde(default)]
+    pub achieved_on: Option<String>,
+}
+
+/// Validates a goal (filesystem-safe id, name, amount > 0, YYYY-MM deadline)
+pub fn validate_goal(goal: &Goal) -> Result<(), String> {
+    let valid_id = !goal.id.is_empty()
+        && goal.id.len() <= 40
+        && goal
+            .id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid_id {
+        return Err("목표 id가 올바르지 않습니다".to_string());
+    }
+    if goal.name.trim().is_empty() {
+        return Err("목표 이름을 입력해주세요".to_string());
+    }
+    validate_string_input(&goal.name, 50, "Goal name")?;
+    if goal.target_amount == 0 {
+        return Err("목표 금액은 0보다 커야 합니다".to_string());
+    }
+    let valid_deadline = goal.deadline.as_deref().is_none_or(|month| {
+        chrono::NaiveDate::parse_from_str(&format!("{month}-01"), "%Y-%m-%d").is_ok()
+            && month.len() == 7
+    });
+    if !valid_deadline {
+        return Err("목표 시점은 YYYY-MM 형식이어야 합니다".to_string());
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Leave Balance (연차 잔여)
+// ============================================================================
+
+/// 연차 수동 조정 (이월, 회사 추가 부여, 앱 설치 전 사용분 차감 등)
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveAdjustment {
+    /// 조정일 (YYYY-MM-DD). 이 날짜가 속한 연차 연도에만 반영
+    pub date: String,
+    /// 더할 일수 (음수면 차감)
+    pub days: f64,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+/// `leave-balance.json`에 저장하는 연차 장부
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveLedger {
+    #[serde(default)]
+    pub adjustments: Vec<LeaveAdjustment>,
+    /// 날짜(YYYY-MM-DD)별 사용 일수. 같은 날을 다시 저장하면 덮어쓴다
+    #[serde(default)]
+    pub debits: std::collections::BTreeMap<String, f64>,
+}
+
+/// Validates a leave adjustment (YYYY-MM-DD, non-zero quarter days up to ±100, note ≤ 50 chars)
+pub fn validate_leave_adjustment(adjustment: &LeaveAdjustment) -> Result<(), String> {
+    if chrono::NaiveDate::parse_from_str(&adjustment.date, "%Y-%m-%d").is_err() {
+        return Err("조정일은 YYYY-MM-DD 형식이어야 합니다".to_string());
+    }
+    let days = adjustment.days;
+    if !days.is_finite() || days == 0.0 || days.abs() > 100.0 || (days * 4.0).fract() != 0.0 {
+        return Err("조정 일수는 0.25일 단위, ±100일 이내여야 합니다".to_string());
+    }
+    if let Some(note) = &adjustment.note {
+        validate_string_input(note, 50, "Leave note")?;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Company Holidays (회사 휴무일)
+// ============================================================================
+
+/// .ics에서 가져온 회사 휴무일 (창립기념일, 연말 휴무 등). 급여 계산에서 공휴일과 같게 본다
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyHoliday {
+    /// YYYY-MM-DD
+    pub date: String,
+    /// 일정 이름 (예: "창립기념일")
+    pub name: String,
+    /// 가져온 달력 이름 (`X-WR-CALNAME`, 없으면 파일 이름)
+    pub calendar: String,
+}
+
+/// `company-holidays.json`: 달력 이름별로 가져온 .ics 일정 (반복 규칙째로 저장해 날짜는 읽을 때 펼친다)
+pub type CompanyHolidayBook = std::collections::BTreeMap<String, Vec<crate::ics::IcsEvent>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_salary_change_appends_version_instead_of_overwriting() {
+        let mut settings = UserSettings {
+            salary_amount: 3_000_000,
+            salary_history: vec![SalaryVersion {
+                effective_from: "2025-01-02".to_string(),
+                salary_type: SalaryType::Monthly,
+                salary_amount: 3_000_000,
+                pay_percent: 90,
+                note: Some("수습".to_string()),
+            }],
+            ..Default::default()
+        };
+
+        // 오늘 적용 중인 급여와 같으면 변경 없음
+        assert!(!settings.record_salary_change(SalaryType::Monthly, 3_000_000, "2025-03-10"));
+
+        // 최상위 급여는 오늘 적용 중인 인상분
+        assert!(settings.record_salary_change(SalaryType::Monthly, 3_300_000, "2025-03-10"));
+        assert_eq!(settings.salary_amount, 3_300_000);
+        assert_eq!(settings.salary_history.len(), 2);
+        let raise = &settings.salary_history[1];
+        assert_eq!(raise.effective_from, "2025-03-10");
+        assert_eq!(raise.salary_amount, 3_300_000);
+        assert_eq!(raise.pay_percent, 90);
+
+        // 같은 날 다시 바뀌면 그 항목을 교체
+        assert!(settings.record_salary_change(SalaryType::Monthly, 3_500_000, "2025-03-10"));
+        assert_eq!(settings.salary_history.len(), 2);
+        assert_eq!(settings.salary_history[1].salary_amount, 3_500_000);
+        assert_eq!(settings.salary_amount, 3_500_000);
+    }
+
+    #[test]
+    fn record_salary_change_keeps_previous_salary_as_first_version() {
+        let mut settings = UserSettings {
+            salary_amount: 3_000_000,
+            hire_date: Some("2024-07-01".to_string()),
+            ..Default::default()
+        };
+        assert!(settings.record_salary_change(SalaryType::Monthly, 3_300_000, "2025-03-10"));
+        assert_eq!(settings.salary_amount, 3_300_000);
+        let amounts: Vec<(&str, u32)> = settings
+            .salary_history
+            .iter()
+            .map(|version| (version.effective_from.as_str(), version.salary_amount))
+            .collect();
+        assert_eq!(
+            amounts,
+            vec![("2024-07-01", 3_000_000), ("2025-03-10", 3_300_000)]
+        );
+        // 첫 항목 이전 날짜도 이전 급여
+        assert_eq!(
+            settings
+                .salary_version_on("2024-01-01")
+                .unwrap()
+                .salary_amount,
+            3_000_000
+        );
+    }
+
+    #[test]
+    fn record_salary_change_sets_base_salary_before_onboarding() {
+        let mut settings = UserSettings::default();
+        assert!(settings.record_salary_change(SalaryType::Yearly, 42_000_000, "2025-03-10"));
+        assert_eq!(settings.salary_type, SalaryType::Yearly);
+        assert_eq!(settings.salary_amount, 42_000_000);
+        assert!(settings.salary_history.is_empty());
+    }
+}
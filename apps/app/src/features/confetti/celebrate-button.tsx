@@ -12,7 +12,7 @@ import { PaydayOverlay } from './payday-overlay';
 
 export function CelebrateButton() {
   const { data: settings } = useUserSettings();
-  const isPayday = useIsPayday(settings?.payDay ?? 25);
+  const isPayday = useIsPayday();
   const [showOverlay, setShowOverlay] = useState(false);
 
   if (!isPayday || !settings) return null;
@@ -9,6 +9,8 @@ import {
 import { useUserSettings } from '~/hooks/use-user-settings';
 import { useWorkCompletedAck } from '~/hooks/use-work-completed-ack';
 import {
+  isPartialLeave,
+  type NonWorkingStatus,
   useWorkday,
   type WorkdaySchedule,
   type WorkdayStatus,
@@ -31,7 +33,7 @@ import {
 export type HomeMainScreen =
   | {
       screen: 'non-working';
-      status: WorkdayStatus;
+      status: NonWorkingStatus;
       salaryInfo: SalaryInfo;
       isPending?: boolean;
       onTodayWork: () => void;
@@ -114,12 +116,7 @@ export function useHomeScreen(): HomeScreenState {
       } else {
         await saveSchedule(startTime, endTime);
       }
-      await waitForSalaryTick(
-        (info) =>
-          info.workStatus !== 'annual-leave' &&
-          info.workStatus !== 'day-off' &&
-          info.workStatus !== 'public-holiday',
-      );
+      await waitForSalaryTick((info) => !isNonWorkingStatus(info.workStatus));
     },
   });
 
@@ -168,7 +165,11 @@ export function useHomeScreen(): HomeScreenState {
       status: WorkdayStatus;
     }) => {
       await saveStatus(_vars.status);
-      await waitForSalaryTick((info) => info.workStatus === _vars.status);
+      if (isPartialLeave(_vars.status)) {
+        await waitForNextSalaryTick();
+      } else {
+        await waitForSalaryTick((info) => info.workStatus === _vars.status);
+      }
     },
   });
 
@@ -450,15 +451,19 @@ interface ResolveParams {
 
 function isNonWorkingStatus(
   status: SalaryInfo['workStatus'],
-): status is WorkdayStatus {
+): status is NonWorkingStatus {
   return (
     status === 'annual-leave' ||
+    status === 'unpaid-leave' ||
+    status === 'sick-leave' ||
     status === 'day-off' ||
     status === 'public-holiday'
   );
 }
 
-function getNonWorkingStatus(status: SalaryInfo['workStatus']): WorkdayStatus {
+function getNonWorkingStatus(
+  status: SalaryInfo['workStatus'],
+): NonWorkingStatus {
   return isNonWorkingStatus(status) ? status : 'day-off';
 }
 
@@ -499,6 +504,7 @@ function resolveMainScreen(params: ResolveParams): HomeMainScreen {
         onStartWork,
       };
     case 'working':
+    case 'on-break':
       return {
         screen: 'working',
         settings,
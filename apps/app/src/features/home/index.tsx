@@ -6,7 +6,11 @@ import {
   waitForNextSalaryTick,
   waitForSalaryTick,
 } from '~/hooks/use-salary-tick';
-import { useWorkday, type WorkdayStatus } from '~/hooks/use-workday';
+import {
+  isPartialLeave,
+  useWorkday,
+  type WorkdayStatus,
+} from '~/hooks/use-workday';
 import { AppBar } from '~/ui/app-bar';
 
 import { useHomeScreen } from './hooks/use-home-screen';
@@ -51,7 +55,11 @@ export function Home() {
 
   const handleSaveStatus = async (status: WorkdayStatus) => {
     await saveStatus(status);
-    await waitForSalaryTick((info) => info.workStatus === status);
+    if (isPartialLeave(status)) {
+      await waitForNextSalaryTick();
+    } else {
+      await waitForSalaryTick((info) => info.workStatus === status);
+    }
   };
 
   if (isAdjusting && mainScreen.screen !== 'non-working') {
@@ -7,11 +7,29 @@ import { TimePeriodInput } from '~/ui/time-period-input';
 
 import { getEffectiveWorkTime } from '../lib/effective-work-time';
 
-type ScheduleType = 'work' | Extract<WorkdayStatus, 'annual-leave' | 'day-off'>;
+type ScheduleType =
+  | 'work'
+  | Extract<
+      WorkdayStatus,
+      | 'annual-leave'
+      | 'morning-half-leave'
+      | 'afternoon-half-leave'
+      | 'morning-quarter-leave'
+      | 'afternoon-quarter-leave'
+      | 'unpaid-leave'
+      | 'sick-leave'
+      | 'day-off'
+    >;
 
 const SCHEDULE_TYPE_OPTIONS = [
   { value: 'work', label: '근무' },
   { value: 'annual-leave', label: '연차' },
+  { value: 'morning-half-leave', label: '오전 반차' },
+  { value: 'afternoon-half-leave', label: '오후 반차' },
+  { value: 'morning-quarter-leave', label: '오전 반반차' },
+  { value: 'afternoon-quarter-leave', label: '오후 반반차' },
+  { value: 'sick-leave', label: '병가' },
+  { value: 'unpaid-leave', label: '무급휴가' },
   { value: 'day-off', label: '휴무' },
 ] as const;
 
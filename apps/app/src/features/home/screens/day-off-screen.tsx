@@ -14,6 +14,8 @@ type NonWorkingScreenState = Extract<HomeMainScreen, { screen: 'non-working' }>;
 
 const STATUS_LABEL: Record<NonWorkingScreenState['status'], string> = {
   'annual-leave': '연차',
+  'unpaid-leave': '무급휴가',
+  'sick-leave': '병가',
   'day-off': '근무 없음',
   'public-holiday': '공휴일',
 };
@@ -25,6 +27,9 @@ export function DayOffScreen({
   onTodayWork,
 }: NonWorkingScreenState) {
   const isAnnualLeave = status === 'annual-leave';
+  const isPaidLeave = isAnnualLeave || status === 'sick-leave';
+  const companyHoliday =
+    status === 'public-holiday' ? salaryInfo.companyHoliday : null;
 
   return (
     <div className="flex flex-1 flex-col">
@@ -37,10 +42,19 @@ export function DayOffScreen({
       <InfoCard className="mt-7">
         <InfoCardRow
           label="근무 상태"
-          value={STATUS_LABEL[status]}
+          value={companyHoliday ? '회사 휴무일' : STATUS_LABEL[status]}
           valueClassName={isAnnualLeave ? 'text-blue' : undefined}
         />
-        {isAnnualLeave && (
+        {companyHoliday && (
+          <>
+            <InfoCardDivider />
+            <InfoCardRow
+              label={companyHoliday.name}
+              value={companyHoliday.calendar}
+            />
+          </>
+        )}
+        {isPaidLeave && (
           <>
             <InfoCardDivider />
             <InfoCardRow
@@ -49,6 +63,15 @@ export function DayOffScreen({
             />
           </>
         )}
+        {salaryInfo.leaveDeduction > 0 && (
+          <>
+            <InfoCardDivider />
+            <InfoCardRow
+              label="휴가 공제"
+              value={formatCurrency(salaryInfo.leaveDeduction)}
+            />
+          </>
+        )}
       </InfoCard>
 
       <AppFooter>
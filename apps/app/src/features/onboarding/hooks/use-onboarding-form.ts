@@ -9,6 +9,7 @@ import { authQuery, userSettingsQuery } from '~/queries';
 export const SALARY_TYPE_OPTIONS = [
   { value: 'yearly', label: '연봉' },
   { value: 'monthly', label: '월급' },
+  { value: 'hourly', label: '시급' },
 ] as const satisfies { value: SalaryType; label: string }[];
 
 export interface OnboardingFormValues {
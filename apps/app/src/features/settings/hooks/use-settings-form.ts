@@ -14,12 +14,15 @@ import { userSettingsQuery, workdayQuery } from '~/queries';
 export const SALARY_TYPE_OPTIONS = [
   { value: 'yearly', label: '연봉' },
   { value: 'monthly', label: '월급' },
+  { value: 'hourly', label: '시급' },
 ] as const satisfies readonly { value: SalaryType; label: string }[];
 
 export interface SettingsFormValues {
   salaryType: SalaryType;
   salaryAmount: number;
   payDay: number;
+  /** YYYY-MM-DD, 모르면 '' */
+  hireDate: string;
   workDays: number[];
   workStartTime: string;
   workEndTime: string;
@@ -41,6 +44,7 @@ export function useSettingsForm({
       salaryType: settings.salaryType ?? 'monthly',
       salaryAmount: settings.salaryAmount,
       payDay: settings.payDay,
+      hireDate: settings.hireDate ?? '',
       workDays: settings.workDays ?? [1, 2, 3, 4, 5],
       workStartTime: settings.workStartTime ?? '09:00',
       workEndTime: settings.workEndTime ?? '18:00',
@@ -59,6 +63,10 @@ export function useSettingsForm({
           errors.payDay = '급여일은 1~31 사이여야 합니다';
         }
 
+        if (value.hireDate && value.hireDate > getTodayString()) {
+          errors.hireDate = '입사일은 오늘 이후일 수 없습니다';
+        }
+
         if (value.workDays.length === 0) {
           errors.workDays = '근무 요일을 선택해주세요';
         }
@@ -88,6 +96,7 @@ export function useSettingsForm({
         salaryType: value.salaryType,
         salaryAmount: value.salaryAmount,
         payDay: value.payDay,
+        hireDate: value.hireDate || null,
         workDays: value.workDays,
         workStartTime: value.workStartTime,
         workEndTime: value.workEndTime,
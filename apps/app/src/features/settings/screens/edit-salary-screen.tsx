@@ -4,11 +4,13 @@ import { useUserSettings } from '~/hooks/use-user-settings';
 import { MAX_SALARY_AMOUNT } from '~/lib/constants';
 import { formatKoreanAmount } from '~/lib/format';
 import type { UserSettings } from '~/lib/tauri-bindings';
+import { getTodayString } from '~/lib/time';
 import {
   AmountInput,
   AppBar,
   AppFooter,
   Button,
+  DateInput,
   Field,
   NumberInput,
   ToggleInput,
@@ -107,6 +109,25 @@ function EditSalaryForm({ settings }: EditSalaryFormProps) {
               );
             }}
           </form.Field>
+
+          <form.Field name="hireDate">
+            {(field) => (
+              <Field.Root
+                name={field.name}
+                invalid={field.state.meta.errors.length > 0}
+              >
+                <Field.Label>입사일</Field.Label>
+                <DateInput
+                  value={field.state.value}
+                  max={getTodayString()}
+                  onValueChange={field.handleChange}
+                />
+                <Field.Error>
+                  {field.state.meta.errors.filter(Boolean)[0]}
+                </Field.Error>
+              </Field.Root>
+            )}
+          </form.Field>
         </div>
 
         <form.Subscribe
@@ -11,7 +11,8 @@ import { SettingsSection } from '../components/settings-section';
 const DAY_NAMES = ['일', '월', '화', '수', '목', '금', '토'];
 
 function formatSalary(amount: number, type?: SalaryType): string {
-  const typeLabel = type === 'yearly' ? '연봉' : '월급';
+  const typeLabel =
+    type === 'yearly' ? '연봉' : type === 'hourly' ? '시급' : '월급';
   return `${typeLabel} · ${formatKoreanAmount(amount)}`;
 }
 
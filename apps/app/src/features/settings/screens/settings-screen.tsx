@@ -35,8 +35,17 @@ const MENUBAR_DISPLAY_OPTIONS = [
   { value: 'none', label: '표기 안 함' },
   { value: 'daily', label: '누적 일급 표기' },
   { value: 'accumulated', label: '누적 월급 표기' },
+  { value: 'countdown', label: '퇴근 카운트다운' },
+  { value: 'payday', label: '월급날 D-day' },
+  { value: 'hourly', label: '시급 표기' },
+  { value: 'per-minute', label: '분당 금액 표기' },
+  { value: 'year-to-date', label: '올해 누적 표기' },
+  { value: 'lifetime', label: '입사 이후 누적 표기' },
 ] as const;
 
+/** 메뉴바 표시 프로필. 'all'은 합계 (`menubarJobId: null`) */
+const MENUBAR_JOB_ALL = 'all';
+
 const ICON_THEME_OPTIONS = [
   { value: 'light', label: '밝은 아이콘' },
   { value: 'dark', label: '어두운 아이콘' },
@@ -117,6 +126,102 @@ export function SettingsScreen() {
     },
   });
 
+  const menubarJobMutation = useMutation({
+    mutationFn: async (menubarJobId: string | null) => {
+      if (!settings) return;
+      const result = await commands.saveUserSettings({
+        ...settings,
+        menubarJobId,
+      });
+      if (result.status === 'error') throw new Error(result.error);
+    },
+    onSuccess: () => {
+      void queryClient.invalidateQueries({
+        queryKey: userSettingsQuery.all(),
+      });
+      void commands.notifySettingsChanged();
+      toast('메뉴바에 표시할 근무를 변경했습니다.');
+    },
+  });
+
+  const menubarJobOptions = [
+    { value: MENUBAR_JOB_ALL, label: '모든 근무 합계' },
+    { value: 'primary', label: '본업만' },
+    ...(settings?.jobProfiles ?? []).map((job) => ({
+      value: job.id,
+      label: `${job.name}만`,
+    })),
+  ];
+
+  const importCompanyHolidaysMutation = useMutation({
+    mutationFn: async () => {
+      const result = await commands.importCompanyHolidays();
+      if (result.status === 'error') throw new Error(result.error);
+      return result.data;
+    },
+    onSuccess: (holidays) => {
+      if (!holidays) return;
+      const calendar = holidays[0]?.calendar;
+      toast(
+        calendar
+          ? `${calendar}에서 휴무일 ${holidays.length}일을 가져왔습니다.`
+          : '가져올 휴무일이 없습니다.',
+      );
+    },
+    onError: (error) => {
+      toast.error(error.message);
+    },
+  });
+
+  const exportCalendarMutation = useMutation({
+    mutationFn: async () => {
+      // 올해 1월 1일부터 내년 12월 31일까지
+      const year = new Date().getFullYear();
+      const result = await commands.exportWorkdayCalendar(
+        `${year}-01-01`,
+        `${year + 1}-12-31`,
+      );
+      if (result.status === 'error') throw new Error(result.error);
+      return result.data;
+    },
+    onSuccess: (path) => {
+      if (path) toast('근무 일정을 내보냈습니다.');
+    },
+    onError: (error) => {
+      toast.error(error.message);
+    },
+  });
+
+  const calendarFeedMutation = useMutation({
+    mutationFn: async (calendarFeedEnabled: boolean) => {
+      if (!settings) return calendarFeedEnabled;
+      const result = await commands.saveUserSettings({
+        ...settings,
+        calendarFeedEnabled,
+      });
+      if (result.status === 'error') throw new Error(result.error);
+      return calendarFeedEnabled;
+    },
+    onSuccess: async (enabled) => {
+      void queryClient.invalidateQueries({
+        queryKey: userSettingsQuery.all(),
+      });
+      if (!enabled) {
+        toast('캘린더 구독 파일을 삭제했습니다.');
+        return;
+      }
+      const result = await commands.getCalendarFeedPath();
+      toast(
+        result.status === 'ok'
+          ? `캘린더 앱에서 구독하세요: ${result.data}`
+          : '캘린더 구독 파일을 만들었습니다.',
+      );
+    },
+    onError: (error) => {
+      toast.error(error.message);
+    },
+  });
+
   const resetDataMutation = useMutation({
     mutationFn: async () => {
       const result = await commands.resetAllData();
@@ -169,6 +274,25 @@ export function SettingsScreen() {
             label="월급 · 근무 정보"
             onClick={() => navigate({ to: '/settings/salary-info' })}
           />
+          <InfoRow
+            as="button"
+            label="회사 휴무일 가져오기 (.ics)"
+            onClick={() => importCompanyHolidaysMutation.mutate()}
+            disabled={importCompanyHolidaysMutation.isPending}
+          />
+          <InfoRow
+            as="button"
+            label="근무 일정 내보내기 (.ics)"
+            onClick={() => exportCalendarMutation.mutate()}
+            disabled={exportCalendarMutation.isPending}
+          />
+          <InfoRow label="캘린더 구독 파일 자동 갱신">
+            <SwitchInput
+              value={settings?.calendarFeedEnabled ?? false}
+              onSave={(enabled) => calendarFeedMutation.mutate(enabled)}
+              disabled={!settings || calendarFeedMutation.isPending}
+            />
+          </InfoRow>
         </SettingsSection>
 
         <SettingsSection title="메뉴바 설정">
@@ -187,6 +311,18 @@ export function SettingsScreen() {
             }
             disabled={!settings || menubarDisplayModeMutation.isPending}
           />
+          {settings && (settings.jobProfiles?.length ?? 0) > 0 && (
+            <SelectInput
+              options={menubarJobOptions}
+              value={settings.menubarJobId ?? MENUBAR_JOB_ALL}
+              onValueChange={(v) =>
+                menubarJobMutation.mutate(
+                  v === MENUBAR_JOB_ALL ? null : (v as string),
+                )
+              }
+              disabled={menubarJobMutation.isPending}
+            />
+          )}
           <SelectInput
             options={ICON_THEME_OPTIONS}
             value={settings?.menubarIconTheme ?? 'light'}
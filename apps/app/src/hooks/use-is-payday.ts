@@ -1,10 +1,6 @@
-export function useIsPayday(payDay: number): boolean {
-  const today = new Date();
-  const lastDay = new Date(
-    today.getFullYear(),
-    today.getMonth() + 1,
-    0,
-  ).getDate();
-  const effectivePayDay = Math.min(payDay, lastDay);
-  return today.getDate() === effectivePayDay;
+import { useSalaryTick } from '~/hooks/use-salary-tick';
+
+/** 오늘이 월급날인지. 주말·공휴일 보정은 Rust ticker가 반영한다. */
+export function useIsPayday(): boolean {
+  return useSalaryTick()?.isPayday ?? false;
 }
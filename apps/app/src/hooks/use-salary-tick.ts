@@ -1,12 +1,21 @@
 import { listen } from '@tauri-apps/api/event';
 import { useEffect, useState } from 'react';
 
+import {
+  commands,
+  type CompanyHoliday,
+  type GoalBasis,
+} from '~/lib/tauri-bindings';
+
 /** 근무 상태 */
 export type WorkStatus =
   | 'before-work'
   | 'working'
+  | 'on-break'
   | 'completed'
   | 'annual-leave'
+  | 'unpaid-leave'
+  | 'sick-leave'
   | 'day-off'
   | 'public-holiday';
 
@@ -31,30 +40,94 @@ export function waitForNextSalaryTick(): Promise<SalaryInfo> {
   return waitForSalaryTick(() => true);
 }
 
+/** 근무 프로필별 급여 요약 */
+export interface JobSalaryInfo {
+  /** 프로필 id (본업은 'primary') */
+  id: string;
+  name: string;
+  dailyRate: number;
+  hourlyRate: number;
+  todayEarnings: number;
+  accumulatedEarnings: number;
+  workStatus: WorkStatus;
+  isPayday: boolean;
+  secondsUntilClockOut: number | null;
+  daysUntilPayday: number;
+}
+
+/** 저축·구매 목표 진행 상황 */
+export interface GoalProgress {
+  id: string;
+  name: string;
+  basis: GoalBasis;
+  /** 기준 금액 (통화 최소 단위) */
+  currentAmount: number;
+  targetAmount: number;
+  /** 0–1 */
+  progress: number;
+  /** 달성 축하를 보낸 날짜 (YYYY-MM-DD) */
+  achievedOn: string | null;
+}
+
 export interface SalaryInfo {
-  /** 일급 (원) */
+  /** 일급 (원, 정수) */
   dailyRate: number;
   /** 시급 (원) */
   hourlyRate: number;
   /** 초당 금액 (원) */
   perSecond: number;
-  /** 월급날부터 누적 금액 (원) */
+  /** 월급날부터 누적 금액 (원, 반올림 정책으로 정수화) */
   accumulatedEarnings: number;
-  /** 오늘 번 금액 (원) */
+  /** 오늘 번 금액 (원, 기본급 + 가산수당 + 상여금) */
   todayEarnings: number;
+  /** 오늘 기본급 (원) */
+  baseEarnings: number;
+  /** 오늘 연장·야간·휴일 가산수당 (원) */
+  premiumEarnings: number;
+  /** 오늘 반영된 상여금 (원, 월급날 일시 반영분 + 적립분) */
+  bonusEarnings: number;
+  /** 이번 급여 기간 무급휴가·병가 공제액 (원, 오늘 포함) */
+  leaveDeduction: number;
   /** 근무 상태 */
   workStatus: WorkStatus;
   /** 오늘이 근무일인지 */
   isWorkDay: boolean;
   /** 월급날부터 근무한 일수 */
   workedDays: number;
+  /** 오늘 휴게를 뺀 실제 근무 시간 (초, 반차·반반차의 휴가 부분 제외) */
+  workedSeconds: number;
+  /** 오늘이 (주말·공휴일 보정 반영) 월급날인지 */
+  isPayday: boolean;
+  /** 퇴근까지 남은 초 (근무·휴게 중일 때만) */
+  secondsUntilClockOut: number | null;
+  /** 출근까지 남은 초 (출근 전일 때만) */
+  secondsUntilClockIn: number | null;
+  /** 다음 월급날까지 남은 일수 */
+  daysUntilPayday: number;
+  /** 프로필별 내역 (본업 포함). 금액 필드는 이 합계 */
+  jobs: JobSalaryInfo[];
+  /** 저축·구매 목표 진행률 (합계 금액 기준) */
+  goals: GoalProgress[];
+  /** 오늘이 가져온 회사 휴무일이면 그 항목 (어느 달력에서 왔는지 표시용) */
+  companyHoliday: CompanyHoliday | null;
 }
 
 let cachedInfo: SalaryInfo | null = null;
 
+/** 이 창에서 salary-tick을 구독 중인 컴포넌트 수 */
+let subscribers = 0;
+
+/** 구독 중이고 창이 보일 때만 ticker가 근무 중 매초 발행하도록 알림 */
+function reportWatching() {
+  void commands.watchSalaryTick(
+    subscribers > 0 && document.visibilityState === 'visible',
+  );
+}
+
 /**
- * Rust 백그라운드 타이머에서 매초 발행하는 salary-tick 이벤트를 구독.
+ * Rust 백그라운드 타이머가 발행하는 salary-tick 이벤트를 구독.
  * 메뉴바와 UI가 동일한 계산 결과를 사용하도록 보장.
+ * 구독하는 동안 창이 보이면 근무 중에는 매초 발행된다.
  */
 export function useSalaryTick(): SalaryInfo | null {
   const [info, setInfo] = useState<SalaryInfo | null>(cachedInfo);
@@ -64,8 +137,18 @@ export function useSalaryTick(): SalaryInfo | null {
       cachedInfo = event.payload;
       setInfo(event.payload);
     });
+    subscribers += 1;
+    if (subscribers === 1) {
+      document.addEventListener('visibilitychange', reportWatching);
+      reportWatching();
+    }
     return () => {
       void unlisten.then((fn) => fn());
+      subscribers -= 1;
+      if (subscribers === 0) {
+        document.removeEventListener('visibilitychange', reportWatching);
+        reportWatching();
+      }
     };
   }, []);
 
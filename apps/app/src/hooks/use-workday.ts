@@ -11,6 +11,29 @@ import { workdayQuery, workdayQueryOptions } from '~/queries/workday';
 
 export type WorkdayStatus = Exclude<WorkdayKind, 'work'>;
 
+/** 반차/반반차 — 남은 시간은 근무하므로 급여 틱의 근무 상태가 유지된다 */
+export type PartialLeaveStatus = Extract<
+  WorkdayStatus,
+  | 'morning-half-leave'
+  | 'afternoon-half-leave'
+  | 'morning-quarter-leave'
+  | 'afternoon-quarter-leave'
+>;
+
+/** 하루 종일 근무하지 않는 상태 */
+export type NonWorkingStatus = Exclude<WorkdayStatus, PartialLeaveStatus>;
+
+export function isPartialLeave(
+  status: WorkdayStatus,
+): status is PartialLeaveStatus {
+  return (
+    status === 'morning-half-leave' ||
+    status === 'afternoon-half-leave' ||
+    status === 'morning-quarter-leave' ||
+    status === 'afternoon-quarter-leave'
+  );
+}
+
 export interface WorkdaySchedule {
   workStartTime: string;
   workEndTime: string;
@@ -1,10 +1,31 @@
+import type { Currency } from '~/lib/tauri-bindings';
+
 const NUMBER_FORMATTER = new Intl.NumberFormat('ko-KR', {
   maximumFractionDigits: 0,
 });
 
-/** 금액을 원화 형식으로 포맷 (예: 1,234원) */
-export function formatCurrency(amount: number): string {
-  return `${NUMBER_FORMATTER.format(Math.floor(amount))}원`;
+/** 통화별 대표 로캘 (Rust `money::format_amount`와 동일한 표기) */
+const CURRENCY_LOCALES: Record<Currency, string> = {
+  KRW: 'ko-KR',
+  USD: 'en-US',
+  JPY: 'ja-JP',
+  EUR: 'de-DE',
+};
+
+/**
+ * 최소 단위 금액을 통화 형식으로 포맷 (예: 1,234원, $1,234.56).
+ * 원화는 기존 표기(1,234원)를 유지한다.
+ */
+export function formatCurrency(amount: number, currency: Currency = 'KRW'): string {
+  if (currency === 'KRW') {
+    return `${NUMBER_FORMATTER.format(Math.floor(amount))}원`;
+  }
+  const formatter = new Intl.NumberFormat(CURRENCY_LOCALES[currency], {
+    style: 'currency',
+    currency,
+  });
+  const digits = formatter.resolvedOptions().maximumFractionDigits ?? 0;
+  return formatter.format(Math.floor(amount) / 10 ** digits);
 }
 
 /** 금액을 억/만/원 단위 한글 표기로 포맷 (예: 580,000,000 → "5억 8000만원", 8,888,888,888 → "88억 8888.9만원") */
@@ -242,6 +242,142 @@ async savePreferences(preferences: AppPreferences) : Promise<Result<null, string
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * 목표 목록 불러오기
+ */
+async loadGoals() : Promise<Result<Goal[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("load_goals") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * 목표 추가 또는 수정 (같은 id면 교체). 저장 후 전체 목록 반환
+ * 
+ * 금액·기준을 바꾸면 달성 기록을 지워 다시 축하할 수 있게 한다.
+ */
+async saveGoal(goal: Goal) : Promise<Result<Goal[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_goal", { goal }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * 목표 삭제. 저장 후 전체 목록 반환
+ */
+async deleteGoal(id: string) : Promise<Result<Goal[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_goal", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * 오늘 기준 연차 잔여
+ */
+async getLeaveBalance() : Promise<Result<LeaveBalance, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_leave_balance") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * 입사일부터 오늘까지 연차 부여·조정 내역
+ */
+async getLeaveAccruals() : Promise<Result<LeaveAccrual[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_leave_accruals") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * 올해 12월 31일 기준 예상 잔여 (예약한 연차·그때까지 부여될 연차 반영)
+ */
+async getYearEndLeaveBalance() : Promise<Result<LeaveBalance, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_year_end_leave_balance") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * 연차 수동 조정 (오늘 날짜로 기록). 조정 후 잔여 반환
+ */
+async adjustLeaveBalance(days: number, note: string | null) : Promise<Result<LeaveBalance, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("adjust_leave_balance", { days, note }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * 파일 선택 창에서 고른 .ics를 회사 휴무일로 가져온다. 취소하면 None
+ * 
+ * 같은 달력을 다시 가져오면 그 달력의 기존 일정을 바꾼다. 돌려주는 목록은 반복 일정을 내년 말까지 펼친 것.
+ */
+async importCompanyHolidays() : Promise<Result<CompanyHoliday[] | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_company_holidays") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * 가져온 회사 휴무일 전체 (날짜순, 반복 일정은 내년 말까지)
+ */
+async getCompanyHolidays() : Promise<Result<CompanyHoliday[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_company_holidays") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * `calendar`에서 가져온 회사 휴무일 삭제
+ */
+async removeCompanyHolidayCalendar(calendar: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("remove_company_holiday_calendar", { calendar }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * `from`부터 `to`까지(포함) 월급날·휴가·근무 시각 일정을 .ics로 저장. 취소하면 None, 저장하면 경로
+ */
+async exportWorkdayCalendar(from: string, to: string) : Promise<Result<string | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_workday_calendar", { from, to }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * 근무 일정 구독 파일 경로 (`calendar_feed_enabled`일 때만 존재)
+ */
+async getCalendarFeedPath() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_calendar_feed_path") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * Sends a native system notification.
  * On mobile platforms, returns an error as notifications are not yet supported.
@@ -359,6 +495,27 @@ async setTrayTitle(title: string | null) : Promise<Result<null, string>> {
 async notifySettingsChanged() : Promise<void> {
     await TAURI_INVOKE("notify_settings_changed");
 },
+/**
+ * 창이 salary-tick을 보고 있는지 알림 (화면에 보일 때 true, 가려지거나 닫힐 때 false).
+ * 
+ * 근무 중 매초 발행은 보는 창이 있거나 메뉴바 타이틀이 매초 바뀔 때만 한다.
+ */
+async watchSalaryTick(watching: boolean) : Promise<void> {
+    await TAURI_INVOKE("watch_salary_tick", { watching });
+},
+/**
+ * 오늘·이번 주·이번 급여 기간 예상 누적, 월급날까지 남은 일수·시간, 올해 누적.
+ * 
+ * `job_id`가 없거나 `PRIMARY_JOB_ID`면 본업 기준. 계획된 연차 등 미래 날짜의 workday 캐시도 반영한다.
+ */
+async getSalaryForecast(jobId: string | null) : Promise<Result<SalaryForecast, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_salary_forecast", { jobId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * 서버 GET → 로컬 캐시 hydrate.
  * 
@@ -410,6 +567,28 @@ async mutateWorkday(date: string, kind: WorkdayKind, clockInTime: string | null,
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+/**
+ * 추가 근무 프로필의 workday 조회 (로컬 전용). 파일 없으면 빈 캐시.
+ */
+async getJobWorkday(jobId: string, date: string) : Promise<Result<WorkdayCache, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_job_workday", { jobId, date }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * 추가 근무 프로필의 workday 저장 (로컬 전용) + ticker/UI 즉시 반영.
+ */
+async saveJobWorkday(jobId: string, cache: WorkdayCache) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_job_workday", { jobId, cache }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -435,7 +614,159 @@ export type AppPreferences = { theme: string;
 language: string | null }
 export type AuthProvider = "kakao" | "apple"
 export type AuthStatus = { isLoggedIn: boolean; provider: AuthProvider | null }
+/**
+ * 상여금 하나 (예: 설·추석 상여 100%, 2·9월)
+ */
+export type Bonus = { name: string; 
+/**
+ * 지급 월 (1–12). 해당 월 월급날에 지급
+ */
+months: number[]; kind?: BonusKind; value: number }
+/**
+ * 상여금을 누적액에 반영하는 방식
+ */
+export type BonusAccrual = 
+/**
+ * 지급 월 월급날에 한 번에 반영
+ */
+"on-payday" | 
+/**
+ * 지급 월 월급날까지의 급여 기간 동안 근무일에 나눠 적립
+ */
+"accrue"
+/**
+ * 상여금 금액 산정 방식
+ */
+export type BonusKind = 
+/**
+ * 정액 (`value` = 통화 최소 단위 금액)
+ */
+"fixed" | 
+/**
+ * 기본 월급 대비 비율 (`value` = %, 100 → 월급 100%)
+ */
+"percent-of-base"
+/**
+ * 무급 휴게시간 구간 (예: 점심 12:00–13:00)
+ */
+export type BreakWindow = { 
+/**
+ * Break start time (HH:MM format)
+ */
+startTime: string; 
+/**
+ * Break end time (HH:MM format)
+ */
+endTime: string }
+/**
+ * .ics에서 가져온 회사 휴무일 (창립기념일, 연말 휴무 등). 급여 계산에서 공휴일과 같게 본다
+ */
+export type CompanyHoliday = { 
+/**
+ * YYYY-MM-DD
+ */
+date: string; 
+/**
+ * 일정 이름 (예: "창립기념일")
+ */
+name: string; 
+/**
+ * 가져온 달력 이름 (`X-WR-CALNAME`, 없으면 파일 이름)
+ */
+calendar: string }
+/**
+ * 급여 통화 (ISO 4217)
+ */
+export type Currency = "KRW" | "USD" | "JPY" | "EUR"
+/**
+ * 사용자가 관리하는 오프라인 환율. `from` 1단위 = `to` `rate`단위
+ */
+export type ExchangeRate = { from: Currency; to: Currency; rate: number }
+/**
+ * 저축·구매 목표 (예: 노트북 1,200,000원, 오늘 점심값)
+ */
+export type Goal = { id: string; name: string; 
+/**
+ * 목표 금액 (`salary_amount`와 같은 통화 최소 단위)
+ */
+targetAmount: number; basis?: GoalBasis; 
+/**
+ * 목표 시점 (YYYY-MM)
+ */
+deadline?: string | null; 
+/**
+ * 달성 축하를 보낸 날짜 (YYYY-MM-DD). 목표당 한 번, `Today` 목표는 하루에 한 번 축하한다
+ */
+achievedOn?: string | null }
+/**
+ * 목표 진행률 기준 금액
+ */
+export type GoalBasis = 
+/**
+ * 이번 급여 기간 누적 (`accumulated_earnings`)
+ */
+"accumulated" | 
+/**
+ * 오늘 번 금액 (`today_earnings`)
+ */
+"today"
+/**
+ * 추가 근무 프로필 (부업 등). 세금·가산수당 규칙은 `UserSettings` 값을 공유
+ */
+export type JobProfile = { 
+/**
+ * 프로필 id (영문·숫자·`-`·`_`). 프로필별 workday override 경로에 사용
+ */
+id: string; name: string; salaryType?: SalaryType; salaryAmount: number; payDay: number; payCycle?: PayCycle; payAnchorDate?: string | null; workDays?: number[]; workStartTime?: string; workEndTime?: string; weekdayHours?: WeekdayHours[]; breakTimes?: BreakWindow[]; roundingPolicy?: RoundingPolicy }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
+/**
+ * 연차 부여·조정 내역 한 줄
+ */
+export type LeaveAccrual = { 
+/**
+ * 부여일 (YYYY-MM-DD)
+ */
+date: string; 
+/**
+ * 부여 일수 (조정이면 음수일 수 있음)
+ */
+days: number; 
+/**
+ * 예: "입사 3개월", "입사 2년", 조정 메모
+ */
+reason: string; 
+/**
+ * 소멸일 (다음 연차 연도 시작일, YYYY-MM-DD)
+ */
+expiresOn: string }
+/**
+ * 연차 연도 기준 잔여
+ */
+export type LeaveBalance = { 
+/**
+ * 기준일 (YYYY-MM-DD)
+ */
+asOf: string; 
+/**
+ * 연차 연도 시작일 (입사일 또는 입사 기념일)
+ */
+periodStart: string; 
+/**
+ * 다음 연차 연도 시작일. 이날 남은 연차가 소멸한다
+ */
+periodEnd: string; 
+/**
+ * 기준일까지 부여된 일수
+ */
+accrued: number; 
+/**
+ * 연차 연도 안에 사용·예약한 일수 (기준일 이후 예약 포함)
+ */
+used: number; 
+/**
+ * 수동 조정 합계
+ */
+adjusted: number; remaining: number }
 export type LoginResult = { isLoggedIn: boolean; needsOnboarding: boolean }
 /**
  * Menubar display mode for salary
@@ -452,7 +783,31 @@ export type MenubarDisplayMode =
 /**
  * 누적 월급
  */
-"accumulated"
+"accumulated" | 
+/**
+ * 근무 중에는 퇴근까지 남은 시간, 그 외에는 월급날 D-day
+ */
+"countdown" | 
+/**
+ * 월급날 D-day
+ */
+"payday" | 
+/**
+ * 현재 시급
+ */
+"hourly" | 
+/**
+ * 현재 분당 금액
+ */
+"per-minute" | 
+/**
+ * 올해 누적
+ */
+"year-to-date" | 
+/**
+ * 입사일(`hire_date`) 이후 누적
+ */
+"lifetime"
 /**
  * Menubar icon theme
  */
@@ -465,6 +820,80 @@ export type MenubarIconTheme =
  * 어두운 아이콘 (검정, 밝은 메뉴바용)
  */
 "dark"
+/**
+ * 급여 지급 주기
+ */
+export type PayCycle = 
+/**
+ * 매월 `pay_day`
+ */
+"monthly" | 
+/**
+ * 매월 15일·말일
+ */
+"semi-monthly" | 
+/**
+ * 2주마다 (`pay_anchor_date` 기준)
+ */
+"bi-weekly" | 
+/**
+ * 매주 (`pay_anchor_date`의 요일)
+ */
+"weekly"
+/**
+ * 금액 표시 기준 (세전/세후)
+ */
+export type PayDisplay = 
+/**
+ * 세전 (4대보험·소득세 공제 전)
+ */
+"gross" | 
+/**
+ * 세후 실수령액
+ */
+"net"
+/**
+ * 월급날이 주말·공휴일일 때 보정 정책
+ */
+export type PaydayAdjustment = 
+/**
+ * 보정 없음
+ */
+"none" | 
+/**
+ * 직전 영업일 지급 (국내 대부분)
+ */
+"previous-business-day" | 
+/**
+ * 다음 영업일 지급
+ */
+"next-business-day"
+/**
+ * 가산수당 규칙 하나. `rate`는 통상시급 대비 가산율 (0.5 → 50% 가산)
+ */
+export type PremiumRule = { enabled: boolean; rate: number }
+/**
+ * 근로기준법 제56조 가산수당 규칙.
+ * 
+ * 5인 미만 사업장은 적용 제외라 기본은 모두 꺼져 있고, 사용자가 규칙별로 켠다.
+ */
+export type PremiumRules = { 
+/**
+ * 연장근로 (1일 8시간 또는 주 40시간 초과) — 50% 가산
+ */
+overtime?: PremiumRule; 
+/**
+ * 야간근로 (22:00–06:00) — 50% 가산, 연장·휴일과 중복 적용
+ */
+night?: PremiumRule; 
+/**
+ * 휴일근로 8시간 이내 — 50% 가산
+ */
+holiday?: PremiumRule; 
+/**
+ * 휴일근로 8시간 초과분 — 100% 가산
+ */
+holidayOvertime?: PremiumRule }
 /**
  * Error types for recovery operations (typed for frontend matching)
  */
@@ -489,10 +918,94 @@ export type RecoveryError =
  * JSON serialization/deserialization error
  */
 { type: "ParseError"; message: string }
+/**
+ * 금액을 최소 단위로 확정할 때 반올림 방식 (회사 정책)
+ */
+export type RoundingPolicy = 
+/**
+ * 절사
+ */
+"floor" | 
+/**
+ * 사사오입
+ */
+"half-up" | 
+/**
+ * 오사오입 (은행가 반올림)
+ */
+"half-even"
 /**
  * Salary type for user settings
  */
-export type SalaryType = "monthly" | "yearly"
+/**
+ * 급여 예상. 금액은 통화 최소 단위 정수 값 (specta가 i64를 내보내지 않아 f64로 둔다)
+ */
+export type SalaryForecast = { 
+/**
+ * 이번 급여 기간 현재 누적
+ */
+accumulatedEarnings: number; 
+/**
+ * 오늘 퇴근 시점 예상 누적
+ */
+endOfToday: number; 
+/**
+ * 이번 주(월–일) 마지막 날 예상 누적. 월급날이 먼저 오면 기간 마지막 날 기준
+ */
+endOfWeek: number; 
+/**
+ * 월급날 전날까지 예상 누적 (이번 월급)
+ */
+endOfPeriod: number; 
+/**
+ * 월급날까지 더 벌 금액
+ */
+remainingUntilPayday: number; 
+/**
+ * 이번 급여 기간 무급휴가·병가 공제 예상액 (계획된 휴가 포함)
+ */
+leaveDeduction: number; 
+/**
+ * 다음 월급날 (보정 반영, YYYY-MM-DD)
+ */
+nextPayday: string; 
+/**
+ * 월급날까지 남은 일수
+ */
+daysUntilPayday: number; 
+/**
+ * 월급날까지 남은 근무일 수 (오늘 근무가 남아 있으면 포함)
+ */
+workDaysUntilPayday: number; 
+/**
+ * 월급날까지 남은 유급 근무 시간
+ */
+hoursUntilPayday: number; 
+/**
+ * 올해 1월 1일(올해 입사했으면 입사일)부터 현재까지 누적
+ */
+yearToDate: number }
+export type SalaryType = "monthly" | "yearly" | 
+/**
+ * 시급 — 실근로시간 × 시급 (+ 주 15시간 이상이면 주휴수당)
+ */
+"hourly"
+/**
+ * 급여 이력 한 항목. `effective_from`부터 다음 항목 전날까지 적용
+ */
+export type SalaryVersion = { 
+/**
+ * 적용 시작일 (YYYY-MM-DD)
+ */
+effectiveFrom: string; salaryType?: SalaryType; salaryAmount: number; 
+/**
+ * 지급 비율 (%). 수습 기간 90% 등, 기본 100
+ */
+payPercent?: number; 
+/**
+ * 메모 (예: "수습", "연봉 인상")
+ */
+note?: string | null }
 export type TermAgreementInput = { code: string; agreed: boolean }
 export type TermItem = { code: string; title: string; required: boolean; contentUrl: string }
 /**
@@ -500,21 +1013,73 @@ export type TermItem = { code: string; title: string; required: boolean; content
  */
 export type UserSettings = { 
 /**
- * Salary type (monthly or yearly)
+ * Salary type (monthly, yearly or hourly)
  */
 salaryType?: SalaryType; 
 /**
- * Salary amount in KRW (monthly net salary or yearly salary)
+ * Salary amount in `currency` minor units (monthly net salary, yearly salary or hourly wage).
+ * KRW·JPY는 원·엔, USD·EUR는 센트
  */
 salaryAmount: number; 
 /**
- * Pay day of month (1-31, default: 25)
+ * 급여 이력 (적용 시작일 순). 첫 항목 이전 날짜도 첫 항목 급여.
+ * 이력이 있으면 최상위 `salary_type`·`salary_amount`는 오늘 적용 중인 항목과 같게 유지한다
+ */
+salaryHistory?: SalaryVersion[]; 
+/**
+ * 급여 통화. 세후 환산(4대보험·소득세)은 KRW에만 적용
+ */
+currency?: Currency; 
+/**
+ * 메뉴바에 함께 표시할 환산 통화. `exchange_rates`에 환율이 없으면 표시 안 함
+ */
+secondaryCurrency?: Currency | null; 
+/**
+ * 오프라인 환율표
+ */
+exchangeRates?: ExchangeRate[]; 
+/**
+ * 금액 표시 기준. 월급은 세후, 연봉·시급은 세전 입력으로 보고 필요 시 환산.
+ * 없으면 입력한 금액 그대로 표시 (기존 설정 파일은 공제 전과 같은 금액)
+ */
+payDisplay?: PayDisplay | null; 
+/**
+ * 월 비과세 수당 (식대 등). 4대보험·소득세 산정 기준에서 제외
+ */
+taxFreeAllowance?: number; 
+/**
+ * 본인 포함 공제대상 가족 수 (소득세 추정용, 기본 1)
+ */
+dependents?: number; 
+/**
+ * Pay day of month (1-31, default: 25). `pay_cycle`이 월 단위일 때만 사용
  */
 payDay: number; 
+/**
+ * 입사일 (YYYY-MM-DD). 입사 이후 누적과 올해 누적의 시작 기준
+ */
+hireDate?: string | null; 
+/**
+ * 급여 지급 주기. 월급·연봉은 주기당 금액으로 나눈다 (예: 주급 = 월급 × 12 / 52)
+ */
+payCycle?: PayCycle; 
+/**
+ * 주급·격주급 기준 지급일 (YYYY-MM-DD). 이 날짜부터 7·14일 간격
+ */
+payAnchorDate?: string | null; 
+/**
+ * 월급날이 주말(근무 요일 외)·공휴일이면 앞/뒤 영업일로 옮길지
+ */
+paydayAdjustment?: PaydayAdjustment; 
 /**
  * Work days (0=Sunday, 1=Monday, ..., 6=Saturday)
  */
 workDays?: number[]; 
+/**
+ * 근무 시간대 (IANA, 예: "Asia/Seoul"). 출퇴근 시각·날짜·급여 기간을 이 시간대로 본다.
+ * 없으면 시스템 시간대
+ */
+workTimezone?: string | null; 
 /**
  * Work start time (HH:MM format, default: "09:00")
  */
@@ -523,6 +1088,35 @@ workStartTime?: string;
  * Work end time (HH:MM format, default: "18:00")
  */
 workEndTime?: string; 
+/**
+ * 요일별 출퇴근 시간. 없는 요일은 `work_start_time`/`work_end_time` 사용
+ */
+weekdayHours?: WeekdayHours[]; 
+/**
+ * 무급 휴게시간 목록. 근무시간과 겹치는 부분만 유급 시간에서 제외
+ */
+breakTimes?: BreakWindow[]; 
+/**
+ * 연장·야간·휴일 가산수당 규칙
+ */
+premiumRules?: PremiumRules; 
+/**
+ * 일급·누적액 최소 단위 반올림 방식
+ */
+roundingPolicy?: RoundingPolicy; 
+/**
+ * 연봉 분할 지급 횟수 (기본 12). 14·16분할이면 월 기본급 = 연봉 / 분할 수,
+ * 12회를 넘는 분은 `bonuses`에 기본급 대비 비율로 지정한다
+ */
+yearlySplits?: number; 
+/**
+ * 상여금 일정
+ */
+bonuses?: Bonus[]; bonusAccrual?: BonusAccrual; 
+/**
+ * 병가 지급 비율 (%). 100이면 유급 병가, 0이면 무급휴가와 같다
+ */
+sickLeavePayPercent?: number; 
 /**
  * Whether onboarding is completed
  */
@@ -536,13 +1130,41 @@ termsAgreed?: boolean;
  */
 termsMarketingAgreed?: boolean; 
 /**
- * Menubar display mode (macOS only): none, daily, accumulated
+ * Menubar display mode (macOS only): amounts, countdowns, rates or totals
  */
 menubarDisplayMode?: MenubarDisplayMode; 
 /**
  * Menubar icon theme: light (white) or dark (black)
  */
-menubarIconTheme?: MenubarIconTheme }
+menubarIconTheme?: MenubarIconTheme; 
+/**
+ * 본업 외 추가 근무 프로필. 금액은 본업과 합산
+ */
+jobProfiles?: JobProfile[]; 
+/**
+ * 메뉴바에 표시할 프로필 id. None이면 합계, `PRIMARY_JOB_ID`면 본업만
+ */
+menubarJobId?: string | null; 
+/**
+ * 앱 데이터 디렉토리의 근무 일정 .ics 구독 파일을 workday 변경마다 다시 쓸지
+ */
+calendarFeedEnabled?: boolean }
+/**
+ * 특정 요일의 출퇴근 시간 (예: 금요일 09:00–16:00)
+ */
+export type WeekdayHours = { 
+/**
+ * 0=Sunday, 1=Monday, ..., 6=Saturday
+ */
+weekday: number; 
+/**
+ * Work start time (HH:MM format)
+ */
+startTime: string; 
+/**
+ * Work end time (HH:MM format)
+ */
+endTime: string }
 /**
  * 서버↔로컬 workday 동기화 캐시. 기존 `today-work-status.json` +
  * `today-work-schedule.json` 두 파일을 대체한다.
@@ -551,7 +1173,31 @@ menubarIconTheme?: MenubarIconTheme }
  */
 export type WorkdayCache = { date: string; kind: WorkdayKind; clockInTime?: string | null; clockOutTime?: string | null; completed?: boolean; events?: WorkdayCacheEvent[]; isDirty?: boolean }
 export type WorkdayCacheEvent = "PAYDAY" | "PUBLIC_HOLIDAY"
-export type WorkdayKind = "work" | "annual-leave" | "day-off" | "public-holiday"
+export type WorkdayKind = "work" | "annual-leave" | "day-off" | "public-holiday" | 
+/**
+ * 오전 반차 (소정근로시간 앞 절반 휴가)
+ */
+"morning-half-leave" | 
+/**
+ * 오후 반차 (뒤 절반 휴가)
+ */
+"afternoon-half-leave" | 
+/**
+ * 오전 반반차 (앞 1/4 휴가)
+ */
+"morning-quarter-leave" | 
+/**
+ * 오후 반반차 (뒤 1/4 휴가)
+ */
+"afternoon-quarter-leave" | 
+/**
+ * 무급휴가. 쉬는 날(`DayOff`)과 달리 월급에서 그날 일급만큼 공제
+ */
+"unpaid-leave" | 
+/**
+ * 병가. `UserSettings.sick_leave_pay_percent`만큼 지급하고 나머지는 공제
+ */
+"sick-leave"
 
 /** tauri-specta globals **/
 
@@ -26,12 +26,25 @@ export type {
   AppPreferences,
   AuthProvider,
   AuthStatus,
+  Bonus,
+  BonusAccrual,
+  BonusKind,
+  CompanyHoliday,
+  Currency,
+  ExchangeRate,
+  Goal,
+  GoalBasis,
   JsonValue,
+  LeaveAccrual,
+  LeaveBalance,
   LoginResult,
   MenubarDisplayMode,
   MenubarIconTheme,
+  PayCycle,
   RecoveryError,
+  SalaryForecast,
   SalaryType,
+  SalaryVersion,
   TermAgreementInput,
   TermItem,
   UserSettings,
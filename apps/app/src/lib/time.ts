@@ -1,9 +1,38 @@
-/** 오늘 날짜를 YYYY-MM-DD 형식으로 반환 */
+/** 근무 시간대 (IANA). null이면 시스템 시간대 */
+let workTimezone: string | null = null;
+
+/** 날짜·시각 계산에 쓸 근무 시간대 설정 (`UserSettings.workTimezone`) */
+export function setWorkTimezone(timezone: string | null | undefined): void {
+  workTimezone = timezone ?? null;
+}
+
+type NowPart = 'year' | 'month' | 'day' | 'hour' | 'minute';
+
+/** 근무 시간대 기준 현재 연·월·일·시·분 */
+function nowParts(): Record<NowPart, string> {
+  const parts = new Intl.DateTimeFormat('en-US', {
+    timeZone: workTimezone ?? undefined,
+    year: 'numeric',
+    month: '2-digit',
+    day: '2-digit',
+    hour: '2-digit',
+    minute: '2-digit',
+    hourCycle: 'h23',
+  }).formatToParts(new Date());
+  const get = (type: Intl.DateTimeFormatPartTypes) =>
+    parts.find((part) => part.type === type)?.value ?? '';
+  return {
+    year: get('year'),
+    month: get('month'),
+    day: get('day'),
+    hour: get('hour'),
+    minute: get('minute'),
+  };
+}
+
+/** 오늘 날짜를 YYYY-MM-DD 형식으로 반환 (근무 시간대 기준) */
 export function getTodayString(): string {
-  const now = new Date();
-  const year = now.getFullYear();
-  const month = String(now.getMonth() + 1).padStart(2, '0');
-  const day = String(now.getDate()).padStart(2, '0');
+  const { year, month, day } = nowParts();
   return `${year}-${month}-${day}`;
 }
 
@@ -40,10 +69,8 @@ export function normalizeOvernightMinutes(
   return { normalizedEnd, normalizedNow };
 }
 
-/** 현재 시각을 HH:MM 형식 문자열로 반환 */
+/** 현재 시각을 HH:MM 형식 문자열로 반환 (근무 시간대 기준) */
 export function getCurrentTimeString(): string {
-  const now = new Date();
-  const hours = String(now.getHours()).padStart(2, '0');
-  const minutes = String(now.getMinutes()).padStart(2, '0');
-  return `${hours}:${minutes}`;
+  const { hour, minute } = nowParts();
+  return `${hour}:${minute}`;
 }
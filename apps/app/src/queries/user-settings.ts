@@ -1,6 +1,7 @@
 import { queryOptions } from '@tanstack/react-query';
 
 import { commands, unwrapResult } from '~/lib/tauri-bindings';
+import { setWorkTimezone } from '~/lib/time';
 
 export const userSettingsQuery = {
   all: () => ['userSettings'] as const,
@@ -10,6 +11,10 @@ export const userSettingsQueryOptions = {
   detail: () =>
     queryOptions({
       queryKey: userSettingsQuery.all(),
-      queryFn: async () => unwrapResult(await commands.loadUserSettings()),
+      queryFn: async () => {
+        const settings = unwrapResult(await commands.loadUserSettings());
+        setWorkTimezone(settings.workTimezone);
+        return settings;
+      },
     }),
 };
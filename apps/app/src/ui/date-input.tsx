@@ -0,0 +1,33 @@
+import { Input } from '@base-ui/react/input';
+import type { ComponentProps } from 'react';
+import { cn } from 'tailwind-variants';
+
+interface DateInputProps
+  extends Omit<
+    ComponentProps<typeof Input>,
+    'type' | 'value' | 'onValueChange'
+  > {
+  /** YYYY-MM-DD, 비어 있으면 '' */
+  value: string;
+  onValueChange: (value: string) => void;
+}
+
+export function DateInput({
+  className,
+  value,
+  onValueChange,
+  ...props
+}: DateInputProps) {
+  return (
+    <Input
+      {...props}
+      type="date"
+      value={value}
+      onValueChange={(next) => onValueChange(String(next))}
+      className={cn(
+        'bg-container-primary focus:border-green-40 b1-600 text-text-high w-full rounded-sm border border-transparent px-4 py-3 focus:outline-none',
+        className,
+      )}
+    />
+  );
+}
@@ -5,6 +5,7 @@ export * from './app-footer';
 export * from './badge';
 export * from './button';
 export * from './checkbox';
+export * from './date-input';
 export * from './day-chip';
 export * from './field';
 export * from './icon-button';
@@ -4,7 +4,7 @@ import { useCallback, useEffect, useRef, useState } from 'react';
 import { RevealItem, RevealSection } from './reveal';
 
 // 가정: 월급 400만, 월급일 25일, 오늘 12일, 근무 09:00-18:00, 주5일
-// salary.rs calculate_salary 로직 기반
+// salary/mod.rs calculate_salary 로직 기반
 const MONTHLY_SALARY = 4_500_000;
 const WORK_DAYS_IN_PERIOD = 21; // 전월 25일~당월 25일 근무일
 const WORKED_DAYS = 12; // 전월 25일~오늘 전까지 근무일